@@ -0,0 +1,22 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![no_main]
+
+// dependencies
+use libfuzzer_sys::fuzz_target;
+
+// project
+use found_terminal::packets::ack::AckPacket;
+
+
+const KEY: &[u8] = b"fuzzing key";
+
+fuzz_target!(|data: &[u8]| {
+    let _ = AckPacket::decode(data, KEY);
+
+    if let Ok(packet) = AckPacket::decode_unverified(data) {
+        assert_eq!(AckPacket::decode(&packet.encode(KEY), KEY), Ok(packet));
+    }
+});
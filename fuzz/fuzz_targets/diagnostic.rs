@@ -0,0 +1,18 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![no_main]
+
+// dependencies
+use libfuzzer_sys::fuzz_target;
+
+// project
+use found_terminal::packets::diagnostic::DiagnosticPacket;
+
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(packet) = DiagnosticPacket::decode(data) {
+        assert_eq!(DiagnosticPacket::decode(&packet.encode()), Ok(packet));
+    }
+});
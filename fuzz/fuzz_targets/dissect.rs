@@ -0,0 +1,37 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+#![no_main]
+
+// dependencies
+use libfuzzer_sys::fuzz_target;
+
+// project
+use found_terminal::protocol::capture::read_capture;
+use found_terminal::protocol::dissect::dissect;
+
+
+const KEY: &[u8] = b"fuzzing key";
+
+// section header and an Ethernet interface, so plain noise reaches the packet blocks
+const PCAPNG_SEED: [u8; 48] = [
+    0x0a, 0x0d, 0x0d, 0x0a, 28, 0, 0, 0, 0x4d, 0x3c, 0x2b, 0x1a, 1, 0, 0, 0,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 28, 0, 0, 0,
+    1, 0, 0, 0, 20, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0,
+];
+
+// captures are hostile input too, and every datagram in them goes to the dissector
+fuzz_target!(|data: &[u8]| {
+    let _ = dissect(data, None);
+    let _ = dissect(data, Some(KEY));
+
+    let seeded = [&PCAPNG_SEED[..], data].concat();
+    for capture in [data, &seeded[..]] {
+        if let Ok(datagrams) = read_capture(capture) {
+            for datagram in datagrams {
+                let _ = dissect(&datagram.payload, None);
+            }
+        }
+    }
+});
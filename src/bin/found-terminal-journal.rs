@@ -5,43 +5,376 @@
 // Original version of this file released by Tristram Oaten under CC0 1.0 Universal
 // https://github.com/0atman/noboilerplate -> 8 | Building a space station in Rust
 
+// library
+use std::env;
+use std::fs::File;
+use std::io::{self, BufReader};
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::str::FromStr;
+use std::time::Duration;
+
+// dependencies
+use rustyline::error::ReadlineError;
+
 // project
 use found_terminal::station::station::Station;
-use found_terminal::station::components::{Repair, PowerDown};
+use found_terminal::station::components::PowerDown;
+use found_terminal::station::query::StatusQuery;
 use found_terminal::journal::journal::Journal;
-use found_terminal::terminalisp::menu::tli_menu;
+use found_terminal::journal::export::ExportFormat;
+use found_terminal::script::script::run_script;
+use found_terminal::repl::editor::editor;
+use found_terminal::repl::error::ReplError;
+use found_terminal::repl::session::Session;
+use found_terminal::protocol::beacon::StationBeacon;
+use found_terminal::protocol::command::CommandInbox;
+use found_terminal::protocol::downlink::JournalDownlink;
+use found_terminal::protocol::udp;
+use found_terminal::protocol::auth::load_key;
+use found_terminal::protocol::port_address::check_port_address;
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::journal as tl_journal;
+use found_terminal::terminalisp::mission as tl_mission;
+use found_terminal::terminalisp::repl as tl_repl;
+use found_terminal::terminalisp::reader::read_all;
+use found_terminal::terminalisp::format::OutputFormat;
+use found_terminal::terminalisp::sink::{set_sink, FormatSink, StdoutSink};
+
+
+// how long the station waits for mission control to confirm a journal
+const DOWNLINK_TIMEOUT : Duration = Duration::from_secs(2);
+
+struct Options {
+    seed: Option<u64>,
+    station_file: Option<PathBuf>,
+    journal_file: Option<PathBuf>,
+    export: Option<ExportFormat>,
+    export_file: Option<PathBuf>,
+    format: OutputFormat,
+    script: Option<String>,
+    repl: bool,
+    history: Option<PathBuf>,
+    beacon: Option<String>,
+    port_address: Option<u16>,
+    key_file: Option<PathBuf>,
+    downlink: Option<SocketAddr>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        seed: None,
+        station_file: None,
+        journal_file: None,
+        export: None,
+        export_file: None,
+        format: OutputFormat::Terminalisp,
+        script: None,
+        repl: false,
+        history: None,
+        beacon: None,
+        port_address: None,
+        key_file: None,
+        downlink: None,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => {
+                let value = args.next().ok_or("--seed needs a value")?;
+                match value.parse::<u64>() {
+                    Ok(v) => { options.seed = Some(v); },
+                    Err(_) => { return Err(format!("invalid seed: {value}")); }
+                }
+            },
+            "--station" => {
+                let value = args.next().ok_or("--station needs a file")?;
+                options.station_file = Some(PathBuf::from(value));
+            },
+            "--journal" => {
+                let value = args.next().ok_or("--journal needs a file")?;
+                options.journal_file = Some(PathBuf::from(value));
+            },
+            "--export" => {
+                let value = args.next().ok_or("--export needs a format")?;
+                match ExportFormat::from_str(value.as_str()) {
+                    Ok(v) => { options.export = Some(v); },
+                    Err(_) => { return Err(format!("invalid export format: {value}")); }
+                }
+            },
+            "--export-file" => {
+                let value = args.next().ok_or("--export-file needs a file")?;
+                options.export_file = Some(PathBuf::from(value));
+            },
+            "--format" => {
+                let value = args.next().ok_or("--format needs a format")?;
+                match OutputFormat::from_str(value.as_str()) {
+                    Ok(v) => { options.format = v; },
+                    Err(_) => { return Err(format!("invalid output format: {value}")); }
+                }
+            },
+            "--script" => {
+                let value = args.next().ok_or("--script needs a file or -")?;
+                options.script = Some(value);
+            },
+            "--repl" => {
+                options.repl = true;
+            },
+            "--history" => {
+                let value = args.next().ok_or("--history needs a file")?;
+                options.history = Some(PathBuf::from(value));
+            },
+            "--beacon" => {
+                let value = args.next().ok_or("--beacon needs an address")?;
+                options.beacon = Some(value);
+            },
+            "--port-address" => {
+                let value = args.next().ok_or("--port-address needs a value")?;
+                match value.parse::<u16>() {
+                    Ok(v) => { options.port_address = Some(check_port_address(v)?); },
+                    Err(_) => { return Err(format!("invalid port address: {value}")); }
+                }
+            },
+            "--key" => {
+                let value = args.next().ok_or("--key needs a file")?;
+                options.key_file = Some(PathBuf::from(value));
+            },
+            "--downlink" => {
+                let value = args.next().ok_or("--downlink needs an address")?;
+                match value.parse::<SocketAddr>() {
+                    Ok(v) => { options.downlink = Some(v); },
+                    Err(_) => { return Err(format!("invalid downlink address: {value}")); }
+                }
+            },
+            _ => { return Err(format!("unknown argument: {arg}")); }
+        }
+    }
+
+    if options.key_file.is_some() && options.beacon.is_none() {
+        return Err(String::from("--key needs --beacon"));
+    }
+    if options.repl && options.script.is_some() {
+        return Err(String::from("--repl and --script cannot be combined"));
+    }
+    if options.history.is_some() && !options.repl {
+        return Err(String::from("--history needs --repl"));
+    }
 
+    return Ok(options);
+}
 
 fn main() {
-    let mut station = Station::new();
+    let options = match parse_options() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_station::argument_error(e);
+            process::exit(2);
+        }
+    };
+    if options.format != OutputFormat::Terminalisp {
+        set_sink(Box::new(FormatSink::new(options.format, Box::new(StdoutSink::new()))));
+    }
 
-    let mut journal = Journal::new(
-        "STATION LOG".to_string(),
-        station.name_display()
-    );
+    let mut station = match &options.station_file {
+        Some(path) if path.exists() => {
+            match Station::load(path) {
+                Ok(v) => {
+                    tl_station::station_file_loaded();
+                    v
+                },
+                Err(e) => {
+                    tl_station::station_file_error(e);
+                    process::exit(1);
+                }
+            }
+        },
+        _ => {
+            match options.seed {
+                Some(seed) => Station::from_seed(seed),
+                None => Station::new(),
+            }
+        }
+    };
+    tl_station::station_seed(station.seed());
 
-    loop {
-        if !day(&mut station, &mut journal) {
-            break
-        };
+    if let Some(port_address) = options.port_address {
+        // already checked while parsing
+        let _ = station.set_port_address(port_address);
+    }
+
+    let mut inbox: Option<CommandInbox> = None;
+    if let Some(address) = &options.beacon {
+        inbox = start_beacon(address.as_str(), options.key_file.as_deref(), &mut station);
+    }
+
+    let mut journal = match &options.journal_file {
+        Some(path) => open_journal(path, station.name_display()),
+        None => Journal::new("STATION LOG".to_string(), station.name_display()),
+    };
+
+    match &options.script {
+        Some(script) => {
+            process_commands(&mut inbox, &mut station, &mut journal, options.station_file.as_deref());
+            if let Err(e) = script_run(script.as_str(), &mut station, &mut journal) {
+                tl_station::argument_error(e);
+                process::exit(1);
+            }
+            process_commands(&mut inbox, &mut station, &mut journal, options.station_file.as_deref());
+            day_ended(&options, &station, &journal);
+        },
+        None if options.repl => {
+            repl(&options, &mut station, &mut journal, &mut inbox);
+        },
+        None => {
+            loop {
+                let running = day(&mut station, &mut journal, &mut inbox, options.station_file.as_deref());
+                day_ended(&options, &station, &journal);
+
+                if !running {
+                    break
+                };
+            }
+        }
+    }
+
+    match options.export {
+        Some(format) => export_journal(&journal, format, options.export_file.as_deref()),
+        None => tl_journal::journal_printout(journal.header(), journal.title(), journal.entries()),
+    }
+}
+
+fn day_ended(options: &Options, station: &Station, journal: &Journal) {
+    downlink_journal(options.downlink, station, journal);
+
+    if let Some(path) = &options.station_file {
+        save_station(station, path);
+    }
+}
+
+fn script_run(script: &str, station: &mut Station, journal: &mut Journal) -> Result<(), String> {
+    if script == "-" {
+        return run_script(io::stdin().lock(), station, journal);
+    }
+
+    let file = File::open(script)
+        .map_err(|e| format!("{script}: {e}"))?;
+    return run_script(BufReader::new(file), station, journal);
+}
+
+fn export_journal(journal: &Journal, format: ExportFormat, path: Option<&Path>) {
+    match path {
+        Some(path) => {
+            match journal.export_to_file(format, path) {
+                Ok(_) => { tl_journal::journal_export_saved(); },
+                Err(e) => { tl_journal::journal_export_error(e); }
+            }
+        },
+        None => { print!("{}", journal.export(format)); }
     }
+}
+
+fn open_journal(path: &Path, title: String) -> Journal {
+    let existing = path.exists();
+
+    match Journal::open_or_create(path, "STATION LOG".to_string(), title) {
+        Ok(v) => {
+            if existing { tl_journal::journal_file_loaded(); }
+            v
+        },
+        Err(e) => {
+            tl_journal::journal_file_error(e);
+            process::exit(1);
+        }
+    }
+}
+
+fn start_beacon(address: &str, key_file: Option<&Path>, station: &mut Station) -> Option<CommandInbox> {
+    let beacon = match StationBeacon::start(address, station) {
+        Ok(v) => v,
+        Err(e) => {
+            tl_mission::beacon_error(e);
+            process::exit(1);
+        }
+    };
+    if let Ok(v) = beacon.local_addr() {
+        tl_mission::beacon_started(v.to_string());
+    }
+
+    let inbox = match key_file {
+        Some(path) => {
+            match load_key(path).and_then(|key| beacon.command_inbox(key)) {
+                Ok(v) => Some(v),
+                Err(e) => {
+                    tl_mission::beacon_error(e);
+                    process::exit(1);
+                }
+            }
+        },
+        None => None,
+    };
+
+    station.set_telemetry(Box::new(beacon));
+
+    return inbox;
+}
+
+// The station is saved straight away, so a restart can't accept the same commands again.
+fn process_commands(inbox: &mut Option<CommandInbox>, station: &mut Station, journal: &mut Journal,
+                    station_file: Option<&Path>) {
+    if let Some(inbox) = inbox {
+        let acks = inbox.process(station);
+        record_station(station, journal);
+
+        if let (false, Some(path)) = (acks.is_empty(), station_file) {
+            save_station(station, path);
+        }
+    }
+}
+
+fn downlink_journal(target: Option<SocketAddr>, station: &Station, journal: &Journal) {
+    let target = match target {
+        Some(v) => v,
+        None => { return; }
+    };
+    let bind = match target {
+        SocketAddr::V4(_) => "0.0.0.0:0",
+        SocketAddr::V6(_) => "[::]:0",
+    };
+
+    let report = udp::bind(bind)
+        .and_then(|socket| JournalDownlink::new(station, journal, socket))
+        .and_then(|downlink| downlink.send(target, DOWNLINK_TIMEOUT));
+    match report {
+        Ok(v) => { tl_mission::journal_downlink(target.to_string(), &v); },
+        Err(e) => { tl_mission::journal_downlink_error(e); }
+    }
+}
 
-    journal.print();
+fn save_station(station: &Station, path: &Path) {
+    match station.save(path) {
+        Ok(_) => { tl_station::station_file_saved(); },
+        Err(e) => { tl_station::station_file_error(e); }
+    }
 }
 
-fn day(station: &mut Station, journal: &mut Journal) -> bool {
+fn day(station: &mut Station, journal: &mut Journal, inbox: &mut Option<CommandInbox>,
+       station_file: Option<&Path>) -> bool {
 
-    journal.add_entry(format!("Mission Day {}", station.mission_day));
+    if let Err(e) = journal.day(station.mission_day) {
+        tl_journal::journal_entry_status_error(e);
+    }
 
     let journal_prompt = format!("Log for Mission Day {}:", station.mission_day);
     loop {
-        match journal.prompt_entry(journal_prompt.clone()) {
-            Ok(_) => { break; }
-            Err(_) => {}
+        if journal.prompt_entry(station.prompter(), journal_prompt.clone()).is_ok() {
+            break;
         }
     }
 
     station.new_day();
+    record_station(station, journal);
 
     if station.is_shut_down() {
         return false;
@@ -56,14 +389,28 @@ fn day(station: &mut Station, journal: &mut Journal) -> bool {
     ];
 
     loop {
+        process_commands(inbox, station, journal, station_file);
+        if station.is_shut_down() {
+            return false;
+        }
+
         let chosen: String;
-        match tli_menu("MENU", prompts.clone()) {
+        match station.prompter().select("MENU", prompts.clone()) {
             Ok(v) => { chosen = v; },
             Err(_) => { continue; }
         }
         match chosen {
             _ if chosen == prompts[0] => {
-                println!("{}", station.status(0, true, true))
+                // categories and sections, modules are too fine-grained for a menu
+                let scopes: Vec<String> = station.paths()
+                    .into_iter()
+                    .filter(|path| path.matches('/').count() < 2)
+                    .collect();
+                let status = StatusQuery::prompt(station.prompter(), scopes)
+                    .and_then(|query| station.status_query(&query).map_err(|e| e.to_string()));
+                if let Ok(v) = status {
+                    tl_station::status(&v);
+                }
             },
             _ if chosen == prompts[1] => {
                 station.repair();
@@ -84,5 +431,73 @@ fn day(station: &mut Station, journal: &mut Journal) -> bool {
         }
     }
 
+    record_station(station, journal);
+    process_commands(inbox, station, journal, station_file);
+
     return true;
 }
+
+fn repl(options: &Options, station: &mut Station, journal: &mut Journal, inbox: &mut Option<CommandInbox>) {
+    let mut editor = match editor() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_repl::repl_error(&ReplError::Editor(e));
+            process::exit(1);
+        }
+    };
+    if let Some(path) = options.history.as_deref().filter(|path| path.exists()) {
+        if let Err(e) = editor.load_history(path) {
+            tl_repl::repl_error(&ReplError::Editor(e.to_string()));
+        }
+    }
+
+    let mut session = Session::start(station, journal);
+    while !station.is_shut_down() {
+        process_commands(inbox, station, journal, options.station_file.as_deref());
+        if let Some(helper) = editor.helper_mut() {
+            helper.set_paths(station.paths());
+        }
+
+        let line = match editor.readline(session.prompt(station).as_str()) {
+            Ok(v) => v,
+            Err(ReadlineError::Interrupted) => { continue; },
+            Err(ReadlineError::Eof) => { break; },
+            Err(e) => {
+                tl_repl::repl_error(&ReplError::Editor(e.to_string()));
+                break;
+            }
+        };
+        let _ = editor.add_history_entry(line.as_str());
+
+        let values = match read_all(line.as_str()) {
+            Ok(v) => v,
+            Err(e) => {
+                tl_repl::repl_error(&ReplError::Read(e));
+                continue;
+            }
+        };
+        for value in values {
+            match session.eval(&value, station, journal) {
+                Ok(true) => { day_ended(options, station, journal); },
+                Ok(false) => {},
+                Err(e) => {
+                    tl_repl::repl_error(&e);
+                    break;
+                }
+            }
+        }
+    }
+    tl_repl::repl_end(station.mission_day);
+
+    if let Some(path) = &options.history {
+        if let Err(e) = editor.save_history(path) {
+            tl_repl::repl_error(&ReplError::Editor(e.to_string()));
+        }
+    }
+}
+
+fn record_station(station: &mut Station, journal: &mut Journal) {
+    if let Err(e) = journal.record_station(station) {
+        tl_journal::journal_entry_status_error(e);
+    }
+}
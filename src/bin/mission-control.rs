@@ -0,0 +1,167 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+// project
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+use found_terminal::protocol::port_address::PortAddressRange;
+use found_terminal::protocol::udp::Endpoints;
+use found_terminal::protocol::command::CommandSender;
+use found_terminal::protocol::auth::load_key;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::command::Command;
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::mission as tl_mission;
+use found_terminal::terminalisp::command as tl_command;
+
+
+struct Options {
+    endpoints: Endpoints,
+    range: PortAddressRange,
+    interval: Duration,
+    rounds: u32,
+    key_file: Option<PathBuf>,
+    command: Option<Command>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        endpoints: Endpoints::new(),
+        range: PortAddressRange::all(),
+        interval: Duration::from_secs(5),
+        rounds: 0,
+        key_file: None,
+        command: None,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            _ if options.endpoints.parse_argument(arg.as_str(), &mut args)? => {},
+            "--range" => {
+                let value = args.next().ok_or("--range needs a port address or BOTTOM-TOP")?;
+                options.range = PortAddressRange::parse(value.as_str())?;
+            },
+            "--interval" => {
+                let value = args.next().ok_or("--interval needs a number of seconds")?;
+                match value.parse::<u64>() {
+                    Ok(v) if v > 0 => { options.interval = Duration::from_secs(v); },
+                    _ => { return Err(format!("invalid interval: {value}")); }
+                }
+            },
+            "--rounds" => {
+                let value = args.next().ok_or("--rounds needs a number")?;
+                match value.parse::<u32>() {
+                    Ok(v) => { options.rounds = v; },
+                    Err(_) => { return Err(format!("invalid rounds: {value}")); }
+                }
+            },
+            "--key" => {
+                let value = args.next().ok_or("--key needs a file")?;
+                options.key_file = Some(PathBuf::from(value));
+            },
+            "--send" => {
+                let value = args.next().ok_or("--send needs a command")?;
+                options.command = Some(Command::parse(value.as_str())?);
+            },
+            _ => { return Err(format!("unknown argument: {arg}")); }
+        }
+    }
+
+    if options.command.is_some() && options.key_file.is_none() {
+        return Err(String::from("--send needs --key"));
+    }
+
+    options.endpoints.default_targets()?;
+
+    return Ok(options);
+}
+
+fn main() {
+    let options = match parse_options() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_station::argument_error(e);
+            process::exit(2);
+        }
+    };
+
+    if let (Some(command), Some(key_file)) = (&options.command, &options.key_file) {
+        send_command(&options, command, key_file);
+        return;
+    }
+
+    let mut mission = match MissionControl::new(options.endpoints.bind.as_str(), options.endpoints.targets) {
+        Ok(v) => v,
+        Err(e) => {
+            tl_mission::mission_error(e);
+            process::exit(1);
+        }
+    };
+
+    let mut ping = PingPacket::new();
+    ping.set_target_range(options.range);
+    mission.set_ping(ping);
+    // a station may miss two pings before its row goes
+    mission.set_station_timeout(options.interval * 3);
+
+    // zero rounds runs until interrupted
+    let mut round: u32 = 0;
+    while options.rounds == 0 || round < options.rounds {
+        round = round.saturating_add(1);
+
+        if let Err(e) = mission.ping_all() {
+            tl_mission::mission_error(e);
+        }
+
+        let updates = match mission.poll(options.interval) {
+            Ok(v) => v,
+            Err(e) => {
+                tl_mission::mission_error(e);
+                continue;
+            }
+        };
+
+        for update in &updates {
+            match update {
+                MissionUpdate::Failure(address, path) => {
+                    if let Some(row) = mission.stations().iter().find(|row| &row.address == address) {
+                        tl_mission::station_failure(row, path.as_str());
+                    }
+                },
+                MissionUpdate::Journal(address, journal) => {
+                    tl_mission::journal_received(address.to_string(), journal);
+                },
+                _ => {},
+            }
+        }
+
+        if updates.iter().any(|update| matches!(update, MissionUpdate::Station(..) | MissionUpdate::Failure(..) | MissionUpdate::Lost(..))) {
+            tl_mission::station_table(mission.stations());
+        }
+    }
+}
+
+fn send_command(options: &Options, command: &Command, key_file: &Path) {
+    let mut sender = match load_key(key_file)
+        .and_then(|key| CommandSender::new(options.endpoints.bind.as_str(), key)) {
+        Ok(v) => v,
+        Err(e) => {
+            tl_command::command_error(e);
+            process::exit(1);
+        }
+    };
+
+    for target in &options.endpoints.targets {
+        match sender.send(*target, options.range, command.clone(), options.interval) {
+            Ok(ack) => { tl_command::command_ack(&ack); },
+            Err(e) => { tl_command::command_error(e); }
+        }
+    }
+}
@@ -6,6 +6,9 @@
 // https://github.com/0atman/noboilerplate -> 8 | Building a space station in Rust
 
 // library
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
 use std::str::FromStr;
 
 // dependencies
@@ -16,8 +19,14 @@ use strum_macros::{Display, EnumString};
 
 // project
 use found_terminal::journal::journal::Journal;
+use found_terminal::prompt::interactive::InquirePrompter;
+use found_terminal::journal::export::ExportFormat;
 use found_terminal::terminalisp::station as tl_station;
 use found_terminal::terminalisp::original as tl_original;
+use found_terminal::terminalisp::journal as tl_journal;
+use found_terminal::terminalisp::escape::quoted;
+use found_terminal::terminalisp::format::OutputFormat;
+use found_terminal::terminalisp::sink::{set_sink, FormatSink, StdoutSink};
 
 
 #[derive(RandGen)]
@@ -74,7 +83,7 @@ impl Station {
 
     fn break_something(&mut self) {
         let broken_index = thread_rng().gen_range(0..self.sections.len());
-        let mut broken_section = &mut self.sections[broken_index];
+        let broken_section = &mut self.sections[broken_index];
         if broken_section.active {
             broken_section.active = false;
             tl_station::section_failure(broken_section.name.to_string())
@@ -98,7 +107,7 @@ impl Station {
                 String::from("version"),
             ],
             vec![
-                format!("\"{}\"", self.name),
+                quoted(self.name.to_string().as_str()),
                 format!("{}", self.version),
             ],
             section_names,
@@ -126,15 +135,73 @@ enum SectionName {
 }
 
 
+struct Options {
+    journal_file: Option<PathBuf>,
+    export: Option<ExportFormat>,
+    export_file: Option<PathBuf>,
+    format: OutputFormat,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        journal_file: None,
+        export: None,
+        export_file: None,
+        format: OutputFormat::Terminalisp,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--journal" => {
+                let value = args.next().ok_or("--journal needs a file")?;
+                options.journal_file = Some(PathBuf::from(value));
+            },
+            "--export" => {
+                let value = args.next().ok_or("--export needs a format")?;
+                match ExportFormat::from_str(value.as_str()) {
+                    Ok(v) => { options.export = Some(v); },
+                    Err(_) => { return Err(format!("invalid export format: {value}")); }
+                }
+            },
+            "--export-file" => {
+                let value = args.next().ok_or("--export-file needs a file")?;
+                options.export_file = Some(PathBuf::from(value));
+            },
+            "--format" => {
+                let value = args.next().ok_or("--format needs a format")?;
+                match OutputFormat::from_str(value.as_str()) {
+                    Ok(v) => { options.format = v; },
+                    Err(_) => { return Err(format!("invalid output format: {value}")); }
+                }
+            },
+            _ => { return Err(format!("unknown argument: {arg}")); }
+        }
+    }
+
+    return Ok(options);
+}
+
 fn main() {
+    let options = match parse_options() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_station::argument_error(e);
+            process::exit(2);
+        }
+    };
+    if options.format != OutputFormat::Terminalisp {
+        set_sink(Box::new(FormatSink::new(options.format, Box::new(StdoutSink::new()))));
+    }
+
     let mut station = Station::new();
 
     tl_original::station_header(station.name.to_string(), station.version);
 
-    let mut journal = Journal::new(
-        "STATION LOG".to_string(),
-        station.log_header()
-    );
+    let mut journal = match &options.journal_file {
+        Some(path) => open_journal(path, station.log_header()),
+        None => Journal::new("STATION LOG".to_string(), station.log_header()),
+    };
 
     loop {
         if !day(&mut station, &mut journal) {
@@ -142,7 +209,37 @@ fn main() {
         }
     }
 
-    journal.print();
+    match options.export {
+        Some(format) => export_journal(&journal, format, options.export_file.as_deref()),
+        None => tl_journal::journal_printout(journal.header(), journal.title(), journal.entries()),
+    }
+}
+
+fn export_journal(journal: &Journal, format: ExportFormat, path: Option<&Path>) {
+    match path {
+        Some(path) => {
+            match journal.export_to_file(format, path) {
+                Ok(_) => { tl_journal::journal_export_saved(); },
+                Err(e) => { tl_journal::journal_export_error(e); }
+            }
+        },
+        None => { print!("{}", journal.export(format)); }
+    }
+}
+
+fn open_journal(path: &Path, title: String) -> Journal {
+    let existing = path.exists();
+
+    match Journal::open_or_create(path, "STATION LOG".to_string(), title) {
+        Ok(v) => {
+            if existing { tl_journal::journal_file_loaded(); }
+            v
+        },
+        Err(e) => {
+            tl_journal::journal_file_error(e);
+            process::exit(1);
+        }
+    }
 }
 
 fn day(station: &mut Station, journal: &mut Journal) -> bool {
@@ -157,9 +254,8 @@ fn day(station: &mut Station, journal: &mut Journal) -> bool {
 
     let journal_prompt = String::from("Enter your log:");
     loop {
-        match journal.prompt_entry(journal_prompt.clone()) {
-            Ok(_) => { break; }
-            Err(_) => {}
+        if journal.prompt_entry(&mut InquirePrompter::new(), journal_prompt.clone()).is_ok() {
+            break;
         }
     }
 
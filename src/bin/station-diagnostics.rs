@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::env;
+use std::process;
+use std::time::Duration;
+
+// project
+use found_terminal::protocol::constants::{DP_LOW, FLAG_SEND_DIAGNOSTICS};
+use found_terminal::protocol::diagnostics::parse_priority;
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+use found_terminal::protocol::port_address::PortAddressRange;
+use found_terminal::protocol::udp::Endpoints;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::mission as tl_mission;
+
+
+struct Options {
+    endpoints: Endpoints,
+    range: PortAddressRange,
+    priority: u8,
+    interval: Duration,
+    rounds: u32,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        endpoints: Endpoints::new(),
+        range: PortAddressRange::all(),
+        priority: DP_LOW,
+        interval: Duration::from_secs(5),
+        rounds: 0,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            _ if options.endpoints.parse_argument(arg.as_str(), &mut args)? => {},
+            "--range" => {
+                let value = args.next().ok_or("--range needs a port address or BOTTOM-TOP")?;
+                options.range = PortAddressRange::parse(value.as_str())?;
+            },
+            "--priority" => {
+                let value = args.next().ok_or("--priority needs a level")?;
+                options.priority = parse_priority(value.as_str())?;
+            },
+            "--interval" => {
+                let value = args.next().ok_or("--interval needs a number of seconds")?;
+                match value.parse::<u64>() {
+                    Ok(v) if v > 0 => { options.interval = Duration::from_secs(v); },
+                    _ => { return Err(format!("invalid interval: {value}")); }
+                }
+            },
+            "--rounds" => {
+                let value = args.next().ok_or("--rounds needs a number")?;
+                match value.parse::<u32>() {
+                    Ok(v) => { options.rounds = v; },
+                    Err(_) => { return Err(format!("invalid rounds: {value}")); }
+                }
+            },
+            _ => { return Err(format!("unknown argument: {arg}")); }
+        }
+    }
+
+    options.endpoints.default_targets()?;
+
+    return Ok(options);
+}
+
+fn main() {
+    let options = match parse_options() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_station::argument_error(e);
+            process::exit(2);
+        }
+    };
+
+    let mut listener = match MissionControl::new(options.endpoints.bind.as_str(), options.endpoints.targets) {
+        Ok(v) => v,
+        Err(e) => {
+            tl_mission::mission_error(e);
+            process::exit(1);
+        }
+    };
+
+    let mut ping = PingPacket::new();
+    ping.flags |= FLAG_SEND_DIAGNOSTICS;
+    ping.diag_priority = options.priority;
+    ping.set_target_range(options.range);
+    listener.set_ping(ping);
+
+    // the ping is repeated every interval, that keeps the subscription alive on restarted stations
+    let mut round: u32 = 0;
+    while options.rounds == 0 || round < options.rounds {
+        round = round.saturating_add(1);
+
+        if let Err(e) = listener.ping_all() {
+            tl_mission::mission_error(e);
+        }
+
+        match listener.poll(options.interval) {
+            Ok(updates) => {
+                for update in updates {
+                    if let MissionUpdate::Diagnostic(address, packet) = update {
+                        tl_mission::diagnostic(address.to_string(), &packet);
+                    }
+                }
+            },
+            Err(e) => { tl_mission::mission_error(e); }
+        }
+    }
+}
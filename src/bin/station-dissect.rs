@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::env;
+use std::fs;
+use std::io::{self, BufRead};
+use std::path::PathBuf;
+use std::process;
+
+// project
+use found_terminal::protocol::auth;
+use found_terminal::protocol::capture::{read_capture, parse_hex};
+use found_terminal::protocol::dissect::{dissect, is_station_packet};
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::dissect as tl_dissect;
+
+
+struct Options {
+    pcap: Option<PathBuf>,
+    key: Option<PathBuf>,
+}
+
+fn parse_options() -> Result<Options, String> {
+    let mut options = Options {
+        pcap: None,
+        key: None,
+    };
+
+    let mut args = env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--pcap" => {
+                options.pcap = Some(PathBuf::from(args.next().ok_or("--pcap needs a file")?));
+            },
+            "--key" => {
+                options.key = Some(PathBuf::from(args.next().ok_or("--key needs a file")?));
+            },
+            _ => { return Err(format!("unknown argument: {arg}")); }
+        }
+    }
+
+    return Ok(options);
+}
+
+fn main() {
+    let options = match parse_options() {
+        Ok(v) => v,
+        Err(e) => {
+            tl_station::argument_error(e);
+            process::exit(2);
+        }
+    };
+
+    let key = match &options.key {
+        Some(path) => match auth::load_key(path) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                tl_dissect::dissect_error(e);
+                process::exit(1);
+            }
+        },
+        None => None,
+    };
+
+    let mut packets: usize = 0;
+    let mut errors: usize = 0;
+
+    match &options.pcap {
+        Some(path) => {
+            let bytes = match fs::read(path) {
+                Ok(v) => v,
+                Err(e) => {
+                    tl_dissect::dissect_error(format!("{}: {}", path.display(), e));
+                    process::exit(1);
+                }
+            };
+            let datagrams = match read_capture(&bytes) {
+                Ok(v) => v,
+                Err(e) => {
+                    tl_dissect::dissect_error(format!("{}: {}", path.display(), e));
+                    process::exit(1);
+                }
+            };
+
+            // other traffic on the same capture is not ours to report
+            for datagram in datagrams.iter().filter(|v| is_station_packet(&v.payload)) {
+                packets += 1;
+                let dissection = dissect(&datagram.payload, key.as_deref());
+                if dissection.result.is_err() {
+                    errors += 1;
+                }
+                tl_dissect::packet(packets, Some(datagram), &dissection);
+            }
+        },
+        None => {
+            // one packet per line, '#' starts a comment
+            for (number, line) in io::stdin().lock().lines().enumerate() {
+                let line = match line {
+                    Ok(v) => v,
+                    Err(e) => {
+                        tl_dissect::dissect_error(e.to_string());
+                        process::exit(1);
+                    }
+                };
+                let hex = line.split('#').next().unwrap_or("").trim();
+                if hex.is_empty() {
+                    continue;
+                }
+
+                let bytes = match parse_hex(hex) {
+                    Ok(v) => v,
+                    Err(e) => {
+                        errors += 1;
+                        tl_dissect::input_error(number + 1, e);
+                        continue;
+                    }
+                };
+
+                packets += 1;
+                let dissection = dissect(&bytes, key.as_deref());
+                if dissection.result.is_err() {
+                    errors += 1;
+                }
+                tl_dissect::packet(packets, None, &dissection);
+            }
+        },
+    }
+
+    tl_dissect::summary(packets, errors);
+}
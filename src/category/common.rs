@@ -5,7 +5,15 @@
 // dependencies
 use rand::Rng;
 use rand::seq::SliceRandom;
-use rand::thread_rng;
+
+// project
+use crate::station::rng::StationRng;
+use crate::station::components;
+use crate::station::components::{Name, ModuleCounts,
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
+use crate::section::common::Section;
+use crate::terminalisp::value::Value;
 
 
 pub trait SectionsAvailable {
@@ -17,8 +25,45 @@ pub trait SectionCounts {
     fn installed_sections(&self) -> u16;
 }
 
-pub fn random_bools(count: u16, min_count: u16, max_count: u16) -> Vec<bool> {
-    let mut rng = thread_rng();
+pub trait Sections {
+    fn sections(&self) -> Vec<&dyn Section>;
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section>;
+}
+
+pub trait Category: Name + components::SectionCounts + ModuleCounts + UpdateModules + Sections
+                    + Status + BreakSomething + Repair + PowerDown + PortAddress {}
+
+impl<T> Category for T
+    where T: Name + components::SectionCounts + ModuleCounts + UpdateModules + Sections
+             + Status + BreakSomething + Repair + PowerDown + PortAddress {}
+
+// only installed sections are listed
+pub fn category_status<T>(category: &T) -> Value
+    where T: Name + components::SectionCounts + ModuleCounts + Sections + ?Sized {
+
+    let sections = category.sections()
+        .into_iter()
+        .filter(|section| section.installed())
+        .map(|section| section.status())
+        .collect();
+
+    return category_form(category, sections);
+}
+
+// the category's own fields around an already chosen list of section forms
+pub fn category_form<T>(category: &T, sections: Vec<Value>) -> Value
+    where T: Name + components::SectionCounts + ModuleCounts + ?Sized {
+
+    return Value::form("category", vec![
+        ("name", Value::string(category.name().as_str())),
+        ("installed-sections", Value::integer(category.installed_sections())),
+        ("total-modules", Value::integer(category.total_modules())),
+        ("active-modules", Value::integer(category.active_modules())),
+        ("sections", Value::List(sections)),
+    ]);
+}
+
+pub fn random_bools(rng: &mut StationRng, count: u16, min_count: u16, max_count: u16) -> Vec<bool> {
     let mut result: Vec<bool> = vec![];
     let mut random_count: u16 = count;
 
@@ -41,17 +86,17 @@ pub fn random_bools(count: u16, min_count: u16, max_count: u16) -> Vec<bool> {
         result.push(rng.gen_bool(0.5));
     }
 
-    result.shuffle(&mut rng);
+    result.shuffle(rng);
 
     return result;
 }
 
-pub fn random_positions(min_count: u8, max_count: u8) -> Vec<u8> {
-    let count: u8 = thread_rng().gen_range(min_count..max_count);
+pub fn random_positions(rng: &mut StationRng, min_count: u8, max_count: u8) -> Vec<u8> {
+    let count: u8 = rng.gen_range(min_count..max_count);
 
     let mut positions: Vec<u8> = vec![];
     for _ in 1..=count {
-        positions.push(thread_rng().gen_range(1..=max_count));
+        positions.push(rng.gen_range(1..=max_count));
     }
 
     return positions;
@@ -4,23 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
 use crate::section::comms;
-use crate::section::common::Installed;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::section::common::{Installed, Section};
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 
 // module
-use crate::category::common::{SectionsAvailable, random_bools};
+use crate::category::common::{SectionsAvailable, Sections, category_status, random_bools};
 
 
 pub struct CommsCategory {
     _name: &'static str,
+    _slug: &'static str,
 
     pub section_antenna: comms::AntennaSection,
     pub section_tracking: comms::TrackingSection,
@@ -30,6 +32,8 @@ pub struct CommsCategory {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _port_address: Option<u16>,
 }
 
 impl SectionsAvailable for CommsCategory {
@@ -37,11 +41,16 @@ impl SectionsAvailable for CommsCategory {
 }
 
 impl CommsCategory {
-    pub fn new(min_count: u16, max_count: u16) -> Self {
-        let installation: Vec<bool> = random_bools(CommsCategory::SECTIONS_AVAILABLE, min_count, max_count);
+    pub fn new(min_count: u16, max_count: u16, rng: &mut StationRng) -> Self {
+        let installation: Vec<bool> = random_bools(rng, CommsCategory::SECTIONS_AVAILABLE, min_count, max_count);
+
+        return CommsCategory::with_installation(installation);
+    }
 
+    pub fn with_installation(installation: Vec<bool>) -> Self {
         let mut section_group = CommsCategory {
             _name: "Comms Category",
+            _slug: "comms",
 
             section_antenna: comms::AntennaSection::new(installation[0]),
             section_tracking: comms::TrackingSection::new(installation[1]),
@@ -51,6 +60,8 @@ impl CommsCategory {
             _installed_sections: installation.iter().filter(|x| x == &&true).count() as u16,
             _total_modules: 0,
             _active_modules: 0,
+
+            _port_address: None,
         };
 
         if section_group.section_antenna.installed() {
@@ -69,7 +80,14 @@ impl CommsCategory {
     }
 }
 
-impl Name for CommsCategory { fn name(&self) -> String { self._name.to_string() } }
+impl PortAddress for CommsCategory {
+    fn port_address(&self) -> Option<u16> { self._port_address }
+    fn set_port_address(&mut self, port_address: Option<u16>) { self._port_address = port_address; }
+}
+impl Name for CommsCategory {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl SectionCounts for CommsCategory {
     fn total_sections(&self) -> u16 { self._total_sections }
     fn installed_sections(&self) -> u16 { self._installed_sections }
@@ -93,58 +111,45 @@ impl UpdateModules for CommsCategory {
     }
 }
 
-impl Status for CommsCategory {
-    fn status(&self, indent: u8) -> String {
-        let mut modules: Vec<String> = vec![];
+impl Sections for CommsCategory {
+    fn sections(&self) -> Vec<&dyn Section> {
+        vec![
+            &self.section_antenna,
+            &self.section_tracking,
+            &self.section_transponder
+        ]
+    }
 
-        if self.section_antenna.installed() {
-            modules.push(self.section_antenna.status(indent + 2));
-        }
-        if self.section_tracking.installed() {
-            modules.push(self.section_tracking.status(indent + 2));
-        }
-        if self.section_transponder.installed() {
-            modules.push(self.section_transponder.status(indent + 2));
-        }
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section> {
+        vec![
+            &mut self.section_antenna,
+            &mut self.section_tracking,
+            &mut self.section_transponder
+        ]
+    }
+}
 
-        tl_station::status(
-            String::from("category"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":installed-sections"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.installed_sections()),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            true,
-            String::from(":sections"),
-            modules,
-            indent
-        )
+impl Status for CommsCategory {
+    fn status(&self) -> Value {
+        category_status(self)
     }
 }
 
 impl BreakSomething for CommsCategory {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=CommsCategory::SECTIONS_AVAILABLE) {
-            1 => { broken_module = self.section_antenna.break_something(); },
-            2 => { broken_module = self.section_tracking.break_something(); },
-            3 => { broken_module = self.section_transponder.break_something(); },
+        match rng.gen_range(1..=CommsCategory::SECTIONS_AVAILABLE) {
+            1 => { broken_module = self.section_antenna.break_something(rng); },
+            2 => { broken_module = self.section_tracking.break_something(rng); },
+            3 => { broken_module = self.section_transponder.break_something(rng); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
         match broken_module {
-            Ok(v) => { Ok(v) },
+            Ok(v) => { Ok(format!("{} / {}", self.name(), v)) },
             Err(e) => { Err(e) },
         }
     }
@@ -155,7 +160,7 @@ impl Repair for CommsCategory {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.section_antenna.repair_display(),
             self.section_tracking.repair_display(),
@@ -168,18 +173,21 @@ impl Repair for CommsCategory {
         if self.section_transponder.repairable() { options.push(prompts[2].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section to repair:", options) {
+        match prompter.select("Select section to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.section_antenna.repair(); },
-            _ if chosen == prompts[1] => { self.section_tracking.repair(); },
-            _ if chosen == prompts[2] => { self.section_transponder.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.section_antenna.repair(prompter); },
+            _ if chosen == prompts[1] => { repaired = self.section_tracking.repair(prompter); },
+            _ if chosen == prompts[2] => { repaired = self.section_transponder.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return repaired.map(|v| format!("{} / {}", self.name(), v));
     }
 }
 
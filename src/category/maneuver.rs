@@ -4,22 +4,24 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
 use crate::section::maneuver;
-use crate::section::common::Installed;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::section::common::{Installed, Section};
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::category::common::{SectionsAvailable, random_bools};
+use crate::category::common::{SectionsAvailable, Sections, category_status, random_bools};
 
 
 pub struct ManeuverCategory {
     _name: &'static str,
+    _slug: &'static str,
 
     pub section_basic_maneuver: maneuver::BasicManeuverSection,
     pub section_maneuver_with_docking: maneuver::ManeuverWithDockingSection,
@@ -28,6 +30,8 @@ pub struct ManeuverCategory {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _port_address: Option<u16>,
 }
 
 impl SectionsAvailable for ManeuverCategory {
@@ -35,11 +39,16 @@ impl SectionsAvailable for ManeuverCategory {
 }
 
 impl ManeuverCategory {
-    pub fn new(min_count: u16, max_count: u16) -> Self {
-        let installation: Vec<bool> = random_bools(ManeuverCategory::SECTIONS_AVAILABLE, min_count, max_count);
+    pub fn new(min_count: u16, max_count: u16, rng: &mut StationRng) -> Self {
+        let installation: Vec<bool> = random_bools(rng, ManeuverCategory::SECTIONS_AVAILABLE, min_count, max_count);
+
+        return ManeuverCategory::with_installation(installation);
+    }
 
+    pub fn with_installation(installation: Vec<bool>) -> Self {
         let mut section_group = ManeuverCategory {
             _name: "Maneuver Category",
+            _slug: "maneuver",
 
             section_basic_maneuver: maneuver::BasicManeuverSection::new(installation[0]),
             section_maneuver_with_docking: maneuver::ManeuverWithDockingSection::new(installation[1]),
@@ -48,6 +57,8 @@ impl ManeuverCategory {
             _installed_sections: installation.iter().filter(|x| x == &&true).count() as u16,
             _total_modules: 0,
             _active_modules: 0,
+
+            _port_address: None,
         };
 
         if section_group.section_basic_maneuver.installed() {
@@ -63,7 +74,14 @@ impl ManeuverCategory {
     }
 }
 
-impl Name for ManeuverCategory { fn name(&self) -> String { self._name.to_string() } }
+impl PortAddress for ManeuverCategory {
+    fn port_address(&self) -> Option<u16> { self._port_address }
+    fn set_port_address(&mut self, port_address: Option<u16>) { self._port_address = port_address; }
+}
+impl Name for ManeuverCategory {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl SectionCounts for ManeuverCategory {
     fn total_sections(&self) -> u16 { self._total_sections }
     fn installed_sections(&self) -> u16 { self._installed_sections }
@@ -86,54 +104,42 @@ impl UpdateModules for ManeuverCategory {
     }
 }
 
-impl Status for ManeuverCategory {
-    fn status(&self, indent: u8) -> String {
-        let mut modules: Vec<String> = vec![];
+impl Sections for ManeuverCategory {
+    fn sections(&self) -> Vec<&dyn Section> {
+        vec![
+            &self.section_basic_maneuver,
+            &self.section_maneuver_with_docking
+        ]
+    }
 
-        if self.section_basic_maneuver.installed() {
-            modules.push(self.section_basic_maneuver.status(indent + 2));
-        }
-        if self.section_maneuver_with_docking.installed() {
-            modules.push(self.section_maneuver_with_docking.status(indent + 2));
-        }
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section> {
+        vec![
+            &mut self.section_basic_maneuver,
+            &mut self.section_maneuver_with_docking
+        ]
+    }
+}
 
-        tl_station::status(
-            String::from("category"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":installed-sections"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.installed_sections()),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            true,
-            String::from(":sections"),
-            modules,
-            indent
-        )
+impl Status for ManeuverCategory {
+    fn status(&self) -> Value {
+        category_status(self)
     }
 }
 
 impl BreakSomething for ManeuverCategory {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=ManeuverCategory::SECTIONS_AVAILABLE) {
-            1 => { broken_module = self.section_basic_maneuver.break_something(); },
-            2 => { broken_module = self.section_maneuver_with_docking.break_something(); },
+        match rng.gen_range(1..=ManeuverCategory::SECTIONS_AVAILABLE) {
+            1 => { broken_module = self.section_basic_maneuver.break_something(rng); },
+            2 => { broken_module = self.section_maneuver_with_docking.break_something(rng); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
         match broken_module {
-            Ok(v) => { Ok(v) },
+            Ok(v) => { Ok(format!("{} / {}", self.name(), v)) },
             Err(e) => { Err(e) },
         }
     }
@@ -144,7 +150,7 @@ impl Repair for ManeuverCategory {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.section_basic_maneuver.repair_display(),
             self.section_maneuver_with_docking.repair_display(),
@@ -155,17 +161,20 @@ impl Repair for ManeuverCategory {
         if self.section_maneuver_with_docking.repairable() { options.push(prompts[1].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section to repair:", options) {
+        match prompter.select("Select section to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.section_basic_maneuver.repair(); },
-            _ if chosen == prompts[1] => { self.section_maneuver_with_docking.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.section_basic_maneuver.repair(prompter); },
+            _ if chosen == prompts[1] => { repaired = self.section_maneuver_with_docking.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return repaired.map(|v| format!("{} / {}", self.name(), v));
     }
 }
 
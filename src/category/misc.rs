@@ -4,22 +4,24 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
 use crate::section::misc;
-use crate::section::common::Installed;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::section::common::{Installed, Section};
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::category::common::{SectionsAvailable, random_bools};
+use crate::category::common::{SectionsAvailable, Sections, category_status, random_bools};
 
 
 pub struct MiscCategory {
     _name: &'static str,
+    _slug: &'static str,
 
     pub section_cargo_bay: misc::CargoBaySection,
 
@@ -27,6 +29,8 @@ pub struct MiscCategory {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _port_address: Option<u16>,
 }
 
 impl SectionsAvailable for MiscCategory {
@@ -34,11 +38,16 @@ impl SectionsAvailable for MiscCategory {
 }
 
 impl MiscCategory {
-    pub fn new(min_count: u16, max_count: u16) -> Self {
-        let installation: Vec<bool> = random_bools(MiscCategory::SECTIONS_AVAILABLE, min_count, max_count);
+    pub fn new(min_count: u16, max_count: u16, rng: &mut StationRng) -> Self {
+        let installation: Vec<bool> = random_bools(rng, MiscCategory::SECTIONS_AVAILABLE, min_count, max_count);
+
+        return MiscCategory::with_installation(installation);
+    }
 
+    pub fn with_installation(installation: Vec<bool>) -> Self {
         let mut section_group = MiscCategory {
             _name: "Misc Category",
+            _slug: "misc",
 
             section_cargo_bay: misc::CargoBaySection::new(installation[0]),
 
@@ -46,6 +55,8 @@ impl MiscCategory {
             _installed_sections: installation.iter().filter(|x| x == &&true).count() as u16,
             _total_modules: 0,
             _active_modules: 0,
+
+            _port_address: None,
         };
 
         if section_group.section_cargo_bay.installed() {
@@ -58,7 +69,14 @@ impl MiscCategory {
     }
 }
 
-impl Name for MiscCategory { fn name(&self) -> String { self._name.to_string() } }
+impl PortAddress for MiscCategory {
+    fn port_address(&self) -> Option<u16> { self._port_address }
+    fn set_port_address(&mut self, port_address: Option<u16>) { self._port_address = port_address; }
+}
+impl Name for MiscCategory {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl SectionCounts for MiscCategory {
     fn total_sections(&self) -> u16 { self._total_sections }
     fn installed_sections(&self) -> u16 { self._installed_sections }
@@ -80,50 +98,39 @@ impl UpdateModules for MiscCategory {
     }
 }
 
-impl Status for MiscCategory {
-    fn status(&self, indent: u8) -> String {
-        let mut modules: Vec<String> = vec![];
+impl Sections for MiscCategory {
+    fn sections(&self) -> Vec<&dyn Section> {
+        vec![
+            &self.section_cargo_bay
+        ]
+    }
 
-        if self.section_cargo_bay.installed() {
-            modules.push(self.section_cargo_bay.status(indent + 2));
-        }
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section> {
+        vec![
+            &mut self.section_cargo_bay
+        ]
+    }
+}
 
-        tl_station::status(
-            String::from("category"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":installed-sections"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.installed_sections()),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            true,
-            String::from(":sections"),
-            modules,
-            indent
-        )
+impl Status for MiscCategory {
+    fn status(&self) -> Value {
+        category_status(self)
     }
 }
 
 impl BreakSomething for MiscCategory {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=MiscCategory::SECTIONS_AVAILABLE) {
-            1 => { broken_module = self.section_cargo_bay.break_something(); },
+        match rng.gen_range(1..=MiscCategory::SECTIONS_AVAILABLE) {
+            1 => { broken_module = self.section_cargo_bay.break_something(rng); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
         match broken_module {
-            Ok(v) => { Ok(v) },
+            Ok(v) => { Ok(format!("{} / {}", self.name(), v)) },
             Err(e) => { Err(e) },
         }
     }
@@ -134,7 +141,7 @@ impl Repair for MiscCategory {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.section_cargo_bay.repair_display(),
         ];
@@ -143,16 +150,19 @@ impl Repair for MiscCategory {
         if self.section_cargo_bay.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section to repair:", options) {
+        match prompter.select("Select section to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.section_cargo_bay.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.section_cargo_bay.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return repaired.map(|v| format!("{} / {}", self.name(), v));
     }
 }
 
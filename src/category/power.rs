@@ -4,22 +4,24 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
 use crate::section::power;
-use crate::section::common::Installed;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::section::common::{Installed, Section};
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::category::common::{SectionsAvailable, random_bools};
+use crate::category::common::{SectionsAvailable, Sections, category_status, random_bools};
 
 
 pub struct PowerCategory {
     _name: &'static str,
+    _slug: &'static str,
 
     pub section_fossil_power: power::FossilPowerSection,
     pub section_fusion_power: power::FusionPowerSection,
@@ -31,6 +33,8 @@ pub struct PowerCategory {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _port_address: Option<u16>,
 }
 
 impl SectionsAvailable for PowerCategory {
@@ -38,11 +42,16 @@ impl SectionsAvailable for PowerCategory {
 }
 
 impl PowerCategory {
-    pub fn new(min_count: u16, max_count: u16) -> Self {
-        let installation: Vec<bool> = random_bools(PowerCategory::SECTIONS_AVAILABLE, min_count, max_count);
+    pub fn new(min_count: u16, max_count: u16, rng: &mut StationRng) -> Self {
+        let installation: Vec<bool> = random_bools(rng, PowerCategory::SECTIONS_AVAILABLE, min_count, max_count);
+
+        return PowerCategory::with_installation(installation);
+    }
 
+    pub fn with_installation(installation: Vec<bool>) -> Self {
         let mut section_group = PowerCategory {
             _name: "Power Category",
+            _slug: "power",
 
             section_fossil_power: power::FossilPowerSection::new(installation[0]),
             section_fusion_power: power::FusionPowerSection::new(installation[1]),
@@ -54,6 +63,8 @@ impl PowerCategory {
             _installed_sections: installation.iter().filter(|x| x == &&true).count() as u16,
             _total_modules: 0,
             _active_modules: 0,
+
+            _port_address: None,
         };
 
         if section_group.section_fossil_power.installed() {
@@ -78,7 +89,14 @@ impl PowerCategory {
     }
 }
 
-impl Name for PowerCategory { fn name(&self) -> String { self._name.to_string() } }
+impl PortAddress for PowerCategory {
+    fn port_address(&self) -> Option<u16> { self._port_address }
+    fn set_port_address(&mut self, port_address: Option<u16>) { self._port_address = port_address; }
+}
+impl Name for PowerCategory {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl SectionCounts for PowerCategory {
     fn total_sections(&self) -> u16 { self._total_sections }
     fn installed_sections(&self) -> u16 { self._installed_sections }
@@ -104,66 +122,51 @@ impl UpdateModules for PowerCategory {
     }
 }
 
-impl Status for PowerCategory {
-    fn status(&self, indent: u8) -> String {
-        let mut modules: Vec<String> = vec![];
+impl Sections for PowerCategory {
+    fn sections(&self) -> Vec<&dyn Section> {
+        vec![
+            &self.section_fossil_power,
+            &self.section_fusion_power,
+            &self.section_nuclear_power,
+            &self.section_radiation_power,
+            &self.section_solar_power
+        ]
+    }
 
-        if self.section_fossil_power.installed() {
-            modules.push(self.section_fossil_power.status(indent + 2));
-        }
-        if self.section_fusion_power.installed() {
-            modules.push(self.section_fusion_power.status(indent + 2));
-        }
-        if self.section_nuclear_power.installed() {
-            modules.push(self.section_nuclear_power.status(indent + 2));
-        }
-        if self.section_radiation_power.installed() {
-            modules.push(self.section_radiation_power.status(indent + 2));
-        }
-        if self.section_solar_power.installed() {
-            modules.push(self.section_solar_power.status(indent + 2));
-        }
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section> {
+        vec![
+            &mut self.section_fossil_power,
+            &mut self.section_fusion_power,
+            &mut self.section_nuclear_power,
+            &mut self.section_radiation_power,
+            &mut self.section_solar_power
+        ]
+    }
+}
 
-        tl_station::status(
-            String::from("category"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":installed-sections"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.installed_sections()),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            true,
-            String::from(":sections"),
-            modules,
-            indent
-        )
+impl Status for PowerCategory {
+    fn status(&self) -> Value {
+        category_status(self)
     }
 }
 
 impl BreakSomething for PowerCategory {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=PowerCategory::SECTIONS_AVAILABLE) {
-            1 => { broken_module = self.section_fossil_power.break_something(); },
-            2 => { broken_module = self.section_fusion_power.break_something(); },
-            3 => { broken_module = self.section_nuclear_power.break_something(); },
-            4 => { broken_module = self.section_radiation_power.break_something(); },
-            5 => { broken_module = self.section_solar_power.break_something(); },
+        match rng.gen_range(1..=PowerCategory::SECTIONS_AVAILABLE) {
+            1 => { broken_module = self.section_fossil_power.break_something(rng); },
+            2 => { broken_module = self.section_fusion_power.break_something(rng); },
+            3 => { broken_module = self.section_nuclear_power.break_something(rng); },
+            4 => { broken_module = self.section_radiation_power.break_something(rng); },
+            5 => { broken_module = self.section_solar_power.break_something(rng); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
         match broken_module {
-            Ok(v) => { Ok(v) },
+            Ok(v) => { Ok(format!("{} / {}", self.name(), v)) },
             Err(e) => { Err(e) },
         }
     }
@@ -174,7 +177,7 @@ impl Repair for PowerCategory {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.section_fossil_power.repair_display(),
             self.section_fusion_power.repair_display(),
@@ -191,20 +194,23 @@ impl Repair for PowerCategory {
         if self.section_solar_power.repairable()     { options.push(prompts[4].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section to repair:", options) {
+        match prompter.select("Select section to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.section_fossil_power.repair(); },
-            _ if chosen == prompts[1] => { self.section_fusion_power.repair(); },
-            _ if chosen == prompts[2] => { self.section_nuclear_power.repair(); },
-            _ if chosen == prompts[3] => { self.section_radiation_power.repair(); },
-            _ if chosen == prompts[4] => { self.section_solar_power.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.section_fossil_power.repair(prompter); },
+            _ if chosen == prompts[1] => { repaired = self.section_fusion_power.repair(prompter); },
+            _ if chosen == prompts[2] => { repaired = self.section_nuclear_power.repair(prompter); },
+            _ if chosen == prompts[3] => { repaired = self.section_radiation_power.repair(prompter); },
+            _ if chosen == prompts[4] => { repaired = self.section_solar_power.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return repaired.map(|v| format!("{} / {}", self.name(), v));
     }
 }
 
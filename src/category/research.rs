@@ -4,22 +4,24 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown,
+                                 PortAddress};
 use crate::section::research;
-use crate::section::common::Installed;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::section::common::{Installed, Section};
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::category::common::{SectionsAvailable, random_bools};
+use crate::category::common::{SectionsAvailable, Sections, category_status, random_bools};
 
 
 pub struct ResearchCategory {
     _name: &'static str,
+    _slug: &'static str,
 
     pub section_astronomy: research::AstronomySection,
     pub section_greenhouse: research::GreenhouseSection,
@@ -29,6 +31,8 @@ pub struct ResearchCategory {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _port_address: Option<u16>,
 }
 
 impl SectionsAvailable for ResearchCategory {
@@ -36,11 +40,16 @@ impl SectionsAvailable for ResearchCategory {
 }
 
 impl ResearchCategory {
-    pub fn new(min_count: u16, max_count: u16) -> Self {
-        let installation: Vec<bool> = random_bools(ResearchCategory::SECTIONS_AVAILABLE, min_count, max_count);
+    pub fn new(min_count: u16, max_count: u16, rng: &mut StationRng) -> Self {
+        let installation: Vec<bool> = random_bools(rng, ResearchCategory::SECTIONS_AVAILABLE, min_count, max_count);
+
+        return ResearchCategory::with_installation(installation);
+    }
 
+    pub fn with_installation(installation: Vec<bool>) -> Self {
         let mut section_group = ResearchCategory {
             _name: "Research Category",
+            _slug: "research",
 
             section_astronomy: research::AstronomySection::new(installation[0]),
             section_greenhouse: research::GreenhouseSection::new(installation[1]),
@@ -50,6 +59,8 @@ impl ResearchCategory {
             _installed_sections: installation.iter().filter(|x| x == &&true).count() as u16,
             _total_modules: 0,
             _active_modules: 0,
+
+            _port_address: None,
         };
 
         if section_group.section_astronomy.installed() {
@@ -68,7 +79,14 @@ impl ResearchCategory {
     }
 }
 
-impl Name for ResearchCategory { fn name(&self) -> String { self._name.to_string() } }
+impl PortAddress for ResearchCategory {
+    fn port_address(&self) -> Option<u16> { self._port_address }
+    fn set_port_address(&mut self, port_address: Option<u16>) { self._port_address = port_address; }
+}
+impl Name for ResearchCategory {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl SectionCounts for ResearchCategory {
     fn total_sections(&self) -> u16 { self._total_sections }
     fn installed_sections(&self) -> u16 { self._installed_sections }
@@ -92,58 +110,45 @@ impl UpdateModules for ResearchCategory {
     }
 }
 
-impl Status for ResearchCategory {
-    fn status(&self, indent: u8) -> String {
-        let mut modules: Vec<String> = vec![];
+impl Sections for ResearchCategory {
+    fn sections(&self) -> Vec<&dyn Section> {
+        vec![
+            &self.section_astronomy,
+            &self.section_greenhouse,
+            &self.section_weather_observation
+        ]
+    }
 
-        if self.section_astronomy.installed() {
-            modules.push(self.section_astronomy.status(indent + 2));
-        }
-        if self.section_greenhouse.installed() {
-            modules.push(self.section_greenhouse.status(indent + 2));
-        }
-        if self.section_weather_observation.installed() {
-            modules.push(self.section_weather_observation.status(indent + 2));
-        }
+    fn sections_mut(&mut self) -> Vec<&mut dyn Section> {
+        vec![
+            &mut self.section_astronomy,
+            &mut self.section_greenhouse,
+            &mut self.section_weather_observation
+        ]
+    }
+}
 
-        tl_station::status(
-            String::from("category"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":installed-sections"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.installed_sections()),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            true,
-            String::from(":sections"),
-            modules,
-            indent
-        )
+impl Status for ResearchCategory {
+    fn status(&self) -> Value {
+        category_status(self)
     }
 }
 
 impl BreakSomething for ResearchCategory {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=ResearchCategory::SECTIONS_AVAILABLE) {
-            1 => { broken_module = self.section_astronomy.break_something(); },
-            2 => { broken_module = self.section_greenhouse.break_something(); },
-            3 => { broken_module = self.section_weather_observation.break_something(); },
+        match rng.gen_range(1..=ResearchCategory::SECTIONS_AVAILABLE) {
+            1 => { broken_module = self.section_astronomy.break_something(rng); },
+            2 => { broken_module = self.section_greenhouse.break_something(rng); },
+            3 => { broken_module = self.section_weather_observation.break_something(rng); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
         match broken_module {
-            Ok(v) => { Ok(v) },
+            Ok(v) => { Ok(format!("{} / {}", self.name(), v)) },
             Err(e) => { Err(e) },
         }
     }
@@ -154,7 +159,7 @@ impl Repair for ResearchCategory {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.section_astronomy.repair_display(),
             self.section_greenhouse.repair_display(),
@@ -167,18 +172,21 @@ impl Repair for ResearchCategory {
         if self.section_weather_observation.repairable() { options.push(prompts[2].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section to repair:", options) {
+        match prompter.select("Select section to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.section_astronomy.repair(); },
-            _ if chosen == prompts[1] => { self.section_greenhouse.repair(); },
-            _ if chosen == prompts[2] => { self.section_weather_observation.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.section_astronomy.repair(prompter); },
+            _ if chosen == prompts[1] => { repaired = self.section_greenhouse.repair(prompter); },
+            _ if chosen == prompts[2] => { repaired = self.section_weather_observation.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return repaired.map(|v| format!("{} / {}", self.name(), v));
     }
 }
 
@@ -0,0 +1,13 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Effect {
+    BreakModules(u8),
+    RestoreModule,
+    PowerDown,
+    Message(String),
+    Reroll,
+}
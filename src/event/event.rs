@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use rand::Rng;
+use strum::IntoEnumIterator;
+use strum_macros::{Display, EnumIter, EnumString};
+
+// project
+use crate::station::rng::StationRng;
+
+// module
+use crate::event::effect::Effect;
+
+
+// one day in EVENT_CHANCE_DENOMINATOR has something happen
+const EVENT_CHANCE_NUMERATOR: u32 = 1;
+const EVENT_CHANCE_DENOMINATOR: u32 = 4;
+const MAX_REROLLS: u8 = 3;
+
+#[derive(Display, EnumString, EnumIter)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum Event {
+    AModuleComesBack, // A module comes back!
+    DangerousSolarFlare, // Dangerous solar flare
+    DeepSpacePulse, // Deep space pulse
+    DeepSpaceWhaleSound, // Deep-space whale sound!?
+    EarthIsDead, // Earth is DEAD
+    MessageFromEarth, // Message from Earth!
+    MoonBaseSignal, // Moon base signal!
+    Paradox,
+    PowerSurgeKillsAModule, // Power surge kills a module
+    ReConnectionToAnOldSatelliteNetwork, // Re-connection to an old satellite network
+    NullEvent, // Reroll
+    TotalPowerFailure, // Total power failure - all modules die early
+}
+
+impl Event {
+    pub fn description(&self) -> &'static str {
+        match self {
+            Event::AModuleComesBack => "A module comes back!",
+            Event::DangerousSolarFlare => "Dangerous solar flare",
+            Event::DeepSpacePulse => "Deep space pulse",
+            Event::DeepSpaceWhaleSound => "Deep-space whale sound!?",
+            Event::EarthIsDead => "Earth is DEAD",
+            Event::MessageFromEarth => "Message from Earth!",
+            Event::MoonBaseSignal => "Moon base signal!",
+            Event::Paradox => "Paradox",
+            Event::PowerSurgeKillsAModule => "Power surge kills a module",
+            Event::ReConnectionToAnOldSatelliteNetwork => "Re-connection to an old satellite network",
+            Event::NullEvent => "Reroll",
+            Event::TotalPowerFailure => "Total power failure - all modules die early",
+        }
+    }
+
+    // relative to the sum of all weights
+    pub fn weight(&self) -> u32 {
+        match self {
+            Event::AModuleComesBack => 10,
+            Event::DangerousSolarFlare => 6,
+            Event::DeepSpacePulse => 5,
+            Event::DeepSpaceWhaleSound => 3,
+            Event::EarthIsDead => 1,
+            Event::MessageFromEarth => 6,
+            Event::MoonBaseSignal => 4,
+            Event::Paradox => 2,
+            Event::PowerSurgeKillsAModule => 10,
+            Event::ReConnectionToAnOldSatelliteNetwork => 4,
+            Event::NullEvent => 8,
+            Event::TotalPowerFailure => 1,
+        }
+    }
+
+    pub fn effect(&self, rng: &mut StationRng) -> Effect {
+        match self {
+            Event::AModuleComesBack => Effect::RestoreModule,
+            Event::DangerousSolarFlare => Effect::BreakModules(rng.gen_range(1..=3)),
+            Event::PowerSurgeKillsAModule => Effect::BreakModules(1),
+            Event::TotalPowerFailure => Effect::PowerDown,
+            Event::NullEvent => Effect::Reroll,
+            Event::DeepSpacePulse
+            | Event::DeepSpaceWhaleSound
+            | Event::EarthIsDead
+            | Event::MessageFromEarth
+            | Event::MoonBaseSignal
+            | Event::Paradox
+            | Event::ReConnectionToAnOldSatelliteNetwork => {
+                Effect::Message(String::from(self.description()))
+            },
+        }
+    }
+}
+
+pub fn total_weight() -> u32 {
+    Event::iter()
+        .map(|event| event.weight())
+        .sum()
+}
+
+pub fn pick_event(rng: &mut StationRng) -> Event {
+    let mut roll = rng.gen_range(0..total_weight());
+
+    for event in Event::iter() {
+        if roll < event.weight() {
+            return event;
+        }
+        roll -= event.weight();
+    }
+
+    unreachable!()
+}
+
+// Every rolled event is returned, a NullEvent is followed by its reroll.
+pub fn roll_events(rng: &mut StationRng) -> Vec<Event> {
+    let mut events: Vec<Event> = vec![];
+
+    if !rng.gen_ratio(EVENT_CHANCE_NUMERATOR, EVENT_CHANCE_DENOMINATOR) {
+        return events;
+    }
+
+    for _ in 0..=MAX_REROLLS {
+        let event = pick_event(rng);
+        events.push(event);
+        if event != Event::NullEvent {
+            break;
+        }
+    }
+
+    return events;
+}
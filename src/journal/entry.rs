@@ -0,0 +1,88 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+// dependencies
+use serde::{Serialize, Deserialize};
+use strum_macros::{Display, EnumString};
+
+
+#[derive(Serialize, Deserialize, Display, EnumString)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+#[strum(serialize_all = "kebab-case")]
+pub enum EntryKind {
+    CrewLog,
+    SystemEvent,
+    DayMarker,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JournalEntry {
+    pub mission_day: u16,
+    pub timestamp: u64,
+    pub kind: EntryKind,
+    pub body: String,
+}
+
+impl JournalEntry {
+    pub fn new(mission_day: u16, kind: EntryKind, body: String) -> Self {
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        JournalEntry {
+            mission_day,
+            timestamp,
+            kind,
+            body,
+        }
+    }
+
+    pub fn timestamp_display(&self) -> String {
+        let days = (self.timestamp / 86400) as i64;
+        let seconds = self.timestamp % 86400;
+        let (year, month, day) = civil_from_days(days);
+
+        format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+                year, month, day,
+                seconds / 3600, (seconds % 3600) / 60, seconds % 60)
+    }
+}
+
+impl fmt::Display for JournalEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            EntryKind::DayMarker => {
+                write!(f, "{} ({})", self.body, self.timestamp_display())
+            },
+            EntryKind::CrewLog => {
+                write!(f, "[{}] {}", self.timestamp_display(), self.body)
+            },
+            EntryKind::SystemEvent => {
+                write!(f, "[{}] (system) {}", self.timestamp_display(), self.body)
+            },
+        }
+    }
+}
+
+// days since 1970-01-01 to (year, month, day), after Howard Hinnant's `civil_from_days`
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let day_of_era = z.rem_euclid(146097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    return (year, month, day);
+}
@@ -0,0 +1,126 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fs;
+use std::path::Path;
+
+// dependencies
+use strum_macros::{Display, EnumString};
+
+// project
+use crate::terminalisp::journal as tl_journal;
+
+// module
+use crate::journal::journal::Journal;
+use crate::journal::entry::EntryKind;
+
+
+#[derive(Display, EnumString)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum ExportFormat {
+    Markdown,
+    Json,
+    Terminalisp,
+}
+
+impl Journal {
+    pub fn export(&self, format: ExportFormat) -> String {
+        match format {
+            ExportFormat::Markdown => self.to_markdown(),
+            ExportFormat::Json => self.to_json(),
+            ExportFormat::Terminalisp => self.to_terminalisp(),
+        }
+    }
+
+    pub fn export_to_file(&self, format: ExportFormat, path: &Path) -> Result<(), String> {
+        fs::write(path, self.export(format))
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut result: String = String::new();
+
+        result.push_str(format!("# {}: {}\n", markdown_text(self.header(), ""),
+                                markdown_text(self.title(), "")).as_str());
+
+        for entry in self.entries() {
+            result.push('\n');
+            match entry.kind {
+                EntryKind::DayMarker => {
+                    result.push_str(format!("## {}\n\n", markdown_text(&entry.body, "")).as_str());
+                    result.push_str(format!("_{}_\n", entry.timestamp_display()).as_str());
+                },
+                EntryKind::CrewLog => {
+                    result.push_str(format!("**{}**\n\n", entry.timestamp_display()).as_str());
+                    result.push_str(format!("{}\n", markdown_text(&entry.body, "")).as_str());
+                },
+                EntryKind::SystemEvent => {
+                    result.push_str(format!("> **{}** (system) {}\n",
+                                            entry.timestamp_display(), markdown_text(&entry.body, "> ")).as_str());
+                },
+            }
+        }
+
+        return result;
+    }
+
+    pub fn to_json(&self) -> String {
+        // entries only hold strings and integers, serialization cannot fail
+        let encoded = serde_json::to_string_pretty(self.entries())
+            .unwrap_or_else(|_| String::from("[]"));
+
+        return format!("{encoded}\n");
+    }
+
+    pub fn to_terminalisp(&self) -> String {
+        tl_journal::journal(self.header(), self.title(), self.entries())
+    }
+}
+
+// Bodies are free text: everything Markdown would read as markup is backslash-escaped, and
+// line breaks become hard breaks, so a body can't open a heading, list or rule of its own.
+// Indentation and blank lines are kept as non-breaking spaces, which Markdown doesn't read as
+// code blocks or paragraph ends. `prefix` continues the block the text sits in on every following line.
+fn markdown_text(text: &str, prefix: &str) -> String {
+    let lines: Vec<String> = text
+        .split('\n')
+        .map(|line| line.trim_end_matches('\r'))
+        .map(|line| {
+            let text = line.trim_start();
+            let indent: String = line[..line.len() - text.len()]
+                .chars()
+                .map(|c| match c {
+                    '\t' => "&nbsp;".repeat(4),
+                    _ => String::from("&nbsp;"),
+                })
+                .collect();
+            match line.is_empty() {
+                true => String::from("&nbsp;"),
+                false => indent + &markdown_line(text),
+            }
+        })
+        .collect();
+
+    return lines.join(format!("\\\n{prefix}").as_str());
+}
+
+fn markdown_line(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+
+    for (index, c) in line.chars().enumerate() {
+        let block_marker = match index {
+            0 => matches!(c, '-' | '+' | '='),
+            _ => index == digits && digits > 0 && matches!(c, '.' | ')'),
+        };
+        if block_marker || matches!(c, '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~' | '&') {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+
+    return result;
+}
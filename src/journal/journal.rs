@@ -2,17 +2,78 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// library
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
 // dependencies
-use inquire::Text;
+use serde::{Serialize, Deserialize};
 
 // project
+use crate::station::station::Station;
+use crate::prompt::prompt::Prompter;
 use crate::terminalisp::journal::*;
 
+// module
+use crate::journal::entry::{JournalEntry, EntryKind};
+
+
+#[derive(Serialize, Deserialize)]
+struct JournalFileHeader {
+    header: String,
+    title: String,
+}
+
+// The unparseable last line of a journal file and the byte offset it starts at.
+struct TornTail {
+    line: usize,
+    offset: usize,
+    error: String,
+}
 
 pub struct Journal {
     header: String,
     title: String,
-    log: Vec<String>,
+    log: Vec<JournalEntry>,
+    mission_day: u16,
+    file: Option<File>,
+}
+
+// A clone is detached from the journal file, only the original appends to it.
+impl Clone for Journal {
+    fn clone(&self) -> Self {
+        Journal {
+            header: self.header.clone(),
+            title: self.title.clone(),
+            log: self.log.clone(),
+            mission_day: self.mission_day,
+            file: None,
+        }
+    }
+}
+
+impl PartialEq for Journal {
+    fn eq(&self, other: &Self) -> bool {
+        self.header == other.header
+            && self.title == other.title
+            && self.log == other.log
+            && self.mission_day == other.mission_day
+    }
+}
+
+impl Eq for Journal {}
+
+impl fmt::Debug for Journal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Journal")
+            .field("header", &self.header)
+            .field("title", &self.title)
+            .field("log", &self.log)
+            .field("mission_day", &self.mission_day)
+            .finish()
+    }
 }
 
 impl Journal {
@@ -21,34 +82,215 @@ impl Journal {
             header,
             title,
             log: vec![],
+            mission_day: 0,
+            file: None,
         }
     }
 
-    pub fn prompt_entry(&mut self, prompt: String) -> Result<String, String> {
-        match Text::new(prompt.as_str()).prompt() {
+    pub fn create(path: &Path, header: String, title: String) -> Result<Self, String> {
+        let file = File::create(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let mut journal = Journal::new(header, title);
+        journal.file = Some(file);
+
+        let file_header = JournalFileHeader {
+            header: journal.header.clone(),
+            title: journal.title.clone(),
+        };
+        journal.append_line(&file_header)?;
+
+        return Ok(journal);
+    }
+
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        let (mut journal, torn_tail) = Journal::parse_lines(content.as_str(), true)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        let mut file = OpenOptions::new()
+            .append(true)
+            .open(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        // cut the torn line off so new entries start on a line of their own
+        let repaired = match torn_tail {
+            Some(tail) => {
+                journal_file_torn_tail(tail.line, tail.error);
+                file.set_len(tail.offset as u64)
+            },
+            None if !content.ends_with('\n') => writeln!(file),
+            None => Ok(()),
+        };
+        repaired.map_err(|e| format!("{}: {}", path.display(), e))?;
+        journal.file = Some(file);
+
+        return Ok(journal);
+    }
+
+    // The journal file format, header line first, also used for the downlink.
+    pub fn from_lines(content: &str) -> Result<Self, String> {
+        Journal::parse_lines(content, false).map(|(journal, _)| journal)
+    }
+
+    // A crash mid-append leaves a last line without its newline, which `open` can drop; anything else,
+    // a complete line this build can't read included, is corruption.
+    fn parse_lines(content: &str, allow_torn_tail: bool) -> Result<(Self, Option<TornTail>), String> {
+        let mut lines = content.split_inclusive('\n');
+
+        let first_line = lines.next().ok_or("missing journal header")?;
+        let file_header: JournalFileHeader = serde_json::from_str(first_line.trim_end())
+            .map_err(|e| format!("line 1: {e}"))?;
+
+        let mut journal = Journal::new(file_header.header, file_header.title);
+        let mut offset = first_line.len();
+
+        for (index, line) in lines.enumerate() {
+            let start = offset;
+            offset += line.len();
+
+            // only the last line can be missing its newline, a complete line is never torn
+            let torn = !line.ends_with('\n');
+            let line = line.trim_end_matches(['\n', '\r']);
+            if line.trim().is_empty() { continue; }
+
+            let entry: JournalEntry = match serde_json::from_str(line) {
+                Ok(v) => v,
+                Err(e) if allow_torn_tail && torn => {
+                    let tail = TornTail { line: index + 2, offset: start, error: e.to_string() };
+                    return Ok((journal, Some(tail)));
+                },
+                Err(e) => { return Err(format!("line {}: {}", index + 2, e)); }
+            };
+            journal.mission_day = entry.mission_day;
+            journal.log.push(entry);
+        }
+
+        return Ok((journal, None));
+    }
+
+    pub fn to_lines(&self) -> Result<String, String> {
+        let file_header = JournalFileHeader {
+            header: self.header.clone(),
+            title: self.title.clone(),
+        };
+
+        let mut content = serde_json::to_string(&file_header)
+            .map_err(|e| e.to_string())?;
+        content.push('\n');
+        for entry in &self.log {
+            content += &serde_json::to_string(entry)
+                .map_err(|e| e.to_string())?;
+            content.push('\n');
+        }
+
+        return Ok(content);
+    }
+
+    pub fn open_or_create(path: &Path, header: String, title: String) -> Result<Self, String> {
+        if path.exists() {
+            Journal::open(path)
+        } else {
+            Journal::create(path, header, title)
+        }
+    }
+
+    pub fn header(&self) -> &str { self.header.as_str() }
+    pub fn title(&self) -> &str { self.title.as_str() }
+    pub fn mission_day(&self) -> u16 { self.mission_day }
+
+    pub fn entries(&self) -> &[JournalEntry] { &self.log }
+
+    pub fn entries_for_day(&self, mission_day: u16) -> Vec<&JournalEntry> {
+        self.log
+            .iter()
+            .filter(|entry| entry.mission_day == mission_day)
+            .collect()
+    }
+
+    pub fn entries_of_kind(&self, kind: EntryKind) -> Vec<&JournalEntry> {
+        self.log
+            .iter()
+            .filter(|entry| entry.kind == kind)
+            .collect()
+    }
+
+    pub fn prompt_entry(&mut self, prompter: &mut dyn Prompter, prompt: String) -> Result<String, String> {
+        match prompter.text(prompt.as_str()) {
             Ok(v) =>  {
-                self.add_entry(v);
-                journal_entry_status_saved();
-                Ok(String::from("Journal entry success."))
+                match self.add_entry(v) {
+                    Ok(_) => {
+                        journal_entry_status_saved();
+                        Ok(String::from("Journal entry success."))
+                    },
+                    Err(e) => {
+                        journal_entry_status_error(e);
+                        Err(String::from("Journal entry error."))
+                    }
+                }
             },
             Err(e) => {
-                journal_entry_status_error(e.to_string());
+                journal_entry_status_error(e);
                 Err(String::from("Journal entry error."))
             }
         }
     }
 
-    pub fn add_entry(&mut self, text: String) {
-        let _ = &self.log.push(text);
+    pub fn day(&mut self, mission_day: u16) -> Result<(), String> {
+        let marked = self.log
+            .iter()
+            .any(|entry| entry.kind == EntryKind::DayMarker && entry.mission_day == mission_day);
+
+        self.mission_day = mission_day;
+        if marked {
+            return Ok(());
+        }
+
+        self.push_entry(EntryKind::DayMarker, format!("Mission Day {mission_day}"))
     }
 
-    pub fn print(&self) {
-        println!("{:-<80}", "");
-        println!("{}: {}", &self.header, &self.title);
-        println!("{:-<80}", "");
-        for item in &self.log {
-            println!("{}", item);
-            println!("{:-<80}", "");
+    pub fn add_entry(&mut self, text: String) -> Result<(), String> {
+        self.push_entry(EntryKind::CrewLog, text)
+    }
+
+    pub fn add_system_entry(&mut self, text: String) -> Result<(), String> {
+        self.push_entry(EntryKind::SystemEvent, text)
+    }
+
+    pub fn record_station(&mut self, station: &mut Station) -> Result<(), String> {
+        let events = station.take_events();
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        self.day(station.mission_day)?;
+        for event in events {
+            self.add_system_entry(event.to_string())?;
         }
+
+        return Ok(());
+    }
+
+    fn push_entry(&mut self, kind: EntryKind, body: String) -> Result<(), String> {
+        let entry = JournalEntry::new(self.mission_day, kind, body);
+
+        self.append_line(&entry)?;
+        let _ = &self.log.push(entry);
+
+        return Ok(());
+    }
+
+    fn append_line<T: Serialize>(&mut self, value: &T) -> Result<(), String> {
+        let file = match &mut self.file {
+            Some(v) => v,
+            None => { return Ok(()); }
+        };
+
+        let line = serde_json::to_string(value)
+            .map_err(|e| e.to_string())?;
+        writeln!(file, "{line}")
+            .and_then(|_| file.flush())
+            .map_err(|e| e.to_string())
     }
 }
@@ -3,3 +3,5 @@
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
 pub mod journal;
+pub mod entry;
+pub mod export;
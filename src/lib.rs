@@ -8,4 +8,11 @@ pub mod section;
 pub mod module;
 
 pub mod journal;
+pub mod event;
+pub mod prompt;
+pub mod script;
+pub mod repl;
 pub mod terminalisp;
+
+pub mod protocol;
+pub mod packets;
@@ -4,8 +4,7 @@
 
 // project
 use crate::station::components::Name;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::symbols;
+use crate::terminalisp::value::Value;
 
 
 pub trait Active: Name {
@@ -15,25 +14,13 @@ pub trait Active: Name {
 }
 
 pub trait StatusModule: Active {
-    fn status(&self, indent: u8) -> String {
-        let status = if self.active() { symbols::OK } else { symbols::INACTIVE };
+    fn status(&self) -> Value {
+        let status = if self.active() { "ok" } else { "inactive" };
 
-        tl_station::status(
-            String::from("module"),
-            true,
-            vec![
-                String::from(":name"),
-                String::from(":status")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                String::from(status)
-            ],
-            false,
-            String::from(""),
-            vec![],
-            indent
-        )
+        Value::form("module", vec![
+            ("name", Value::string(self.name().as_str())),
+            ("status", Value::quoted(status)),
+        ])
     }
 }
 
@@ -64,3 +51,8 @@ pub trait PowerDownModule: Active {
         self.deactivate();
     }
 }
+
+pub trait Module: StatusModule + BreakModule + RepairModule + PowerDownModule {}
+
+impl<T> Module for T
+    where T: StatusModule + BreakModule + RepairModule + PowerDownModule {}
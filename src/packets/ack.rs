@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use strum_macros::Display;
+
+// project
+use crate::protocol::auth;
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_COMMAND_ACK;
+
+
+// fits a status without the inner tree into one datagram
+pub const MESSAGE_SIZE_MAX : usize = 1024;
+
+// sequence, status, message length
+pub const ACK_BODY_SIZE_MIN : usize = 8 + 1 + 2;
+pub const ACK_PACKET_SIZE_MIN : usize = HEADER_SIZE + ACK_BODY_SIZE_MIN + auth::MAC_SIZE;
+
+#[derive(Display)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum AckStatus {
+    Applied,
+    Failed,
+    Rejected,
+}
+
+impl AckStatus {
+    fn to_byte(self) -> u8 {
+        match self {
+            AckStatus::Applied => 0,
+            AckStatus::Failed => 1,
+            AckStatus::Rejected => 2,
+        }
+    }
+
+    fn from_byte(byte: u8) -> Result<Self, DecodeError> {
+        match byte {
+            0 => Ok(AckStatus::Applied),
+            1 => Ok(AckStatus::Failed),
+            2 => Ok(AckStatus::Rejected),
+            _ => Err(DecodeError::InvalidValue { field: "acknowledgement status", value: byte as u32 }),
+        }
+    }
+}
+
+// The station's answer to a command, signed with the same key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AckPacket {
+    pub sequence: u64,
+    pub status: AckStatus,
+    pub message: String,
+}
+
+impl AckPacket {
+    pub fn encode(&self, key: &[u8]) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(ACK_PACKET_SIZE_MIN + self.message.len());
+
+        common::write_header(&mut buf, OP_COMMAND_ACK);
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.push(self.status.to_byte());
+
+        let mut length = self.message.len().min(MESSAGE_SIZE_MAX);
+        while !self.message.is_char_boundary(length) {
+            length -= 1;
+        }
+        buf.extend_from_slice(&(length as u16).to_be_bytes());
+        buf.extend_from_slice(&self.message.as_bytes()[..length]);
+
+        auth::append_mac(key, &mut buf);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8], key: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_COMMAND_ACK, ACK_PACKET_SIZE_MIN)?;
+        let signed = auth::verified(key, bytes)?;
+
+        return AckPacket::parse(signed);
+    }
+
+    // For inspecting captures only, the MAC is skipped and not checked.
+    pub fn decode_unverified(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_COMMAND_ACK, ACK_PACKET_SIZE_MIN)?;
+
+        return AckPacket::parse(&bytes[..bytes.len() - auth::MAC_SIZE]);
+    }
+
+    fn parse(signed: &[u8]) -> Result<Self, DecodeError> {
+        let body = &signed[HEADER_SIZE..];
+
+        let sequence = u64::from_be_bytes([body[0], body[1], body[2], body[3],
+                                            body[4], body[5], body[6], body[7]]);
+        let status = AckStatus::from_byte(body[8])?;
+        let length = u16::from_be_bytes([body[9], body[10]]) as usize;
+        if length > MESSAGE_SIZE_MAX || body.len() != ACK_BODY_SIZE_MIN + length {
+            return Err(DecodeError::LengthMismatch { field: "acknowledgement message", length });
+        }
+        let message = String::from_utf8(body[ACK_BODY_SIZE_MIN..].to_vec())
+            .map_err(|_| DecodeError::InvalidUtf8("acknowledgement message"))?;
+
+        return Ok(AckPacket { sequence, status, message });
+    }
+}
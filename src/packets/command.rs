@@ -0,0 +1,172 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fmt;
+
+// project
+use crate::protocol::auth;
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::{OP_COMMAND_REPAIR, OP_COMMAND_POWER_DOWN,
+                                OP_COMMAND_NEW_DAY, OP_COMMAND_STATUS_REQUEST};
+use crate::protocol::port_address::PortAddressRange;
+
+
+// the path length is sent in one byte
+pub const PATH_SIZE_MAX : usize = 255;
+
+pub const COMMAND_OP_CODES: [u16; 4] = [OP_COMMAND_REPAIR, OP_COMMAND_POWER_DOWN,
+                                        OP_COMMAND_NEW_DAY, OP_COMMAND_STATUS_REQUEST];
+
+// sequence, target range, path length
+pub const COMMAND_BODY_SIZE_MIN : usize = 8 + 4 + 1;
+pub const COMMAND_PACKET_SIZE_MIN : usize = HEADER_SIZE + COMMAND_BODY_SIZE_MIN + auth::MAC_SIZE;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    Repair(String),
+    PowerDown,
+    NewDay,
+    StatusRequest,
+}
+
+impl Command {
+    pub fn op_code(&self) -> u16 {
+        match self {
+            Command::Repair(_) => OP_COMMAND_REPAIR,
+            Command::PowerDown => OP_COMMAND_POWER_DOWN,
+            Command::NewDay => OP_COMMAND_NEW_DAY,
+            Command::StatusRequest => OP_COMMAND_STATUS_REQUEST,
+        }
+    }
+
+    // "repair PATH", "power-down", "new-day", "status"
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let mut words = value.split_whitespace();
+        let command = match (words.next(), words.next()) {
+            (Some("repair"), Some(path)) => {
+                check_path(path)?;
+                Command::Repair(path.to_string())
+            },
+            (Some("power-down"), None) => Command::PowerDown,
+            (Some("new-day"), None) => Command::NewDay,
+            (Some("status"), None) => Command::StatusRequest,
+            _ => { return Err(format!("unknown command: {value}")); }
+        };
+        if words.next().is_some() {
+            return Err(format!("unknown command: {value}"));
+        }
+
+        return Ok(command);
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Repair(path) => write!(f, "repair {path}"),
+            Command::PowerDown => write!(f, "power-down"),
+            Command::NewDay => write!(f, "new-day"),
+            Command::StatusRequest => write!(f, "status"),
+        }
+    }
+}
+
+pub fn is_command(op_code: u16) -> bool {
+    COMMAND_OP_CODES.contains(&op_code)
+}
+
+// Cutting a path short would repair something else, or split a character.
+fn check_path(path: &str) -> Result<(), String> {
+    if path.len() > PATH_SIZE_MAX {
+        return Err(format!("command path is {} bytes, at most {PATH_SIZE_MAX} fit", path.len()));
+    }
+    return Ok(());
+}
+
+// Signed with the shared key: the MAC covers header and body.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CommandPacket {
+    pub sequence: u64,
+    pub target_range: PortAddressRange,
+    pub command: Command,
+}
+
+impl CommandPacket {
+    pub fn encode(&self, key: &[u8]) -> Result<Vec<u8>, String> {
+        let mut buf: Vec<u8> = Vec::with_capacity(COMMAND_PACKET_SIZE_MIN + PATH_SIZE_MAX);
+
+        common::write_header(&mut buf, self.command.op_code());
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(&self.target_range.top.to_be_bytes());
+        buf.extend_from_slice(&self.target_range.bottom.to_be_bytes());
+
+        let path = match &self.command {
+            Command::Repair(path) => path.as_str(),
+            _ => "",
+        };
+        check_path(path)?;
+        buf.push(path.len() as u8);
+        buf.extend_from_slice(path.as_bytes());
+
+        auth::append_mac(key, &mut buf);
+
+        return Ok(buf);
+    }
+
+    pub fn decode(bytes: &[u8], key: &[u8]) -> Result<Self, DecodeError> {
+        let op_code = CommandPacket::check(bytes)?;
+        let signed = auth::verified(key, bytes)?;
+
+        return CommandPacket::parse(op_code, signed);
+    }
+
+    // For inspecting captures only, the MAC is skipped and not checked.
+    pub fn decode_unverified(bytes: &[u8]) -> Result<Self, DecodeError> {
+        let op_code = CommandPacket::check(bytes)?;
+
+        return CommandPacket::parse(op_code, &bytes[..bytes.len() - auth::MAC_SIZE]);
+    }
+
+    fn check(bytes: &[u8]) -> Result<u16, DecodeError> {
+        let op_code = common::read_op_code(bytes)?;
+        if !is_command(op_code) {
+            return Err(DecodeError::UnexpectedOpCodes { found: op_code, expected: &COMMAND_OP_CODES });
+        }
+        if bytes.len() < COMMAND_PACKET_SIZE_MIN {
+            return Err(DecodeError::ShortBuffer { length: bytes.len(), expected: COMMAND_PACKET_SIZE_MIN });
+        }
+
+        return Ok(op_code);
+    }
+
+    fn parse(op_code: u16, signed: &[u8]) -> Result<Self, DecodeError> {
+        let body = &signed[HEADER_SIZE..];
+
+        let sequence = u64::from_be_bytes([body[0], body[1], body[2], body[3],
+                                            body[4], body[5], body[6], body[7]]);
+        let target_range = PortAddressRange {
+            top: u16::from_be_bytes([body[8], body[9]]),
+            bottom: u16::from_be_bytes([body[10], body[11]]),
+        };
+
+        let length = body[12] as usize;
+        if body.len() != COMMAND_BODY_SIZE_MIN + length {
+            return Err(DecodeError::LengthMismatch { field: "command path", length });
+        }
+        let path = String::from_utf8(body[COMMAND_BODY_SIZE_MIN..].to_vec())
+            .map_err(|_| DecodeError::InvalidUtf8("command path"))?;
+
+        let command = match op_code {
+            OP_COMMAND_REPAIR => Command::Repair(path),
+            OP_COMMAND_POWER_DOWN => Command::PowerDown,
+            OP_COMMAND_NEW_DAY => Command::NewDay,
+            _ => Command::StatusRequest,
+        };
+
+        return Ok(CommandPacket { sequence, target_range, command });
+    }
+}
@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_DIAGNOSTIC;
+
+
+pub const MESSAGE_SIZE_MAX : usize = 512;
+
+// priority, port address, message length
+pub const DIAGNOSTIC_BODY_SIZE_MIN : usize = 1 + 2 + 2;
+pub const DIAGNOSTIC_PACKET_SIZE_MIN : usize = HEADER_SIZE + DIAGNOSTIC_BODY_SIZE_MIN;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiagnosticPacket {
+    pub priority: u8,
+    pub port_address: u16,
+    pub message: String,
+}
+
+impl DiagnosticPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(DIAGNOSTIC_PACKET_SIZE_MIN + self.message.len());
+
+        common::write_header(&mut buf, OP_DIAGNOSTIC);
+        buf.push(self.priority);
+        buf.extend_from_slice(&self.port_address.to_be_bytes());
+
+        let mut length = self.message.len().min(MESSAGE_SIZE_MAX);
+        while !self.message.is_char_boundary(length) {
+            length -= 1;
+        }
+        buf.extend_from_slice(&(length as u16).to_be_bytes());
+        buf.extend_from_slice(&self.message.as_bytes()[..length]);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_DIAGNOSTIC, DIAGNOSTIC_PACKET_SIZE_MIN)?;
+
+        let body = &bytes[HEADER_SIZE..];
+        let priority = body[0];
+        let port_address = u16::from_be_bytes([body[1], body[2]]);
+        let length = u16::from_be_bytes([body[3], body[4]]) as usize;
+        if length > MESSAGE_SIZE_MAX || body.len() != DIAGNOSTIC_BODY_SIZE_MIN + length {
+            return Err(DecodeError::LengthMismatch { field: "diagnostic message", length });
+        }
+        let message = String::from_utf8(body[DIAGNOSTIC_BODY_SIZE_MIN..].to_vec())
+            .map_err(|_| DecodeError::InvalidUtf8("diagnostic message"))?;
+
+        return Ok(DiagnosticPacket { priority, port_address, message });
+    }
+}
@@ -0,0 +1,69 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_FAILURE;
+
+// module
+use crate::packets::ping_reply::{PingReply, PING_REPLY_BODY_SIZE};
+
+
+// the path length is sent in one byte
+pub const PATH_SIZE_MAX : usize = 255;
+
+pub const FAILURE_PACKET_SIZE_MIN : usize = HEADER_SIZE + PING_REPLY_BODY_SIZE + 3;
+
+// Pushed by a station to everyone who pinged it, whenever a module breaks.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FailurePacket {
+    pub station: PingReply,
+    // of the failed module's category
+    pub port_address: u16,
+    pub path: String,
+}
+
+impl FailurePacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(FAILURE_PACKET_SIZE_MIN + self.path.len());
+
+        common::write_header(&mut buf, OP_FAILURE);
+        self.station.write_body(&mut buf);
+        buf.extend_from_slice(&self.port_address.to_be_bytes());
+
+        let mut length = self.path.len().min(PATH_SIZE_MAX);
+        while !self.path.is_char_boundary(length) {
+            length -= 1;
+        }
+        buf.push(length as u8);
+        buf.extend_from_slice(&self.path.as_bytes()[..length]);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_FAILURE, FAILURE_PACKET_SIZE_MIN)?;
+
+        let body = &bytes[HEADER_SIZE..];
+        let station = PingReply::read_body(body)?;
+
+        let fields = &body[PING_REPLY_BODY_SIZE..];
+        let port_address = u16::from_be_bytes([fields[0], fields[1]]);
+
+        let length = fields[2] as usize;
+        let start = PING_REPLY_BODY_SIZE + 3;
+        if body.len() < start + length {
+            return Err(DecodeError::ShortBuffer { length: bytes.len(), expected: FAILURE_PACKET_SIZE_MIN + length });
+        }
+        if body.len() != start + length {
+            return Err(DecodeError::LengthMismatch { field: "module path", length });
+        }
+        let path = String::from_utf8(body[start..].to_vec())
+            .map_err(|_| DecodeError::InvalidUtf8("module path"))?;
+
+        return Ok(FailurePacket { station, port_address, path });
+    }
+}
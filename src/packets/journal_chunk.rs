@@ -0,0 +1,61 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_JOURNAL_CHUNK;
+
+
+pub const CHUNK_PAYLOAD_SIZE_MAX : usize = 1024;
+
+// transfer, sequence, total, payload length
+pub const JOURNAL_CHUNK_BODY_SIZE_MIN : usize = 4 + 2 + 2 + 2;
+pub const JOURNAL_CHUNK_PACKET_SIZE_MIN : usize = HEADER_SIZE + JOURNAL_CHUNK_BODY_SIZE_MIN;
+
+// One numbered slice of a journal on its way down to mission control.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JournalChunkPacket {
+    pub transfer: u32,
+    pub sequence: u16,
+    pub total: u16,
+    pub payload: Vec<u8>,
+}
+
+impl JournalChunkPacket {
+    pub fn encode(&self) -> Vec<u8> {
+        let length = self.payload.len().min(CHUNK_PAYLOAD_SIZE_MAX);
+        let mut buf: Vec<u8> = Vec::with_capacity(JOURNAL_CHUNK_PACKET_SIZE_MIN + length);
+
+        common::write_header(&mut buf, OP_JOURNAL_CHUNK);
+        buf.extend_from_slice(&self.transfer.to_be_bytes());
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(&self.total.to_be_bytes());
+        buf.extend_from_slice(&(length as u16).to_be_bytes());
+        buf.extend_from_slice(&self.payload[..length]);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_JOURNAL_CHUNK, JOURNAL_CHUNK_PACKET_SIZE_MIN)?;
+
+        let body = &bytes[HEADER_SIZE..];
+        let transfer = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+        let sequence = u16::from_be_bytes([body[4], body[5]]);
+        let total = u16::from_be_bytes([body[6], body[7]]);
+        if sequence >= total {
+            return Err(DecodeError::InvalidValue { field: "journal chunk sequence", value: sequence as u32 });
+        }
+
+        let length = u16::from_be_bytes([body[8], body[9]]) as usize;
+        if length > CHUNK_PAYLOAD_SIZE_MAX || body.len() != JOURNAL_CHUNK_BODY_SIZE_MIN + length {
+            return Err(DecodeError::LengthMismatch { field: "journal chunk payload", length });
+        }
+        let payload = body[JOURNAL_CHUNK_BODY_SIZE_MIN..].to_vec();
+
+        return Ok(JournalChunkPacket { transfer, sequence, total, payload });
+    }
+}
@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_JOURNAL_RESEND;
+
+
+// more missing chunks are asked for in the next round
+pub const MISSING_COUNT_MAX : usize = 256;
+
+// transfer, missing count
+pub const JOURNAL_RESEND_BODY_SIZE_MIN : usize = 4 + 2;
+pub const JOURNAL_RESEND_PACKET_SIZE_MIN : usize = HEADER_SIZE + JOURNAL_RESEND_BODY_SIZE_MIN;
+
+// Sent back by the receiver of a journal; no missing chunks means the transfer is complete.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct JournalResendPacket {
+    pub transfer: u32,
+    pub missing: Vec<u16>,
+}
+
+impl JournalResendPacket {
+    pub fn complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let count = self.missing.len().min(MISSING_COUNT_MAX);
+        let mut buf: Vec<u8> = Vec::with_capacity(JOURNAL_RESEND_PACKET_SIZE_MIN + 2 * count);
+
+        common::write_header(&mut buf, OP_JOURNAL_RESEND);
+        buf.extend_from_slice(&self.transfer.to_be_bytes());
+        buf.extend_from_slice(&(count as u16).to_be_bytes());
+        for sequence in &self.missing[..count] {
+            buf.extend_from_slice(&sequence.to_be_bytes());
+        }
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_JOURNAL_RESEND, JOURNAL_RESEND_PACKET_SIZE_MIN)?;
+
+        let body = &bytes[HEADER_SIZE..];
+        let transfer = u32::from_be_bytes([body[0], body[1], body[2], body[3]]);
+
+        let count = u16::from_be_bytes([body[4], body[5]]) as usize;
+        if count > MISSING_COUNT_MAX || body.len() != JOURNAL_RESEND_BODY_SIZE_MIN + 2 * count {
+            return Err(DecodeError::LengthMismatch { field: "missing chunk list", length: count });
+        }
+        let missing = body[JOURNAL_RESEND_BODY_SIZE_MIN..]
+            .chunks(2)
+            .map(|pair| u16::from_be_bytes([pair[0], pair[1]]))
+            .collect();
+
+        return Ok(JournalResendPacket { transfer, missing });
+    }
+}
@@ -0,0 +1,12 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+pub mod ping;
+pub mod ping_reply;
+pub mod failure;
+pub mod diagnostic;
+pub mod command;
+pub mod ack;
+pub mod journal_chunk;
+pub mod journal_resend;
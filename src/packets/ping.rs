@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::common;
+use crate::protocol::constants::{HEADER_SIZE, PROTOCOL_VERSION_HI, PROTOCOL_VERSION_LO};
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_PING;
+use crate::protocol::port_address::PortAddressRange;
+
+
+pub const PING_PACKET_SIZE : usize = HEADER_SIZE + 6;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PingPacket {
+    pub prot_ver_hi: u8,
+    pub prot_ver_lo: u8,
+    pub flags: u8,
+    pub diag_priority: u8,
+    pub target_port_address_top_hi: u8,
+    pub target_port_address_top_lo: u8,
+    pub target_port_address_bottom_hi: u8,
+    pub target_port_address_bottom_lo: u8,
+}
+
+impl Default for PingPacket {
+    fn default() -> Self {
+        PingPacket::new()
+    }
+}
+
+impl PingPacket {
+    pub fn new() -> Self {
+        let mut ping = PingPacket {
+            prot_ver_hi: PROTOCOL_VERSION_HI,
+            prot_ver_lo: PROTOCOL_VERSION_LO,
+            flags: 0,
+            diag_priority: 0,
+            target_port_address_top_hi: 0,
+            target_port_address_top_lo: 0,
+            target_port_address_bottom_hi: 0,
+            target_port_address_bottom_lo: 0,
+        };
+        ping.set_target_range(PortAddressRange::all());
+
+        return ping;
+    }
+
+    pub fn target_range(&self) -> PortAddressRange {
+        PortAddressRange {
+            bottom: u16::from_be_bytes([self.target_port_address_bottom_hi,
+                                        self.target_port_address_bottom_lo]),
+            top: u16::from_be_bytes([self.target_port_address_top_hi,
+                                     self.target_port_address_top_lo]),
+        }
+    }
+
+    pub fn set_target_range(&mut self, range: PortAddressRange) {
+        [self.target_port_address_top_hi, self.target_port_address_top_lo] = range.top.to_be_bytes();
+        [self.target_port_address_bottom_hi, self.target_port_address_bottom_lo] = range.bottom.to_be_bytes();
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(PING_PACKET_SIZE);
+
+        common::write_header(&mut buf, OP_PING);
+        buf.push(self.flags);
+        buf.push(self.diag_priority);
+        buf.push(self.target_port_address_top_hi);
+        buf.push(self.target_port_address_top_lo);
+        buf.push(self.target_port_address_bottom_hi);
+        buf.push(self.target_port_address_bottom_lo);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_PING, PING_PACKET_SIZE)?;
+
+        let body = &bytes[HEADER_SIZE..];
+        return Ok(PingPacket {
+            prot_ver_hi: bytes[10],
+            prot_ver_lo: bytes[11],
+            flags: body[0],
+            diag_priority: body[1],
+            target_port_address_top_hi: body[2],
+            target_port_address_top_lo: body[3],
+            target_port_address_bottom_hi: body[4],
+            target_port_address_bottom_lo: body[5],
+        });
+    }
+}
+
+pub fn compose() -> Vec<u8> {
+    PingPacket::new().encode()
+}
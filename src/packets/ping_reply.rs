@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::station::Station;
+use crate::station::components::{Name, ModuleCounts};
+use crate::protocol::common;
+use crate::protocol::constants::HEADER_SIZE;
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes::OP_PING_REPLY;
+
+
+// station name, null-padded
+pub const NAME_SIZE : usize = 32;
+
+pub const PING_REPLY_BODY_SIZE : usize = NAME_SIZE + 9;
+pub const PING_REPLY_PACKET_SIZE : usize = HEADER_SIZE + PING_REPLY_BODY_SIZE;
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PingReply {
+    pub name: String,
+    pub version: u8,
+    pub mission_day: u16,
+    pub active_modules: u16,
+    pub total_modules: u16,
+    pub port_address: u16,
+}
+
+impl PingReply {
+    pub fn from_station(station: &Station) -> Self {
+        PingReply {
+            name: station.name(),
+            version: station.version,
+            mission_day: station.mission_day,
+            active_modules: station.active_modules(),
+            total_modules: station.total_modules(),
+            port_address: station.port_address(),
+        }
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf: Vec<u8> = Vec::with_capacity(PING_REPLY_PACKET_SIZE);
+
+        common::write_header(&mut buf, OP_PING_REPLY);
+        self.write_body(&mut buf);
+
+        return buf;
+    }
+
+    pub fn decode(bytes: &[u8]) -> Result<Self, DecodeError> {
+        common::read_header(bytes, OP_PING_REPLY, PING_REPLY_PACKET_SIZE)?;
+
+        return PingReply::read_body(&bytes[HEADER_SIZE..]);
+    }
+
+    // the station block is shared with the packets that a station pushes
+    pub(crate) fn write_body(&self, buf: &mut Vec<u8>) {
+        let mut name = [0u8; NAME_SIZE];
+        // the last byte stays null
+        let mut length = self.name.len().min(NAME_SIZE - 1);
+        while !self.name.is_char_boundary(length) {
+            length -= 1;
+        }
+        name[..length].copy_from_slice(&self.name.as_bytes()[..length]);
+        buf.extend_from_slice(&name);
+
+        buf.push(self.version);
+        buf.extend_from_slice(&self.mission_day.to_be_bytes());
+        buf.extend_from_slice(&self.active_modules.to_be_bytes());
+        buf.extend_from_slice(&self.total_modules.to_be_bytes());
+        buf.extend_from_slice(&self.port_address.to_be_bytes());
+    }
+
+    pub(crate) fn read_body(body: &[u8]) -> Result<Self, DecodeError> {
+        if body.len() < PING_REPLY_BODY_SIZE {
+            return Err(DecodeError::ShortBuffer { length: HEADER_SIZE + body.len(),
+                                                  expected: PING_REPLY_PACKET_SIZE });
+        }
+
+        let name_bytes = &body[..NAME_SIZE - 1];
+        let length = name_bytes.iter().position(|b| *b == 0).unwrap_or(NAME_SIZE - 1);
+        let name = String::from_utf8(name_bytes[..length].to_vec())
+            .map_err(|_| DecodeError::InvalidUtf8("station name"))?;
+
+        let fields = &body[NAME_SIZE..];
+        return Ok(PingReply {
+            name,
+            version: fields[0],
+            mission_day: u16::from_be_bytes([fields[1], fields[2]]),
+            active_modules: u16::from_be_bytes([fields[3], fields[4]]),
+            total_modules: u16::from_be_bytes([fields[5], fields[6]]),
+            port_address: u16::from_be_bytes([fields[7], fields[8]]),
+        });
+    }
+}
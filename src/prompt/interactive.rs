@@ -0,0 +1,34 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use inquire::Text;
+
+// project
+use crate::terminalisp::menu::tli_menu;
+
+// module
+use crate::prompt::prompt::Prompter;
+
+
+#[derive(Default)]
+pub struct InquirePrompter {}
+
+impl InquirePrompter {
+    pub fn new() -> Self {
+        InquirePrompter {}
+    }
+}
+
+impl Prompter for InquirePrompter {
+    fn select(&mut self, title: &str, options: Vec<String>) -> Result<String, String> {
+        tli_menu(title, options)
+    }
+
+    fn text(&mut self, prompt: &str) -> Result<String, String> {
+        Text::new(prompt)
+            .prompt()
+            .map_err(|e| e.to_string())
+    }
+}
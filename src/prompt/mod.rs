@@ -2,6 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-pub mod common;
-pub mod constants;
-pub mod op_codes;
+pub mod prompt;
+
+pub mod interactive;
+pub mod scripted;
+pub mod testing;
@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::collections::VecDeque;
+
+// module
+use crate::prompt::prompt::Prompter;
+
+
+// Answers prompts from a fixed list. A select answer matches an option
+// exactly, or the start of an option ignoring case ("power" picks "Power Category (4/5)").
+pub struct ScriptedPrompter {
+    answers: VecDeque<String>,
+}
+
+impl ScriptedPrompter {
+    pub fn new(answers: Vec<String>) -> Self {
+        ScriptedPrompter {
+            answers: VecDeque::from(answers),
+        }
+    }
+
+    pub fn push_answer(&mut self, answer: String) {
+        self.answers.push_back(answer);
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.answers.len()
+    }
+
+    fn next_answer(&mut self, prompt: &str) -> Result<String, String> {
+        self.answers
+            .pop_front()
+            .ok_or(format!("no scripted answer for \"{prompt}\""))
+    }
+}
+
+impl Prompter for ScriptedPrompter {
+    fn select(&mut self, title: &str, options: Vec<String>) -> Result<String, String> {
+        let answer = self.next_answer(title)?;
+        let lower = answer.to_lowercase();
+
+        if let Some(v) = options.iter().find(|option| option == &&answer) {
+            return Ok(v.clone());
+        }
+        if let Some(v) = options.iter().find(|option| option.to_lowercase().starts_with(&lower)) {
+            return Ok(v.clone());
+        }
+
+        return Err(format!("\"{answer}\" is not an option for \"{title}\""));
+    }
+
+    fn text(&mut self, prompt: &str) -> Result<String, String> {
+        self.next_answer(prompt)
+    }
+}
@@ -0,0 +1,78 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::cell::RefCell;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+// module
+use crate::prompt::prompt::Prompter;
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromptRecord {
+    pub title: String,
+    pub options: Vec<String>,
+}
+
+// Picks options by index and records every prompt it was shown.
+// Clones share the record, so a test can keep one after handing the other to a station.
+#[derive(Clone, Default)]
+pub struct TestPrompter {
+    choices: Rc<RefCell<VecDeque<usize>>>,
+    texts: Rc<RefCell<VecDeque<String>>>,
+    records: Rc<RefCell<Vec<PromptRecord>>>,
+}
+
+impl TestPrompter {
+    pub fn new() -> Self {
+        TestPrompter::default()
+    }
+
+    pub fn choose(&self, index: usize) -> &Self {
+        self.choices.borrow_mut().push_back(index);
+        self
+    }
+
+    pub fn answer(&self, text: &str) -> &Self {
+        self.texts.borrow_mut().push_back(text.to_string());
+        self
+    }
+
+    pub fn records(&self) -> Vec<PromptRecord> {
+        self.records.borrow().clone()
+    }
+}
+
+impl Prompter for TestPrompter {
+    fn select(&mut self, title: &str, options: Vec<String>) -> Result<String, String> {
+        self.records.borrow_mut().push(PromptRecord {
+            title: title.to_string(),
+            options: options.clone(),
+        });
+
+        let index = self.choices
+            .borrow_mut()
+            .pop_front()
+            .unwrap_or(0);
+
+        options
+            .get(index)
+            .cloned()
+            .ok_or(format!("no option {index} for \"{title}\""))
+    }
+
+    fn text(&mut self, prompt: &str) -> Result<String, String> {
+        self.records.borrow_mut().push(PromptRecord {
+            title: prompt.to_string(),
+            options: vec![],
+        });
+
+        self.texts
+            .borrow_mut()
+            .pop_front()
+            .ok_or(format!("no answer for \"{prompt}\""))
+    }
+}
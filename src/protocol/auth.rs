@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fs;
+use std::path::Path;
+
+// dependencies
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+// module
+use crate::protocol::error::DecodeError;
+
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const MAC_SIZE : usize = 32;
+
+// The shared key is the content of a config file, surrounding whitespace ignored.
+pub fn load_key(path: &Path) -> Result<Vec<u8>, String> {
+    let content = fs::read_to_string(path)
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    let key = content.trim();
+    if key.is_empty() {
+        return Err(format!("{}: key file is empty", path.display()));
+    }
+
+    return Ok(key.as_bytes().to_vec());
+}
+
+pub fn sign(key: &[u8], bytes: &[u8]) -> [u8; MAC_SIZE] {
+    // HMAC takes keys of any length
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    mac.update(bytes);
+
+    return mac.finalize().into_bytes().into();
+}
+
+pub fn verify(key: &[u8], bytes: &[u8], tag: &[u8]) -> Result<(), DecodeError> {
+    let mut mac = HmacSha256::new_from_slice(key).unwrap();
+    mac.update(bytes);
+
+    mac.verify_slice(tag)
+        .map_err(|_| DecodeError::AuthenticationFailed)
+}
+
+// Splits a signed packet into the signed part and its MAC, after checking the MAC.
+pub fn verified<'a>(key: &[u8], bytes: &'a [u8]) -> Result<&'a [u8], DecodeError> {
+    if bytes.len() < MAC_SIZE {
+        return Err(DecodeError::ShortBuffer { length: bytes.len(), expected: MAC_SIZE });
+    }
+    let (signed, tag) = bytes.split_at(bytes.len() - MAC_SIZE);
+    verify(key, signed, tag)?;
+
+    return Ok(signed);
+}
+
+pub fn append_mac(key: &[u8], buf: &mut Vec<u8>) {
+    let tag = sign(key, buf);
+    buf.extend_from_slice(&tag);
+}
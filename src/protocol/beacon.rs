@@ -0,0 +1,264 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+// project
+use crate::station::station::Station;
+use crate::station::events::SystemEvent;
+use crate::packets::ping::PingPacket;
+use crate::packets::ping_reply::PingReply;
+use crate::packets::failure::FailurePacket;
+use crate::packets::diagnostic::DiagnosticPacket;
+use crate::packets::command::is_command;
+
+// module
+use crate::protocol::common::read_op_code;
+use crate::protocol::constants::FLAG_SEND_DIAGNOSTICS;
+use crate::protocol::diagnostics::event_priority;
+use crate::protocol::command::CommandInbox;
+use crate::protocol::port_address::PortAddressRange;
+use crate::protocol::telemetry::Telemetry;
+use crate::protocol::udp;
+use crate::protocol::udp::Datagram;
+
+
+// how often the responder thread checks whether it should stop
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+// anyone can ping, so only this many are pushed to, and only while they keep pinging
+pub const SUBSCRIBERS_MAX : usize = 32;
+pub const SUBSCRIPTION_TIMEOUT : Duration = Duration::from_secs(60);
+
+// installed by `command_inbox`, until then commands are dropped
+type CommandSlot = Arc<Mutex<Option<Sender<Datagram>>>>;
+
+// what the responder thread answers with, refreshed on every station update
+struct Snapshot {
+    reply: PingReply,
+    port_addresses: Vec<u16>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Subscriber {
+    pub address: SocketAddr,
+    pub range: PortAddressRange,
+    pub flags: u8,
+    pub diag_priority: u8,
+    pub last_ping: Instant,
+}
+
+impl Subscriber {
+    pub fn wants_diagnostic(&self, priority: u8) -> bool {
+        self.flags & FLAG_SEND_DIAGNOSTICS != 0 && priority >= self.diag_priority
+    }
+}
+
+struct Subscriptions {
+    subscribers: Vec<Subscriber>,
+    timeout: Duration,
+}
+
+impl Subscriptions {
+    fn expire(&mut self) {
+        let timeout = self.timeout;
+        self.subscribers.retain(|subscriber| subscriber.last_ping.elapsed() < timeout);
+    }
+
+    fn live(&mut self) -> Vec<Subscriber> {
+        self.expire();
+        return self.subscribers.clone();
+    }
+
+    // A new subscriber waits for a free place, the ones already listed keep theirs.
+    fn renew(&mut self, subscriber: Subscriber) {
+        self.expire();
+        self.subscribers.retain(|v| v.address != subscriber.address);
+        if self.subscribers.len() < SUBSCRIBERS_MAX {
+            self.subscribers.push(subscriber);
+        }
+    }
+}
+
+// The station side of the protocol: a thread answers pings from a snapshot of the station,
+// and everyone who pinged gets failure packets pushed to them.
+// Pings and pushes are only exchanged where the target range covers a station or category port address.
+pub struct StationBeacon {
+    socket: UdpSocket,
+    snapshot: Arc<Mutex<Snapshot>>,
+    subscriptions: Arc<Mutex<Subscriptions>>,
+    commands: CommandSlot,
+    stop: Arc<AtomicBool>,
+    responder: Option<JoinHandle<()>>,
+}
+
+impl StationBeacon {
+    pub fn start(address: &str, station: &Station) -> Result<Self, String> {
+        let socket = udp::bind(address)?;
+        let responder_socket = socket.try_clone()
+            .map_err(|e| e.to_string())?;
+
+        let snapshot = Arc::new(Mutex::new(Snapshot {
+            reply: PingReply::from_station(station),
+            port_addresses: station.port_addresses(),
+        }));
+        let subscriptions = Arc::new(Mutex::new(Subscriptions { subscribers: vec![], timeout: SUBSCRIPTION_TIMEOUT }));
+        let commands = Arc::new(Mutex::new(None));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let responder = {
+            let snapshot = Arc::clone(&snapshot);
+            let subscriptions = Arc::clone(&subscriptions);
+            let commands = Arc::clone(&commands);
+            let stop = Arc::clone(&stop);
+            thread::spawn(move || respond(responder_socket, snapshot, subscriptions, commands, stop))
+        };
+
+        return Ok(StationBeacon {
+            socket,
+            snapshot,
+            subscriptions,
+            commands,
+            stop,
+            responder: Some(responder),
+        });
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, String> {
+        self.socket.local_addr()
+            .map_err(|e| e.to_string())
+    }
+
+    // Subscribers that pinged within the timeout, the others are forgotten.
+    pub fn subscribers(&self) -> Vec<Subscriber> {
+        self.subscriptions.lock().unwrap().live()
+    }
+
+    pub fn set_subscription_timeout(&self, timeout: Duration) {
+        self.subscriptions.lock().unwrap().timeout = timeout;
+    }
+
+    // Command packets are only accepted once an inbox exists, they are applied on the
+    // thread that owns the Station through `CommandInbox::process`.
+    pub fn command_inbox(&self, key: Vec<u8>) -> Result<CommandInbox, String> {
+        let socket = self.socket.try_clone()
+            .map_err(|e| e.to_string())?;
+        let (sender, receiver) = mpsc::channel();
+        *self.commands.lock().unwrap() = Some(sender);
+
+        return Ok(CommandInbox::new(receiver, socket, key));
+    }
+
+    fn refresh(&self, station: &Station) -> PingReply {
+        let reply = PingReply::from_station(station);
+        *self.snapshot.lock().unwrap() = Snapshot {
+            reply: reply.clone(),
+            port_addresses: station.port_addresses(),
+        };
+
+        return reply;
+    }
+}
+
+impl Telemetry for StationBeacon {
+    fn update(&mut self, station: &Station) {
+        self.refresh(station);
+    }
+
+    fn module_failure(&mut self, station: &Station, path: &str, port_address: u16) {
+        let packet = FailurePacket {
+            station: self.refresh(station),
+            port_address,
+            path: path.to_string(),
+        };
+        let bytes = packet.encode();
+
+        for subscriber in self.subscribers() {
+            if !subscriber.range.contains(port_address) {
+                continue;
+            }
+            // an unreachable subscriber must not take the station down with it
+            let _ = udp::send(&self.socket, &bytes, subscriber.address);
+        }
+    }
+
+    fn event(&mut self, station: &Station, event: &SystemEvent) {
+        let packet = DiagnosticPacket {
+            priority: event_priority(event),
+            port_address: station.port_address(),
+            message: event.to_string(),
+        };
+        let bytes = packet.encode();
+        let port_addresses = station.port_addresses();
+
+        for subscriber in self.subscribers() {
+            if subscriber.wants_diagnostic(packet.priority) && subscriber.range.contains_any(&port_addresses) {
+                let _ = udp::send(&self.socket, &bytes, subscriber.address);
+            }
+        }
+    }
+}
+
+impl Drop for StationBeacon {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::Relaxed);
+        if let Some(responder) = self.responder.take() {
+            let _ = responder.join();
+        }
+    }
+}
+
+fn respond(socket: UdpSocket,
+           snapshot: Arc<Mutex<Snapshot>>,
+           subscriptions: Arc<Mutex<Subscriptions>>,
+           commands: CommandSlot,
+           stop: Arc<AtomicBool>) {
+
+    while !stop.load(Ordering::Relaxed) {
+        let (bytes, source) = match udp::try_receive(&socket, POLL_INTERVAL) {
+            Ok(Some(v)) => v,
+            Ok(None) => { continue; },
+            Err(_) => {
+                thread::sleep(POLL_INTERVAL);
+                continue;
+            }
+        };
+
+        if read_op_code(&bytes).map(is_command).unwrap_or(false) {
+            if let Some(sender) = commands.lock().unwrap().as_ref() {
+                let _ = sender.send((bytes, source));
+            }
+            continue;
+        }
+
+        let ping = match PingPacket::decode(&bytes) {
+            Ok(v) => v,
+            Err(_) => { continue; }
+        };
+        let range = ping.target_range();
+
+        let encoded = {
+            let snapshot = snapshot.lock().unwrap();
+            if !range.contains_any(&snapshot.port_addresses) {
+                continue;
+            }
+            snapshot.reply.encode()
+        };
+        let _ = udp::send(&socket, &encoded, source);
+
+        subscriptions.lock().unwrap().renew(Subscriber {
+            address: source,
+            range,
+            flags: ping.flags,
+            diag_priority: ping.diag_priority,
+            last_ping: Instant::now(),
+        });
+    }
+}
@@ -0,0 +1,241 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+
+
+// link types, see https://www.tcpdump.org/linktypes.html
+const LINKTYPE_NULL : u32 = 0;
+const LINKTYPE_ETHERNET : u32 = 1;
+const LINKTYPE_RAW : u32 = 101;
+const LINKTYPE_LINUX_SLL : u32 = 113;
+const LINKTYPE_LINUX_SLL2 : u32 = 276;
+
+const ETHERTYPE_IPV4 : u16 = 0x0800;
+const ETHERTYPE_IPV6 : u16 = 0x86dd;
+const ETHERTYPE_VLAN : u16 = 0x8100;
+
+const IP_PROTOCOL_UDP : u8 = 17;
+
+const PCAPNG_SECTION_HEADER : u32 = 0x0a0d0d0a;
+const PCAPNG_INTERFACE_DESCRIPTION : u32 = 0x00000001;
+const PCAPNG_SIMPLE_PACKET : u32 = 0x00000003;
+const PCAPNG_ENHANCED_PACKET : u32 = 0x00000006;
+
+// block type and both lengths, plus the fixed fields each packet block starts with
+const PCAPNG_SIMPLE_PACKET_LENGTH_MIN : usize = 16;
+const PCAPNG_ENHANCED_PACKET_LENGTH_MIN : usize = 32;
+
+// One UDP datagram out of a capture, `frame` counts from 1 like capture viewers do.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CapturedDatagram {
+    pub frame: usize,
+    pub source: SocketAddr,
+    pub destination: SocketAddr,
+    pub payload: Vec<u8>,
+}
+
+// Frames that are not UDP over IP are skipped; a damaged file is an error.
+pub fn read_capture(bytes: &[u8]) -> Result<Vec<CapturedDatagram>, String> {
+    if bytes.len() < 4 {
+        return Err(String::from("capture too short for a file header"));
+    }
+
+    match u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]) {
+        0xa1b2c3d4 | 0xa1b23c4d => read_pcap(bytes, false),
+        0xd4c3b2a1 | 0x4d3cb2a1 => read_pcap(bytes, true),
+        PCAPNG_SECTION_HEADER => read_pcapng(bytes),
+        _ => Err(String::from("not a pcap or pcapng capture")),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    big_endian: bool,
+}
+
+impl<'a> Reader<'a> {
+    fn slice(&self, at: usize, length: usize) -> Result<&'a [u8], String> {
+        at.checked_add(length)
+            .and_then(|end| self.bytes.get(at..end))
+            .ok_or(format!("capture truncated at byte {at}"))
+    }
+
+    fn u16(&self, at: usize) -> Result<u16, String> {
+        let b = self.slice(at, 2)?;
+        let b = [b[0], b[1]];
+        return Ok(if self.big_endian { u16::from_be_bytes(b) } else { u16::from_le_bytes(b) });
+    }
+
+    fn u32(&self, at: usize) -> Result<u32, String> {
+        let b = self.slice(at, 4)?;
+        let b = [b[0], b[1], b[2], b[3]];
+        return Ok(if self.big_endian { u32::from_be_bytes(b) } else { u32::from_le_bytes(b) });
+    }
+}
+
+fn read_pcap(bytes: &[u8], big_endian: bool) -> Result<Vec<CapturedDatagram>, String> {
+    let reader = Reader { bytes, big_endian };
+    let link_type = reader.u32(20)?;
+
+    let mut datagrams: Vec<CapturedDatagram> = vec![];
+    let mut at: usize = 24;
+    let mut frame: usize = 0;
+
+    while at < bytes.len() {
+        frame += 1;
+        let captured = reader.u32(at + 8)? as usize;
+        let data = reader.slice(at + 16, captured)?;
+
+        if let Some(v) = udp_datagram(frame, link_type, data) {
+            datagrams.push(v);
+        }
+
+        at += 16 + captured;
+    }
+
+    return Ok(datagrams);
+}
+
+fn read_pcapng(bytes: &[u8]) -> Result<Vec<CapturedDatagram>, String> {
+    let mut reader = Reader { bytes, big_endian: false };
+    let mut link_types: Vec<u32> = vec![];
+
+    let mut datagrams: Vec<CapturedDatagram> = vec![];
+    let mut at: usize = 0;
+    let mut frame: usize = 0;
+
+    while at < bytes.len() {
+        // the byte order magic of each section decides how the rest of it is read
+        if reader.slice(at, 4)? == PCAPNG_SECTION_HEADER.to_le_bytes() {
+            let magic = reader.slice(at + 8, 4)?;
+            reader.big_endian = magic == [0x1a, 0x2b, 0x3c, 0x4d];
+            link_types.clear();
+        }
+
+        let block_type = reader.u32(at)?;
+        let length = reader.u32(at + 4)? as usize;
+        let length_min = match block_type {
+            PCAPNG_SIMPLE_PACKET => PCAPNG_SIMPLE_PACKET_LENGTH_MIN,
+            PCAPNG_ENHANCED_PACKET => PCAPNG_ENHANCED_PACKET_LENGTH_MIN,
+            _ => 12,
+        };
+        if length < length_min || !length.is_multiple_of(4) {
+            return Err(format!("invalid pcapng block length {length} at byte {at}"));
+        }
+        reader.slice(at, length)?;
+
+        match block_type {
+            PCAPNG_INTERFACE_DESCRIPTION => {
+                link_types.push(reader.u16(at + 8)? as u32);
+            },
+            PCAPNG_ENHANCED_PACKET => {
+                frame += 1;
+                let interface = reader.u32(at + 8)? as usize;
+                let captured = reader.u32(at + 20)? as usize;
+                let data = reader.slice(at + 28, captured)?;
+                let link_type = *link_types.get(interface)
+                    .ok_or(format!("packet on unknown interface {interface} at byte {at}"))?;
+
+                if let Some(v) = udp_datagram(frame, link_type, data) {
+                    datagrams.push(v);
+                }
+            },
+            PCAPNG_SIMPLE_PACKET => {
+                frame += 1;
+                let original = reader.u32(at + 8)? as usize;
+                let data = reader.slice(at + 12, original.min(length - PCAPNG_SIMPLE_PACKET_LENGTH_MIN))?;
+                let link_type = *link_types.first()
+                    .ok_or(format!("packet before any interface at byte {at}"))?;
+
+                if let Some(v) = udp_datagram(frame, link_type, data) {
+                    datagrams.push(v);
+                }
+            },
+            _ => {},
+        }
+
+        at += length;
+    }
+
+    return Ok(datagrams);
+}
+
+fn udp_datagram(frame: usize, link_type: u32, data: &[u8]) -> Option<CapturedDatagram> {
+    let ip = match link_type {
+        LINKTYPE_NULL => data.get(4..)?,
+        LINKTYPE_RAW => data,
+        LINKTYPE_ETHERNET => {
+            let mut ether_type = u16::from_be_bytes([*data.get(12)?, *data.get(13)?]);
+            let mut offset = 14;
+            if ether_type == ETHERTYPE_VLAN {
+                ether_type = u16::from_be_bytes([*data.get(16)?, *data.get(17)?]);
+                offset = 18;
+            }
+            if ether_type != ETHERTYPE_IPV4 && ether_type != ETHERTYPE_IPV6 {
+                return None;
+            }
+            data.get(offset..)?
+        },
+        LINKTYPE_LINUX_SLL => data.get(16..)?,
+        LINKTYPE_LINUX_SLL2 => data.get(20..)?,
+        _ => { return None; }
+    };
+
+    let (source_ip, destination_ip, udp) = match ip.first()? >> 4 {
+        4 => {
+            let header = ((ip[0] & 0x0f) as usize) * 4;
+            if *ip.get(9)? != IP_PROTOCOL_UDP {
+                return None;
+            }
+            let source: [u8; 4] = ip.get(12..16)?.try_into().ok()?;
+            let destination: [u8; 4] = ip.get(16..20)?.try_into().ok()?;
+            (IpAddr::V4(Ipv4Addr::from(source)), IpAddr::V4(Ipv4Addr::from(destination)), ip.get(header..)?)
+        },
+        6 => {
+            if *ip.get(6)? != IP_PROTOCOL_UDP {
+                return None;
+            }
+            let source: [u8; 16] = ip.get(8..24)?.try_into().ok()?;
+            let destination: [u8; 16] = ip.get(24..40)?.try_into().ok()?;
+            (IpAddr::V6(Ipv6Addr::from(source)), IpAddr::V6(Ipv6Addr::from(destination)), ip.get(40..)?)
+        },
+        _ => { return None; }
+    };
+
+    let source_port = u16::from_be_bytes([*udp.first()?, *udp.get(1)?]);
+    let destination_port = u16::from_be_bytes([*udp.get(2)?, *udp.get(3)?]);
+    let length = u16::from_be_bytes([*udp.get(4)?, *udp.get(5)?]) as usize;
+    // a snapped capture keeps what it has, the dissector reports it as truncated
+    let end = length.max(8).min(udp.len());
+
+    return Some(CapturedDatagram {
+        frame,
+        source: SocketAddr::new(source_ip, source_port),
+        destination: SocketAddr::new(destination_ip, destination_port),
+        payload: udp.get(8..end)?.to_vec(),
+    });
+}
+
+// Hex dump input: whitespace, colons and dashes between bytes are ignored.
+pub fn parse_hex(line: &str) -> Result<Vec<u8>, String> {
+    let digits: Vec<char> = line.chars()
+        .filter(|c| !c.is_whitespace() && *c != ':' && *c != '-')
+        .collect();
+    if !digits.len().is_multiple_of(2) {
+        return Err(String::from("odd number of hex digits"));
+    }
+    if let Some(c) = digits.iter().find(|c| !c.is_ascii_hexdigit()) {
+        return Err(format!("invalid hex digit \"{c}\""));
+    }
+
+    digits.chunks(2)
+        .map(|pair| {
+            let text: String = pair.iter().collect();
+            u8::from_str_radix(text.as_str(), 16)
+                .map_err(|e| e.to_string())
+        })
+        .collect()
+}
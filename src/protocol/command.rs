@@ -0,0 +1,187 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{SocketAddr, UdpSocket};
+use std::sync::mpsc::Receiver;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// project
+use crate::station::station::Station;
+use crate::station::components::PowerDown;
+use crate::packets::command::{Command, CommandPacket};
+use crate::packets::ack::{AckPacket, AckStatus};
+use crate::terminalisp::command as tl_command;
+
+// module
+use crate::protocol::port_address::PortAddressRange;
+use crate::protocol::udp;
+use crate::protocol::udp::Datagram;
+
+
+// The same calls the menu makes for each choice.
+pub fn apply_command(station: &mut Station, command: &Command) -> Result<String, String> {
+    match command {
+        Command::Repair(path) => {
+            station.repair_path(path.as_str())
+                .map_err(|e| e.to_string())
+        },
+        Command::PowerDown => {
+            station.power_down();
+            Ok(station.status_header().to_string())
+        },
+        Command::NewDay => {
+            station.new_day();
+            Ok(station.mission_day_display())
+        },
+        Command::StatusRequest => {
+            Ok(station.status_header().to_string())
+        },
+    }
+}
+
+// Sequences are microsecond timestamps, commands further off than this are not taken.
+pub const COMMAND_WINDOW: Duration = Duration::from_secs(300);
+
+pub fn sequence_now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as u64)
+        .unwrap_or(1)
+}
+
+// Station side: authenticates commands forwarded by the beacon and applies them in order.
+// The last sequence lives in the station, so it is saved and a restart can't reopen it.
+pub struct CommandInbox {
+    receiver: Receiver<Datagram>,
+    socket: UdpSocket,
+    key: Vec<u8>,
+}
+
+impl CommandInbox {
+    pub fn new(receiver: Receiver<Datagram>, socket: UdpSocket, key: Vec<u8>) -> Self {
+        CommandInbox {
+            receiver,
+            socket,
+            key,
+        }
+    }
+
+    // Applies everything that arrived since the last call, without blocking.
+    pub fn process(&mut self, station: &mut Station) -> Vec<AckPacket> {
+        let mut acks: Vec<AckPacket> = vec![];
+
+        while let Ok((bytes, source)) = self.receiver.try_recv() {
+            if let Some(ack) = self.handle(station, &bytes, source) {
+                // the sender resends when an ack is lost
+                let _ = udp::send(&self.socket, &ack.encode(&self.key), source);
+                acks.push(ack);
+            }
+        }
+
+        return acks;
+    }
+
+    // Unauthenticated packets get no answer, commands for other port addresses are ignored.
+    pub fn handle(&mut self, station: &mut Station, bytes: &[u8], source: SocketAddr) -> Option<AckPacket> {
+        let packet = match CommandPacket::decode(bytes, &self.key) {
+            Ok(v) => v,
+            Err(e) => {
+                tl_command::command_rejected(source.to_string(), e.to_string());
+                return None;
+            }
+        };
+
+        if !packet.target_range.contains_any(&station.port_addresses()) {
+            return None;
+        }
+
+        // a station that never saw the original can still be handed a captured command
+        let error = match station.last_command_sequence() {
+            _ if packet.sequence.abs_diff(sequence_now()) > COMMAND_WINDOW.as_micros() as u64 => {
+                Some(format!("sequence {} outside the command window", packet.sequence))
+            },
+            Some(last) if packet.sequence <= last => {
+                Some(format!("replayed sequence {}, last was {}", packet.sequence, last))
+            },
+            _ => None,
+        };
+        if let Some(error) = error {
+            tl_command::command_rejected(source.to_string(), error.clone());
+            return Some(AckPacket {
+                sequence: packet.sequence,
+                status: AckStatus::Rejected,
+                message: error,
+            });
+        }
+        station.set_last_command_sequence(packet.sequence);
+
+        tl_command::command_received(source.to_string(), packet.sequence, packet.command.to_string());
+
+        let ack = match apply_command(station, &packet.command) {
+            Ok(message) => AckPacket { sequence: packet.sequence, status: AckStatus::Applied, message },
+            Err(message) => AckPacket { sequence: packet.sequence, status: AckStatus::Failed, message },
+        };
+        tl_command::command_ack(&ack);
+
+        return Some(ack);
+    }
+}
+
+// Mission control side: signs commands with increasing sequence numbers and waits for the ack.
+pub struct CommandSender {
+    socket: UdpSocket,
+    key: Vec<u8>,
+    next_sequence: u64,
+}
+
+impl CommandSender {
+    pub fn new(address: &str, key: Vec<u8>) -> Result<Self, String> {
+        let socket = udp::bind(address)?;
+
+        // a restarted mission control must not reuse sequence numbers the station has seen
+        let next_sequence = sequence_now();
+
+        return Ok(CommandSender { socket, key, next_sequence });
+    }
+
+    pub fn send(&mut self,
+                target: SocketAddr,
+                target_range: PortAddressRange,
+                command: Command,
+                timeout: Duration) -> Result<AckPacket, String> {
+
+        // keep up with the clock, so a long-running sender stays inside the command window
+        self.next_sequence = self.next_sequence.max(sequence_now());
+        let packet = CommandPacket {
+            sequence: self.next_sequence,
+            target_range,
+            command,
+        };
+        self.next_sequence += 1;
+
+        udp::send(&self.socket, &packet.encode(&self.key)?, target)?;
+
+        let deadline = Instant::now() + timeout;
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+
+            let (bytes, _) = match udp::try_receive(&self.socket, remaining)? {
+                Some(v) => v,
+                None => { break; }
+            };
+
+            if let Ok(ack) = AckPacket::decode(&bytes, &self.key) {
+                if ack.sequence == packet.sequence {
+                    return Ok(ack);
+                }
+            }
+        }
+
+        return Err(format!("{target}: no acknowledgement for sequence {}", packet.sequence));
+    }
+}
@@ -0,0 +1,59 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// module
+use crate::protocol::constants::{STATION_PACKET_ID, HEADER_SIZE,
+                                 PROTOCOL_VERSION_HI, PROTOCOL_VERSION_LO};
+use crate::protocol::error::DecodeError;
+use crate::protocol::op_codes;
+
+
+pub fn op_code_little_endian(op_code: u16) -> [u8; 2] {
+    op_code.to_le_bytes()
+}
+
+pub fn op_code_from_little_endian(bytes: [u8; 2]) -> u16 {
+    u16::from_le_bytes(bytes)
+}
+
+// Every packet starts with the same header: id, op code (little-endian), protocol version.
+pub fn write_header(buf: &mut Vec<u8>, op_code: u16) {
+    buf.extend_from_slice(&STATION_PACKET_ID);
+    buf.extend_from_slice(&op_code_little_endian(op_code));
+    buf.push(PROTOCOL_VERSION_HI);
+    buf.push(PROTOCOL_VERSION_LO);
+}
+
+// Checked in wire order, so a short foreign datagram is a bad magic and not a short buffer.
+pub fn read_op_code(bytes: &[u8]) -> Result<u16, DecodeError> {
+    let id_length = bytes.len().min(STATION_PACKET_ID.len());
+    if bytes[..id_length] != STATION_PACKET_ID[..id_length] {
+        return Err(DecodeError::BadMagic);
+    }
+    if bytes.len() < HEADER_SIZE {
+        return Err(DecodeError::ShortBuffer { length: bytes.len(), expected: HEADER_SIZE });
+    }
+    if bytes[10] != PROTOCOL_VERSION_HI || bytes[11] != PROTOCOL_VERSION_LO {
+        return Err(DecodeError::VersionMismatch { hi: bytes[10], lo: bytes[11] });
+    }
+
+    let op_code = op_code_from_little_endian([bytes[8], bytes[9]]);
+    if !op_codes::is_known(op_code) {
+        return Err(DecodeError::UnknownOpCode(op_code));
+    }
+
+    return Ok(op_code);
+}
+
+pub fn read_header(bytes: &[u8], op_code: u16, size: usize) -> Result<(), DecodeError> {
+    let found = read_op_code(bytes)?;
+    if found != op_code {
+        return Err(DecodeError::UnexpectedOpCode { found, expected: op_code });
+    }
+    if bytes.len() < size {
+        return Err(DecodeError::ShortBuffer { length: bytes.len(), expected: size });
+    }
+
+    return Ok(());
+}
@@ -0,0 +1,31 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+pub const STATION_PACKET_ID : [u8; 8] = *b"Station\0";
+
+pub const PROTOCOL_VERSION_HI : u8 = 0;
+pub const PROTOCOL_VERSION_LO : u8 = 14;
+
+// id, op code, protocol version
+pub const HEADER_SIZE : usize = 12;
+
+pub const PORT_ADDRESS_MIN : u16 = 0;
+pub const PORT_ADDRESS_MAX : u16 = 32767;
+
+pub const DEFAULT_BROADCAST_IP_2 : &str = "2.255.255.255";
+pub const DEFAULT_BROADCAST_IP_10 : &str = "10.255.255.255";
+
+pub const DEFAULT_IP_PORT : u16 = 0x1936;
+
+pub const MAX_PACKET_SIZE : usize = 1500;
+
+// ping flags
+pub const FLAG_SEND_DIAGNOSTICS : u8 = 0b0000_0100;
+
+// diagnostics at or above the priority a subscriber asked for are sent
+pub const DP_LOW : u8 = 0x10;
+pub const DP_MED : u8 = 0x40;
+pub const DP_HIGH : u8 = 0x80;
+pub const DP_CRITICAL : u8 = 0xe0;
+pub const DP_VOLATILE : u8 = 0xf0;
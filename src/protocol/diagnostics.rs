@@ -0,0 +1,48 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::events::SystemEvent;
+
+// module
+use crate::protocol::constants::{DP_LOW, DP_MED, DP_HIGH, DP_CRITICAL, DP_VOLATILE};
+
+
+pub fn event_priority(event: &SystemEvent) -> u8 {
+    match event {
+        SystemEvent::FinalTransmissionCountdown(_) => DP_LOW,
+        SystemEvent::ModuleRepaired(_)
+        | SystemEvent::ModuleRestored(_)
+        | SystemEvent::StationEvent(_)
+        | SystemEvent::IncomingMessage(_) => DP_MED,
+        SystemEvent::ModuleFailure(_) => DP_HIGH,
+        SystemEvent::PowerDown
+        | SystemEvent::Shutdown
+        | SystemEvent::FinalTransmission => DP_CRITICAL,
+    }
+}
+
+// only the documented levels have names
+pub fn priority_name(priority: u8) -> Option<&'static str> {
+    match priority {
+        DP_LOW => Some("low"),
+        DP_MED => Some("medium"),
+        DP_HIGH => Some("high"),
+        DP_CRITICAL => Some("critical"),
+        DP_VOLATILE => Some("volatile"),
+        _ => None,
+    }
+}
+
+pub fn parse_priority(value: &str) -> Result<u8, String> {
+    match value {
+        "low" => Ok(DP_LOW),
+        "medium" => Ok(DP_MED),
+        "high" => Ok(DP_HIGH),
+        "critical" => Ok(DP_CRITICAL),
+        "volatile" => Ok(DP_VOLATILE),
+        _ => value.parse::<u8>()
+            .map_err(|_| format!("invalid diagnostic priority: {value}")),
+    }
+}
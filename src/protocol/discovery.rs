@@ -0,0 +1,85 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+// project
+use crate::packets::ping::PingPacket;
+use crate::packets::ping_reply::PingReply;
+
+// module
+use crate::protocol::constants::DEFAULT_IP_PORT;
+use crate::protocol::udp;
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DiscoveredStation {
+    pub address: SocketAddr,
+    pub reply: PingReply,
+}
+
+pub fn discover(broadcast_ip: &str, timeout: Duration) -> Result<Vec<DiscoveredStation>, String> {
+    let target: SocketAddr = format!("{broadcast_ip}:{DEFAULT_IP_PORT}")
+        .parse()
+        .map_err(|_| format!("invalid broadcast address \"{broadcast_ip}\""))?;
+
+    return discover_at(&[target], &PingPacket::new(), timeout);
+}
+
+// Pings every target (broadcast addresses or single stations) and collects replies until the timeout.
+pub fn discover_at(targets: &[SocketAddr],
+                   ping: &PingPacket,
+                   timeout: Duration) -> Result<Vec<DiscoveredStation>, String> {
+
+    let loopback = targets.iter().all(|target| target.ip().is_loopback());
+    let socket = udp::bind(if loopback { "127.0.0.1:0" } else { "0.0.0.0:0" })?;
+    socket.set_broadcast(true)
+        .map_err(|e| e.to_string())?;
+
+    for target in targets {
+        udp::send_ping(&socket, ping, *target)?;
+    }
+
+    return collect_replies(&socket, timeout);
+}
+
+pub fn collect_replies(socket: &UdpSocket, timeout: Duration) -> Result<Vec<DiscoveredStation>, String> {
+    let mut stations: Vec<DiscoveredStation> = vec![];
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+
+        let (bytes, address) = match udp::try_receive(socket, remaining)? {
+            Some(v) => v,
+            None => { break; }
+        };
+
+        // anything that is not a reply is someone else's traffic on the port
+        if let Ok(reply) = PingReply::decode(&bytes) {
+            stations.retain(|station| station.address != address);
+            stations.push(DiscoveredStation { address, reply });
+        }
+    }
+
+    return Ok(stations);
+}
+
+// Station side: waits for one packet and answers it if it is a ping.
+pub fn answer_ping(socket: &UdpSocket, reply: &PingReply, timeout: Option<Duration>) -> Result<bool, String> {
+    let (bytes, source) = udp::receive(socket, timeout)?;
+
+    if PingPacket::decode(&bytes).is_err() {
+        return Ok(false);
+    }
+
+    udp::send(socket, &reply.encode(), source)?;
+
+    return Ok(true);
+}
@@ -0,0 +1,157 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::packets::ping::{PingPacket, PING_PACKET_SIZE};
+use crate::packets::ping_reply::{PingReply, PING_REPLY_PACKET_SIZE};
+use crate::packets::failure::{FailurePacket, FAILURE_PACKET_SIZE_MIN};
+use crate::packets::diagnostic::{DiagnosticPacket, DIAGNOSTIC_PACKET_SIZE_MIN};
+use crate::packets::command::{CommandPacket, COMMAND_PACKET_SIZE_MIN, is_command};
+use crate::packets::ack::{AckPacket, ACK_PACKET_SIZE_MIN};
+use crate::packets::journal_chunk::{JournalChunkPacket, JOURNAL_CHUNK_PACKET_SIZE_MIN};
+use crate::packets::journal_resend::{JournalResendPacket, JOURNAL_RESEND_PACKET_SIZE_MIN};
+
+// module
+use crate::protocol::auth;
+use crate::protocol::common::op_code_from_little_endian;
+use crate::protocol::error::DecodeError;
+use crate::protocol::constants::{STATION_PACKET_ID, HEADER_SIZE,
+                                 PROTOCOL_VERSION_HI, PROTOCOL_VERSION_LO};
+use crate::protocol::op_codes::*;
+
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum MacCheck {
+    Verified,
+    // no key was given, the MAC is shown but not checked
+    Unchecked,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Fields {
+    Ping(PingPacket),
+    PingReply(PingReply),
+    Failure(FailurePacket),
+    Diagnostic(DiagnosticPacket),
+    Command(CommandPacket, MacCheck),
+    Ack(AckPacket, MacCheck),
+    JournalChunk(JournalChunkPacket),
+    JournalResend(JournalResendPacket),
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Problem {
+    NotStationPacket,
+    Truncated(String),
+    Malformed(DecodeError),
+    VersionMismatch,
+    UnknownOpCode,
+    BadMac(DecodeError),
+}
+
+// Everything that could be read from one datagram, even when it does not decode.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Dissection {
+    pub length: usize,
+    pub op_code: Option<u16>,
+    pub version: Option<(u8, u8)>,
+    pub result: Result<Fields, Problem>,
+}
+
+pub fn op_code_name(op_code: u16) -> Option<&'static str> {
+    match op_code {
+        OP_PING => Some("ping"),
+        OP_PING_REPLY => Some("ping-reply"),
+        OP_FAILURE => Some("failure"),
+        OP_DIAGNOSTIC => Some("diagnostic"),
+        OP_JOURNAL_CHUNK => Some("journal-chunk"),
+        OP_JOURNAL_RESEND => Some("journal-resend"),
+        OP_COMMAND_REPAIR => Some("command-repair"),
+        OP_COMMAND_POWER_DOWN => Some("command-power-down"),
+        OP_COMMAND_NEW_DAY => Some("command-new-day"),
+        OP_COMMAND_STATUS_REQUEST => Some("command-status-request"),
+        OP_COMMAND_ACK => Some("command-ack"),
+        _ => None,
+    }
+}
+
+pub fn is_station_packet(bytes: &[u8]) -> bool {
+    bytes.len() >= STATION_PACKET_ID.len() && bytes[..STATION_PACKET_ID.len()] == STATION_PACKET_ID
+}
+
+// Unlike the decoders, reads as much of the header as there is before giving up.
+pub fn dissect(bytes: &[u8], key: Option<&[u8]>) -> Dissection {
+    let mut dissection = Dissection {
+        length: bytes.len(),
+        op_code: None,
+        version: None,
+        result: Err(Problem::NotStationPacket),
+    };
+
+    if !is_station_packet(bytes) {
+        return dissection;
+    }
+    if bytes.len() < HEADER_SIZE {
+        dissection.result = Err(Problem::Truncated(
+            format!("{} bytes, header needs {}", bytes.len(), HEADER_SIZE)));
+        return dissection;
+    }
+
+    let op_code = op_code_from_little_endian([bytes[8], bytes[9]]);
+    dissection.op_code = Some(op_code);
+    dissection.version = Some((bytes[10], bytes[11]));
+
+    if bytes[10] != PROTOCOL_VERSION_HI || bytes[11] != PROTOCOL_VERSION_LO {
+        dissection.result = Err(Problem::VersionMismatch);
+        return dissection;
+    }
+
+    let size_min = match op_code {
+        OP_PING => PING_PACKET_SIZE,
+        OP_PING_REPLY => PING_REPLY_PACKET_SIZE,
+        OP_FAILURE => FAILURE_PACKET_SIZE_MIN,
+        OP_DIAGNOSTIC => DIAGNOSTIC_PACKET_SIZE_MIN,
+        OP_JOURNAL_CHUNK => JOURNAL_CHUNK_PACKET_SIZE_MIN,
+        OP_JOURNAL_RESEND => JOURNAL_RESEND_PACKET_SIZE_MIN,
+        OP_COMMAND_ACK => ACK_PACKET_SIZE_MIN,
+        _ if is_command(op_code) => COMMAND_PACKET_SIZE_MIN,
+        _ => {
+            dissection.result = Err(Problem::UnknownOpCode);
+            return dissection;
+        }
+    };
+    if bytes.len() < size_min {
+        dissection.result = Err(Problem::Truncated(
+            format!("{} bytes, expected at least {}", bytes.len(), size_min)));
+        return dissection;
+    }
+
+    dissection.result = decode(op_code, bytes, key);
+
+    return dissection;
+}
+
+fn decode(op_code: u16, bytes: &[u8], key: Option<&[u8]>) -> Result<Fields, Problem> {
+    // signed packets are checked first, a bad MAC means the body can't be trusted
+    let mac = match key {
+        Some(key) if op_code == OP_COMMAND_ACK || is_command(op_code) => {
+            auth::verified(key, bytes).map_err(Problem::BadMac)?;
+            MacCheck::Verified
+        },
+        _ => MacCheck::Unchecked,
+    };
+
+    let fields = match op_code {
+        OP_PING => PingPacket::decode(bytes).map(Fields::Ping),
+        OP_PING_REPLY => PingReply::decode(bytes).map(Fields::PingReply),
+        OP_FAILURE => FailurePacket::decode(bytes).map(Fields::Failure),
+        OP_DIAGNOSTIC => DiagnosticPacket::decode(bytes).map(Fields::Diagnostic),
+        OP_JOURNAL_CHUNK => JournalChunkPacket::decode(bytes).map(Fields::JournalChunk),
+        OP_JOURNAL_RESEND => JournalResendPacket::decode(bytes).map(Fields::JournalResend),
+        OP_COMMAND_ACK => AckPacket::decode_unverified(bytes).map(|v| Fields::Ack(v, mac)),
+        _ => CommandPacket::decode_unverified(bytes).map(|v| Fields::Command(v, mac)),
+    };
+
+    return fields.map_err(Problem::Malformed);
+}
@@ -0,0 +1,304 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+// project
+use crate::station::station::Station;
+use crate::journal::journal::Journal;
+use crate::packets::journal_chunk::{JournalChunkPacket, CHUNK_PAYLOAD_SIZE_MAX};
+use crate::packets::journal_resend::{JournalResendPacket, MISSING_COUNT_MAX};
+
+// module
+use crate::protocol::udp;
+
+
+// rounds of resend requests before a receiver gives a transfer up
+pub const RESEND_ROUNDS_MAX : u32 = 5;
+
+// completed transfers remembered, so late duplicates don't start a new one
+const COMPLETED_MAX : usize = 16;
+
+// chunks are unauthenticated, so a receiver only sets this much aside for them
+pub const TRANSFER_CHUNKS_MAX : u16 = 4096;
+pub const TRANSFERS_MAX : usize = 8;
+
+pub fn split_journal(journal: &Journal, transfer: u32) -> Result<Vec<JournalChunkPacket>, String> {
+    let content = journal.to_lines()?;
+    let payloads: Vec<&[u8]> = content.as_bytes().chunks(CHUNK_PAYLOAD_SIZE_MAX).collect();
+    if payloads.len() > TRANSFER_CHUNKS_MAX as usize {
+        return Err(format!("journal too large for one downlink: {} bytes", content.len()));
+    }
+
+    let total = payloads.len() as u16;
+    return Ok(payloads
+        .into_iter()
+        .enumerate()
+        .map(|(sequence, payload)| JournalChunkPacket {
+            transfer,
+            sequence: sequence as u16,
+            total,
+            payload: payload.to_vec(),
+        })
+        .collect());
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DownlinkReport {
+    pub transfer: u32,
+    pub chunks: usize,
+    pub resent: usize,
+    pub confirmed: bool,
+}
+
+// Station side: one journal transfer, answering resend requests until the receiver confirms it.
+pub struct JournalDownlink {
+    socket: UdpSocket,
+    transfer: u32,
+    chunks: Vec<JournalChunkPacket>,
+}
+
+impl JournalDownlink {
+    pub fn new(station: &Station, journal: &Journal, socket: UdpSocket) -> Result<Self, String> {
+        if !station.downlink_available() {
+            return Err(String::from("downlink needs an active antenna or transponder"));
+        }
+
+        let transfer = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_micros() as u32)
+            .unwrap_or(1);
+        let chunks = split_journal(journal, transfer)?;
+
+        return Ok(JournalDownlink { socket, transfer, chunks });
+    }
+
+    pub fn transfer(&self) -> u32 { self.transfer }
+    pub fn chunks(&self) -> &[JournalChunkPacket] { &self.chunks }
+
+    pub fn send_chunk(&self, target: SocketAddr, sequence: u16) -> Result<(), String> {
+        match self.chunks.get(sequence as usize) {
+            Some(chunk) => udp::send(&self.socket, &chunk.encode(), target),
+            None => Err(format!("no chunk {sequence} in transfer {}", self.transfer)),
+        }
+    }
+
+    pub fn send(&self, target: SocketAddr, timeout: Duration) -> Result<DownlinkReport, String> {
+        for sequence in 0..self.chunks.len() {
+            self.send_chunk(target, sequence as u16)?;
+        }
+
+        return self.serve(target, timeout);
+    }
+
+    pub fn serve(&self, target: SocketAddr, timeout: Duration) -> Result<DownlinkReport, String> {
+        let mut report = DownlinkReport {
+            transfer: self.transfer,
+            chunks: self.chunks.len(),
+            resent: 0,
+            confirmed: false,
+        };
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+
+            let (bytes, source) = match udp::try_receive(&self.socket, remaining)? {
+                Some(v) => v,
+                None => { break; }
+            };
+            if source != target {
+                continue;
+            }
+
+            let request = match JournalResendPacket::decode(&bytes) {
+                Ok(v) if v.transfer == self.transfer => v,
+                _ => { continue; }
+            };
+            if request.complete() {
+                report.confirmed = true;
+                break;
+            }
+            for sequence in request.missing {
+                // a request for a chunk that was never sent is the receiver's problem
+                if self.send_chunk(target, sequence).is_ok() {
+                    report.resent += 1;
+                }
+            }
+        }
+
+        return Ok(report);
+    }
+}
+
+// Receiver side bookkeeping of one transfer.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reassembly {
+    transfer: u32,
+    chunks: Vec<Option<Vec<u8>>>,
+}
+
+impl Reassembly {
+    pub fn new(transfer: u32, total: u16) -> Result<Self, String> {
+        if total == 0 || total > TRANSFER_CHUNKS_MAX {
+            return Err(format!("transfer {} announces {} chunks, at most {} are taken",
+                               transfer, total, TRANSFER_CHUNKS_MAX));
+        }
+
+        return Ok(Reassembly {
+            transfer,
+            chunks: vec![None; total as usize],
+        });
+    }
+
+    pub fn add(&mut self, chunk: &JournalChunkPacket) -> Result<(), String> {
+        if chunk.transfer != self.transfer || chunk.total as usize != self.chunks.len() {
+            return Err(format!("chunk {} does not belong to transfer {}", chunk.sequence, self.transfer));
+        }
+
+        match self.chunks.get_mut(chunk.sequence as usize) {
+            Some(slot) => { *slot = Some(chunk.payload.clone()); },
+            None => { return Err(format!("chunk {} out of range", chunk.sequence)); }
+        }
+
+        return Ok(());
+    }
+
+    pub fn missing(&self) -> Vec<u16> {
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, chunk)| chunk.is_none())
+            .map(|(sequence, _)| sequence as u16)
+            .collect()
+    }
+
+    pub fn is_complete(&self) -> bool {
+        self.chunks.iter().all(|chunk| chunk.is_some())
+    }
+
+    pub fn journal(&self) -> Result<Journal, String> {
+        if !self.is_complete() {
+            return Err(format!("transfer {} is missing {} chunks", self.transfer, self.missing().len()));
+        }
+
+        let bytes: Vec<u8> = self.chunks.iter().flatten().flatten().copied().collect();
+        let content = String::from_utf8(bytes)
+            .map_err(|_| format!("transfer {} is not valid UTF-8", self.transfer))?;
+
+        return Journal::from_lines(content.as_str());
+    }
+}
+
+struct Transfer {
+    source: SocketAddr,
+    reassembly: Reassembly,
+    last_chunk: Instant,
+    resend_rounds: u32,
+}
+
+// Mission control side: reassembles journals from any number of stations at once.
+pub struct DownlinkReceiver {
+    transfers: Vec<Transfer>,
+    completed: Vec<(SocketAddr, u32)>,
+}
+
+impl Default for DownlinkReceiver {
+    fn default() -> Self {
+        DownlinkReceiver::new()
+    }
+}
+
+impl DownlinkReceiver {
+    pub fn new() -> Self {
+        DownlinkReceiver {
+            transfers: vec![],
+            completed: vec![],
+        }
+    }
+
+    pub fn pending(&self) -> bool {
+        !self.transfers.is_empty()
+    }
+
+    // The finished journal comes back once, after its confirmation went out.
+    pub fn chunk(&mut self,
+                 socket: &UdpSocket,
+                 source: SocketAddr,
+                 chunk: &JournalChunkPacket) -> Result<Option<Journal>, String> {
+
+        let key = (source, chunk.transfer);
+        if self.completed.contains(&key) {
+            // the sender missed the confirmation
+            return self.confirm(socket, source, chunk.transfer).map(|_| None);
+        }
+
+        let index = match self.transfers.iter().position(|v| (v.source, v.reassembly.transfer) == key) {
+            Some(v) => v,
+            None => {
+                let reassembly = Reassembly::new(chunk.transfer, chunk.total)?;
+                // a station downlinks one journal at a time, a new transfer replaces its old one
+                self.transfers.retain(|v| v.source != source);
+                if self.transfers.len() >= TRANSFERS_MAX {
+                    return Err(format!("too many pending transfers, dropping transfer {} from {}",
+                                       chunk.transfer, source));
+                }
+                self.transfers.push(Transfer {
+                    source,
+                    reassembly,
+                    last_chunk: Instant::now(),
+                    resend_rounds: 0,
+                });
+                self.transfers.len() - 1
+            }
+        };
+
+        let transfer = &mut self.transfers[index];
+        transfer.reassembly.add(chunk)?;
+        transfer.last_chunk = Instant::now();
+        if !transfer.reassembly.is_complete() {
+            return Ok(None);
+        }
+
+        let transfer = self.transfers.swap_remove(index);
+        self.completed.push(key);
+        if self.completed.len() > COMPLETED_MAX {
+            self.completed.remove(0);
+        }
+        self.confirm(socket, source, chunk.transfer)?;
+
+        return transfer.reassembly.journal().map(Some);
+    }
+
+    // Asks again for what is missing from transfers that went quiet for `gap`.
+    pub fn request_resends(&mut self, socket: &UdpSocket, gap: Duration) -> Result<(), String> {
+        self.transfers.retain(|v| v.resend_rounds < RESEND_ROUNDS_MAX || v.last_chunk.elapsed() < gap);
+
+        for transfer in &mut self.transfers {
+            if transfer.last_chunk.elapsed() < gap {
+                continue;
+            }
+
+            let mut missing = transfer.reassembly.missing();
+            missing.truncate(MISSING_COUNT_MAX);
+            let request = JournalResendPacket { transfer: transfer.reassembly.transfer, missing };
+            udp::send(socket, &request.encode(), transfer.source)?;
+
+            transfer.resend_rounds += 1;
+            transfer.last_chunk = Instant::now();
+        }
+
+        return Ok(());
+    }
+
+    fn confirm(&self, socket: &UdpSocket, source: SocketAddr, transfer: u32) -> Result<(), String> {
+        let request = JournalResendPacket { transfer, missing: vec![] };
+        udp::send(socket, &request.encode(), source)
+    }
+}
@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::error::Error;
+use std::fmt;
+
+
+// Everything a decoder can say about a datagram it refuses.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    BadMagic,
+    UnknownOpCode(u16),
+    UnexpectedOpCode { found: u16, expected: u16 },
+    UnexpectedOpCodes { found: u16, expected: &'static [u16] },
+    ShortBuffer { length: usize, expected: usize },
+    VersionMismatch { hi: u8, lo: u8 },
+    LengthMismatch { field: &'static str, length: usize },
+    InvalidUtf8(&'static str),
+    InvalidValue { field: &'static str, value: u32 },
+    AuthenticationFailed,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::BadMagic => write!(f, "packet id is not a station packet"),
+            DecodeError::UnknownOpCode(op_code) => write!(f, "unknown op code 0x{op_code:04x}"),
+            DecodeError::UnexpectedOpCode { found, expected } => {
+                write!(f, "unexpected op code 0x{found:04x}, expected 0x{expected:04x}")
+            },
+            DecodeError::UnexpectedOpCodes { found, expected } => {
+                let expected: Vec<String> = expected.iter().map(|v| format!("0x{v:04x}")).collect();
+                write!(f, "unexpected op code 0x{found:04x}, expected one of {}", expected.join(", "))
+            },
+            DecodeError::ShortBuffer { length, expected } => {
+                write!(f, "packet too short: {length} bytes, expected at least {expected}")
+            },
+            DecodeError::VersionMismatch { hi, lo } => write!(f, "protocol version {hi}.{lo} not supported"),
+            DecodeError::LengthMismatch { field, length } => {
+                write!(f, "{field} length {length} does not match the packet")
+            },
+            DecodeError::InvalidUtf8(field) => write!(f, "{field} is not valid UTF-8"),
+            DecodeError::InvalidValue { field, value } => write!(f, "invalid {field} {value}"),
+            DecodeError::AuthenticationFailed => write!(f, "authentication failed"),
+        }
+    }
+}
+
+impl Error for DecodeError {}
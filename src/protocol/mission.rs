@@ -0,0 +1,163 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::{SocketAddr, UdpSocket};
+use std::time::{Duration, Instant};
+
+// project
+use crate::packets::ping::PingPacket;
+use crate::packets::ping_reply::PingReply;
+use crate::packets::failure::FailurePacket;
+use crate::packets::diagnostic::DiagnosticPacket;
+use crate::packets::journal_chunk::JournalChunkPacket;
+use crate::journal::journal::Journal;
+
+// module
+use crate::protocol::downlink::DownlinkReceiver;
+use crate::protocol::udp;
+
+
+// how long a journal transfer may stay quiet before missing chunks are asked for again
+pub const RESEND_GAP : Duration = Duration::from_millis(200);
+
+// rows of stations that haven't answered for this long are dropped
+pub const STATION_TIMEOUT : Duration = Duration::from_secs(30);
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StationRow {
+    pub address: SocketAddr,
+    pub reply: PingReply,
+    pub latest_failure: Option<String>,
+    pub last_seen: Instant,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MissionUpdate {
+    Station(SocketAddr),
+    Failure(SocketAddr, String),
+    Diagnostic(SocketAddr, DiagnosticPacket),
+    Journal(SocketAddr, Journal),
+    Lost(SocketAddr),
+}
+
+// Mission control side: pings the targets and keeps one row per station that answered.
+pub struct MissionControl {
+    socket: UdpSocket,
+    targets: Vec<SocketAddr>,
+    ping: PingPacket,
+    stations: Vec<StationRow>,
+    station_timeout: Duration,
+    downlink: DownlinkReceiver,
+}
+
+impl MissionControl {
+    pub fn new(address: &str, targets: Vec<SocketAddr>) -> Result<Self, String> {
+        let socket = udp::bind(address)?;
+        socket.set_broadcast(true)
+            .map_err(|e| e.to_string())?;
+
+        return Ok(MissionControl {
+            socket,
+            targets,
+            ping: PingPacket::new(),
+            stations: vec![],
+            station_timeout: STATION_TIMEOUT,
+            downlink: DownlinkReceiver::new(),
+        });
+    }
+
+    pub fn local_addr(&self) -> Result<SocketAddr, String> {
+        self.socket.local_addr()
+            .map_err(|e| e.to_string())
+    }
+
+    pub fn set_ping(&mut self, ping: PingPacket) {
+        self.ping = ping;
+    }
+
+    pub fn set_station_timeout(&mut self, timeout: Duration) {
+        self.station_timeout = timeout;
+    }
+
+    pub fn stations(&self) -> &[StationRow] {
+        &self.stations
+    }
+
+    pub fn ping_all(&self) -> Result<(), String> {
+        for target in &self.targets {
+            udp::send_ping(&self.socket, &self.ping, *target)?;
+        }
+
+        return Ok(());
+    }
+
+    // Reads replies, failure pushes and journal chunks until the timeout runs out, then drops
+    // the rows of stations that went quiet.
+    pub fn poll(&mut self, timeout: Duration) -> Result<Vec<MissionUpdate>, String> {
+        let mut updates: Vec<MissionUpdate> = vec![];
+        let deadline = Instant::now() + timeout;
+
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                break;
+            }
+
+            let wait = match self.downlink.pending() {
+                true => remaining.min(RESEND_GAP),
+                false => remaining,
+            };
+            let received = udp::try_receive(&self.socket, wait)?;
+            self.downlink.request_resends(&self.socket, RESEND_GAP)?;
+
+            let (bytes, address) = match received {
+                Some(v) => v,
+                None => { continue; }
+            };
+
+            if let Ok(reply) = PingReply::decode(&bytes) {
+                self.update_row(address, reply, None);
+                updates.push(MissionUpdate::Station(address));
+            }
+            else if let Ok(failure) = FailurePacket::decode(&bytes) {
+                self.update_row(address, failure.station, Some(failure.path.clone()));
+                updates.push(MissionUpdate::Failure(address, failure.path));
+            }
+            else if let Ok(diagnostic) = DiagnosticPacket::decode(&bytes) {
+                updates.push(MissionUpdate::Diagnostic(address, diagnostic));
+            }
+            else if let Ok(chunk) = JournalChunkPacket::decode(&bytes) {
+                // a broken transfer is left to the resend rounds
+                if let Ok(Some(journal)) = self.downlink.chunk(&self.socket, address, &chunk) {
+                    updates.push(MissionUpdate::Journal(address, journal));
+                }
+            }
+        }
+
+        let (stations, lost): (Vec<StationRow>, Vec<StationRow>) = self.stations
+            .drain(..)
+            .partition(|row| row.last_seen.elapsed() < self.station_timeout);
+        self.stations = stations;
+        updates.extend(lost.into_iter().map(|row| MissionUpdate::Lost(row.address)));
+
+        return Ok(updates);
+    }
+
+    fn update_row(&mut self, address: SocketAddr, reply: PingReply, failure: Option<String>) {
+        match self.stations.iter_mut().find(|row| row.address == address) {
+            Some(row) => {
+                row.reply = reply;
+                row.last_seen = Instant::now();
+                if failure.is_some() {
+                    row.latest_failure = failure;
+                }
+            },
+            None => {
+                self.stations.push(StationRow { address, reply, latest_failure: failure, last_seen: Instant::now() });
+            }
+        }
+    }
+}
@@ -0,0 +1,20 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+pub mod auth;
+pub mod beacon;
+pub mod capture;
+pub mod command;
+pub mod common;
+pub mod constants;
+pub mod diagnostics;
+pub mod discovery;
+pub mod downlink;
+pub mod dissect;
+pub mod error;
+pub mod mission;
+pub mod op_codes;
+pub mod port_address;
+pub mod telemetry;
+pub mod udp;
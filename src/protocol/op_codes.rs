@@ -0,0 +1,23 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+pub const OP_PING : u16 = 0x1000;
+pub const OP_PING_REPLY : u16 = 0x2100;
+pub const OP_FAILURE : u16 = 0x2200;
+pub const OP_DIAGNOSTIC : u16 = 0x2300;
+pub const OP_JOURNAL_CHUNK : u16 = 0x2400;
+pub const OP_JOURNAL_RESEND : u16 = 0x2401;
+
+pub const OP_COMMAND_REPAIR : u16 = 0x3000;
+pub const OP_COMMAND_POWER_DOWN : u16 = 0x3001;
+pub const OP_COMMAND_NEW_DAY : u16 = 0x3002;
+pub const OP_COMMAND_STATUS_REQUEST : u16 = 0x3003;
+pub const OP_COMMAND_ACK : u16 = 0x3100;
+
+pub fn is_known(op_code: u16) -> bool {
+    matches!(op_code, OP_PING | OP_PING_REPLY | OP_FAILURE | OP_DIAGNOSTIC
+                    | OP_JOURNAL_CHUNK | OP_JOURNAL_RESEND
+                    | OP_COMMAND_REPAIR | OP_COMMAND_POWER_DOWN | OP_COMMAND_NEW_DAY
+                    | OP_COMMAND_STATUS_REQUEST | OP_COMMAND_ACK)
+}
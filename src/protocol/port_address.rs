@@ -0,0 +1,79 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fmt;
+
+// module
+use crate::protocol::constants::{PORT_ADDRESS_MIN, PORT_ADDRESS_MAX};
+
+
+pub fn check_port_address(port_address: u16) -> Result<u16, String> {
+    if !(PORT_ADDRESS_MIN..=PORT_ADDRESS_MAX).contains(&port_address) {
+        return Err(format!("port address {} outside {}..={}",
+                           port_address, PORT_ADDRESS_MIN, PORT_ADDRESS_MAX));
+    }
+    return Ok(port_address);
+}
+
+// Inclusive range of port addresses a packet is meant for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PortAddressRange {
+    pub bottom: u16,
+    pub top: u16,
+}
+
+impl Default for PortAddressRange {
+    fn default() -> Self {
+        PortAddressRange::all()
+    }
+}
+
+impl PortAddressRange {
+    pub fn new(bottom: u16, top: u16) -> Result<Self, String> {
+        check_port_address(bottom)?;
+        check_port_address(top)?;
+        if bottom > top {
+            return Err(format!("port address range {bottom}-{top} is empty"));
+        }
+        return Ok(PortAddressRange { bottom, top });
+    }
+
+    pub fn all() -> Self {
+        PortAddressRange { bottom: PORT_ADDRESS_MIN, top: PORT_ADDRESS_MAX }
+    }
+
+    pub fn single(port_address: u16) -> Self {
+        PortAddressRange { bottom: port_address, top: port_address }
+    }
+
+    // "N" or "BOTTOM-TOP"
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let parse_one = |part: &str| part.trim()
+            .parse::<u16>()
+            .map_err(|_| format!("invalid port address: {part}"));
+
+        match value.split_once('-') {
+            Some((bottom, top)) => PortAddressRange::new(parse_one(bottom)?, parse_one(top)?),
+            None => {
+                let port_address = check_port_address(parse_one(value)?)?;
+                Ok(PortAddressRange::single(port_address))
+            }
+        }
+    }
+
+    pub fn contains(&self, port_address: u16) -> bool {
+        (self.bottom..=self.top).contains(&port_address)
+    }
+
+    pub fn contains_any(&self, port_addresses: &[u16]) -> bool {
+        port_addresses.iter().any(|port_address| self.contains(*port_address))
+    }
+}
+
+impl fmt::Display for PortAddressRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.bottom, self.top)
+    }
+}
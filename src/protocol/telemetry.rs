@@ -0,0 +1,18 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::station::Station;
+use crate::station::events::SystemEvent;
+
+
+// Installed on a Station to publish its state, called after every change to the module tree.
+pub trait Telemetry {
+    fn update(&mut self, station: &Station);
+
+    // `port_address` is the failed module's category's, falling back to the station's
+    fn module_failure(&mut self, station: &Station, path: &str, port_address: u16);
+
+    fn event(&mut self, station: &Station, event: &SystemEvent);
+}
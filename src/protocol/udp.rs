@@ -0,0 +1,118 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::io::{self, ErrorKind};
+use std::net::{IpAddr, SocketAddr, UdpSocket};
+use std::time::Duration;
+
+// project
+use crate::packets::ping::PingPacket;
+
+// module
+use crate::protocol::constants::{MAX_PACKET_SIZE, DEFAULT_IP_PORT,
+                                 DEFAULT_BROADCAST_IP_2, DEFAULT_BROADCAST_IP_10};
+
+
+// bytes and sender of one datagram
+pub type Datagram = (Vec<u8>, SocketAddr);
+
+pub fn bind(address: &str) -> Result<UdpSocket, String> {
+    UdpSocket::bind(address)
+        .map_err(|e| format!("{address}: {e}"))
+}
+
+pub fn send(socket: &UdpSocket, bytes: &[u8], target: SocketAddr) -> Result<(), String> {
+    match socket.send_to(bytes, target) {
+        Ok(n) if n == bytes.len() => Ok(()),
+        Ok(n) => Err(format!("{target}: sent {n} of {} bytes", bytes.len())),
+        Err(e) => Err(format!("{target}: {e}")),
+    }
+}
+
+pub fn receive(socket: &UdpSocket, timeout: Option<Duration>) -> Result<Datagram, String> {
+    receive_io(socket, timeout)
+        .map_err(|e| e.to_string())
+}
+
+// Like `receive`, but a timeout is not an error.
+pub fn try_receive(socket: &UdpSocket, timeout: Duration) -> Result<Option<Datagram>, String> {
+    match receive_io(socket, Some(timeout)) {
+        Ok(v) => Ok(Some(v)),
+        Err(e) if matches!(e.kind(), ErrorKind::WouldBlock | ErrorKind::TimedOut) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn receive_io(socket: &UdpSocket, timeout: Option<Duration>) -> io::Result<Datagram> {
+    socket.set_read_timeout(timeout)?;
+
+    let mut buf = [0u8; MAX_PACKET_SIZE];
+    let (n, source) = socket.recv_from(&mut buf)?;
+
+    return Ok((buf[..n].to_vec(), source));
+}
+
+pub fn send_ping(socket: &UdpSocket, ping: &PingPacket, target: SocketAddr) -> Result<(), String> {
+    send(socket, &ping.encode(), target)
+}
+
+// An address with or without its port, stations listen on the default port.
+pub fn parse_target(value: &str) -> Result<SocketAddr, String> {
+    if let Ok(v) = value.parse::<SocketAddr>() {
+        return Ok(v);
+    }
+    match value.parse::<IpAddr>() {
+        Ok(ip) => Ok(SocketAddr::new(ip, DEFAULT_IP_PORT)),
+        Err(_) => Err(format!("invalid target address: {value}")),
+    }
+}
+
+// The `--bind` and `--target` options of the tools that ping stations.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Endpoints {
+    pub bind: String,
+    pub targets: Vec<SocketAddr>,
+}
+
+impl Default for Endpoints {
+    fn default() -> Self {
+        Endpoints::new()
+    }
+}
+
+impl Endpoints {
+    pub fn new() -> Self {
+        Endpoints {
+            bind: String::from("0.0.0.0:0"),
+            targets: vec![],
+        }
+    }
+
+    // Takes `arg` and its value when it is one of ours, false leaves it to the caller.
+    pub fn parse_argument(&mut self, arg: &str, args: &mut dyn Iterator<Item = String>) -> Result<bool, String> {
+        match arg {
+            "--bind" => {
+                self.bind = args.next().ok_or("--bind needs an address")?;
+            },
+            "--target" => {
+                let value = args.next().ok_or("--target needs an address")?;
+                self.targets.push(parse_target(value.as_str())?);
+            },
+            _ => { return Ok(false); }
+        }
+
+        return Ok(true);
+    }
+
+    // Without a target both station broadcast networks are pinged.
+    pub fn default_targets(&mut self) -> Result<(), String> {
+        if self.targets.is_empty() {
+            self.targets.push(parse_target(DEFAULT_BROADCAST_IP_2)?);
+            self.targets.push(parse_target(DEFAULT_BROADCAST_IP_10)?);
+        }
+
+        return Ok(());
+    }
+}
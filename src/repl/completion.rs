@@ -0,0 +1,64 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// module
+use crate::repl::form::{FORM_NAMES, STATUS_KEYWORDS};
+
+
+// Completes form names after "(", station paths inside a string, category slugs after
+// ":category" and the status keywords. Returns where the replaced text starts and the
+// candidates.
+pub fn complete(line: &str, position: usize, paths: &[String]) -> (usize, Vec<String>) {
+    let before = &line[..position];
+
+    if let Some(start) = open_string(before) {
+        return (start, matching(paths.iter().map(|v| v.as_str()), &before[start..]));
+    }
+
+    let start = before
+        .rfind(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .map(|at| at + 1)
+        .unwrap_or(0);
+    let prefix = &before[start..];
+    let previous = before[..start].trim_end();
+
+    if before[..start].ends_with('(') {
+        return (start, matching(FORM_NAMES.into_iter(), prefix));
+    }
+    if previous.ends_with(":category") {
+        let categories = paths.iter().map(|v| v.as_str()).filter(|v| !v.contains('/'));
+        return (start, matching(categories, prefix));
+    }
+    if prefix.starts_with(':') {
+        return (start, matching(STATUS_KEYWORDS.into_iter(), prefix));
+    }
+
+    return (start, vec![]);
+}
+
+// the byte after the quote that opens an unterminated string
+fn open_string(text: &str) -> Option<usize> {
+    let mut start: Option<usize> = None;
+    let mut escaped = false;
+
+    for (at, c) in text.char_indices() {
+        match (start, c) {
+            (Some(_), _) if escaped => { escaped = false; },
+            (Some(_), '\\') => { escaped = true; },
+            (Some(_), '"') => { start = None; },
+            (None, '"') => { start = Some(at + 1); },
+            (None, ';') => { return None; },
+            _ => {},
+        }
+    }
+
+    return start;
+}
+
+fn matching<'a>(candidates: impl Iterator<Item = &'a str>, prefix: &str) -> Vec<String> {
+    candidates
+        .filter(|candidate| candidate.starts_with(prefix))
+        .map(|candidate| candidate.to_string())
+        .collect()
+}
@@ -0,0 +1,70 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use rustyline::completion::Completer;
+use rustyline::highlight::Highlighter;
+use rustyline::hint::Hinter;
+use rustyline::history::DefaultHistory;
+use rustyline::validate::{ValidationContext, ValidationResult, Validator};
+use rustyline::{Context, Editor, Helper};
+
+// project
+use crate::terminalisp::reader::{read_all, ReadError};
+
+// module
+use crate::repl::completion::complete;
+
+
+pub type ReplEditor = Editor<ReplHelper, DefaultHistory>;
+
+// The station paths change as sections get installed, so the caller refreshes them
+// before each line.
+#[derive(Default)]
+pub struct ReplHelper {
+    paths: Vec<String>,
+}
+
+impl ReplHelper {
+    pub fn new() -> Self {
+        ReplHelper::default()
+    }
+
+    pub fn set_paths(&mut self, paths: Vec<String>) {
+        self.paths = paths;
+    }
+}
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(&self, line: &str, pos: usize, _ctx: &Context<'_>) -> rustyline::Result<(usize, Vec<String>)> {
+        Ok(complete(line, pos, &self.paths))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+// An open paren or string continues on the next line; other read errors are reported
+// by the evaluator.
+impl Validator for ReplHelper {
+    fn validate(&self, ctx: &mut ValidationContext) -> rustyline::Result<ValidationResult> {
+        match read_all(ctx.input()) {
+            Err(ReadError::UnexpectedEnd) | Err(ReadError::UnterminatedString(_)) => Ok(ValidationResult::Incomplete),
+            _ => Ok(ValidationResult::Valid(None)),
+        }
+    }
+}
+
+impl Helper for ReplHelper {}
+
+pub fn editor() -> Result<ReplEditor, String> {
+    let mut editor = ReplEditor::new().map_err(|e| e.to_string())?;
+    editor.set_helper(Some(ReplHelper::new()));
+    return Ok(editor);
+}
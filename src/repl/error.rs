@@ -0,0 +1,66 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::error::Error;
+use std::fmt;
+
+// project
+use crate::station::path::PathError;
+use crate::terminalisp::reader::ReadError;
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReplError {
+    Read(ReadError),
+    NotAForm(String),
+    UnknownForm(String),
+    BadArguments { form: &'static str, usage: &'static str },
+    Path(PathError),
+    ShutDown,
+    Editor(String),
+}
+
+impl ReplError {
+    // the quoted symbol in `(error :kind ...)`
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ReplError::Read(_) => "read",
+            ReplError::NotAForm(_) => "not-a-form",
+            ReplError::UnknownForm(_) => "unknown-form",
+            ReplError::BadArguments { .. } => "bad-arguments",
+            ReplError::Path(_) => "path",
+            ReplError::ShutDown => "shut-down",
+            ReplError::Editor(_) => "editor",
+        }
+    }
+}
+
+impl fmt::Display for ReplError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplError::Read(e) => write!(f, "{e}"),
+            ReplError::NotAForm(value) => write!(f, "not a form: {value}"),
+            ReplError::UnknownForm(name) => write!(f, "unknown form \"{name}\""),
+            ReplError::BadArguments { form, usage } => write!(f, "bad arguments to {form}, expected {usage}"),
+            ReplError::Path(e) => write!(f, "{e}"),
+            ReplError::ShutDown => write!(f, "the station has shut down"),
+            ReplError::Editor(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl Error for ReplError {}
+
+impl From<ReadError> for ReplError {
+    fn from(e: ReadError) -> Self {
+        ReplError::Read(e)
+    }
+}
+
+impl From<PathError> for ReplError {
+    fn from(e: PathError) -> Self {
+        ReplError::Path(e)
+    }
+}
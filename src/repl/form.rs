@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::query::StatusQuery;
+use crate::terminalisp::value::Value;
+
+// module
+use crate::repl::error::ReplError;
+
+
+pub const FORM_NAMES: [&str; 6] = ["status", "repair", "science", "new-day", "journal", "power-down"];
+pub const STATUS_KEYWORDS: [&str; 5] = [":category", ":section", ":inactive", ":installed", ":summary"];
+
+const STATUS_USAGE: &str = "(status [\"PATH\" | :category SLUG | :section \"PATH\"] [:inactive] [:installed] [:summary])";
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Form {
+    Status(StatusQuery),
+    Repair(String),
+    Science,
+    NewDay,
+    Journal(String),
+    PowerDown,
+}
+
+impl Form {
+    pub fn from_value(value: &Value) -> Result<Form, ReplError> {
+        let name = match value.head() {
+            Some(v) => v,
+            None => { return Err(ReplError::NotAForm(value.to_string())); }
+        };
+        let arguments = &value.as_list().unwrap_or_default()[1..];
+
+        let form = match name {
+            "status" => Form::Status(status_query(arguments)?),
+            "repair" => {
+                let path = match arguments {
+                    [path] => text(path),
+                    _ => None,
+                };
+                Form::Repair(path.ok_or(ReplError::BadArguments { form: "repair", usage: "(repair \"PATH\")" })?)
+            },
+            "journal" => {
+                match arguments {
+                    [Value::Str(entry)] => Form::Journal(entry.clone()),
+                    _ => { return Err(ReplError::BadArguments { form: "journal", usage: "(journal \"TEXT\")" }); }
+                }
+            },
+            "science" => no_arguments(arguments, "science", "(science)", Form::Science)?,
+            "new-day" => no_arguments(arguments, "new-day", "(new-day)", Form::NewDay)?,
+            "power-down" => no_arguments(arguments, "power-down", "(power-down)", Form::PowerDown)?,
+            _ => { return Err(ReplError::UnknownForm(name.to_string())); }
+        };
+
+        return Ok(form);
+    }
+
+    pub fn ends_day(&self) -> bool {
+        !matches!(self, Form::Journal(_) | Form::Status(_))
+    }
+}
+
+// paths are accepted both as strings and as bare symbols
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::Str(v) | Value::Symbol(v) => Some(v.clone()),
+        _ => None,
+    }
+}
+
+// A path or `:category`/`:section` picks the scope, the bare keywords are flags.
+fn status_query(arguments: &[Value]) -> Result<StatusQuery, ReplError> {
+    let bad = ReplError::BadArguments { form: "status", usage: STATUS_USAGE };
+    let mut query = StatusQuery::new();
+    let mut scope: Option<String> = None;
+
+    let mut index = 0;
+    while index < arguments.len() {
+        let path = match &arguments[index] {
+            Value::Str(path) => Some(path.clone()),
+            Value::Keyword(key) if key == "category" || key == "section" => {
+                index += 1;
+                let depth = if key == "category" { 0 } else { 1 };
+                let path = arguments.get(index).and_then(text).ok_or(bad.clone())?;
+                if path.trim_matches('/').matches('/').count() != depth {
+                    return Err(bad);
+                }
+                Some(path)
+            },
+            Value::Keyword(key) if key == "inactive" => { query.inactive_only = true; None },
+            Value::Keyword(key) if key == "installed" => { query.hide_uninstalled = true; None },
+            Value::Keyword(key) if key == "summary" => { query.summary = true; None },
+            _ => { return Err(bad); }
+        };
+
+        if path.is_some() {
+            if scope.is_some() {
+                return Err(bad);
+            }
+            scope = path;
+        }
+        index += 1;
+    }
+
+    query.scope = scope;
+    return Ok(query);
+}
+
+fn no_arguments(arguments: &[Value], form: &'static str, usage: &'static str, result: Form) -> Result<Form, ReplError> {
+    if !arguments.is_empty() {
+        return Err(ReplError::BadArguments { form, usage });
+    }
+    return Ok(result);
+}
@@ -2,4 +2,8 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
-pub const OP_PING : u16 = 0x1000;
+pub mod error;
+pub mod form;
+pub mod session;
+pub mod completion;
+pub mod editor;
@@ -0,0 +1,82 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::station::Station;
+use crate::station::components::PowerDown;
+use crate::journal::journal::Journal;
+use crate::script::day::{DayRhythm, DayStart, journal_entry};
+use crate::terminalisp::station as tl_station;
+use crate::terminalisp::value::Value;
+
+// module
+use crate::repl::error::ReplError;
+use crate::repl::form::Form;
+
+
+// A REPL day follows the same rhythm as a script day, see `DayRhythm`.
+pub struct Session {
+    day: DayRhythm,
+}
+
+impl Session {
+    pub fn start(station: &Station, journal: &mut Journal) -> Self {
+        return Session { day: DayRhythm::start(station, journal) };
+    }
+
+    pub fn awaiting_log(&self) -> bool {
+        self.day.awaiting_log()
+    }
+
+    pub fn prompt(&self, station: &Station) -> String {
+        match self.day.awaiting_log() {
+            true => format!("log {}> ", station.mission_day),
+            false => format!("day {}> ", station.mission_day),
+        }
+    }
+
+    // Returns whether the form ended the day, so the caller can save and downlink.
+    pub fn eval(&mut self, value: &Value, station: &mut Station, journal: &mut Journal) -> Result<bool, ReplError> {
+        if station.is_shut_down() {
+            return Err(ReplError::ShutDown);
+        }
+        let form = Form::from_value(value)?;
+
+        let log = match &form {
+            Form::Journal(text) => Some(text.as_str()),
+            _ => None,
+        };
+        match self.day.open(log, station, journal) {
+            DayStart::ShutDown => { return Ok(true); },
+            DayStart::Logged => { return Ok(false); },
+            DayStart::Open | DayStart::Unlogged => {},
+        }
+
+        let ends_day = form.ends_day();
+        match form {
+            Form::Journal(text) => {
+                journal_entry(journal, text);
+            },
+            Form::Status(query) => {
+                tl_station::status(&station.status_query(&query)?);
+            },
+            Form::Repair(path) => {
+                tl_station::module_repaired(station.repair_path(path.as_str())?);
+            },
+            Form::Science => {
+                station.science();
+            },
+            Form::NewDay => {},
+            Form::PowerDown => {
+                station.power_down();
+            },
+        }
+
+        if ends_day {
+            self.day.end(station, journal);
+        }
+
+        return Ok(ends_day);
+    }
+}
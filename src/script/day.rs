@@ -0,0 +1,76 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::station::station::Station;
+use crate::journal::journal::Journal;
+use crate::terminalisp::journal as tl_journal;
+
+
+// How the first command of a day opened it, or that the day was already open.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DayStart {
+    Open,
+    Logged,
+    Unlogged,
+    ShutDown,
+}
+
+// The day rhythm shared by scripts and the REPL. A day opens with its first command:
+// a journal line there is the day's log entry, any other command opens the day without
+// one and then runs. Commands that end the day close it, and the next one opens a new day.
+pub struct DayRhythm {
+    awaiting_log: bool,
+}
+
+impl DayRhythm {
+    pub fn start(station: &Station, journal: &mut Journal) -> Self {
+        log_result(journal.day(station.mission_day));
+        return DayRhythm { awaiting_log: true };
+    }
+
+    pub fn awaiting_log(&self) -> bool {
+        self.awaiting_log
+    }
+
+    // `log` is the command's text when it is a journal line.
+    pub fn open(&mut self, log: Option<&str>, station: &mut Station, journal: &mut Journal) -> DayStart {
+        if !self.awaiting_log {
+            return DayStart::Open;
+        }
+
+        if let Some(text) = log {
+            log_result(journal.add_entry(text.to_string()));
+        }
+
+        station.new_day();
+        log_result(journal.record_station(station));
+        self.awaiting_log = false;
+
+        if station.is_shut_down() {
+            return DayStart::ShutDown;
+        }
+        return match log {
+            Some(_) => DayStart::Logged,
+            None => DayStart::Unlogged,
+        };
+    }
+
+    pub fn end(&mut self, station: &mut Station, journal: &mut Journal) {
+        log_result(journal.record_station(station));
+        log_result(journal.day(station.mission_day));
+        self.awaiting_log = true;
+    }
+}
+
+// journal lines after the day's log entry
+pub fn journal_entry(journal: &mut Journal, text: String) {
+    log_result(journal.add_entry(text));
+}
+
+fn log_result(result: Result<(), String>) {
+    if let Err(e) = result {
+        tl_journal::journal_entry_status_error(e);
+    }
+}
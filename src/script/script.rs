@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::io::BufRead;
+
+// project
+use crate::station::station::Station;
+use crate::station::components::PowerDown;
+use crate::journal::journal::Journal;
+use crate::terminalisp::script as tl_script;
+use crate::terminalisp::station as tl_station;
+
+// module
+use crate::script::day::{DayRhythm, DayStart, journal_entry};
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Command {
+    Journal(String),
+    Status,
+    Repair(String),
+    Science,
+    NewDay,
+    PowerDown,
+}
+
+impl Command {
+    // `;` starts a comment, like in terminalisp; anything else the crew writes is journal text.
+    pub fn parse(line: &str) -> Option<Command> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with(';') {
+            return None;
+        }
+
+        match line {
+            "STATUS" => Some(Command::Status),
+            "SCIENCE" => Some(Command::Science),
+            "NEW DAY" => Some(Command::NewDay),
+            "POWER DOWN" => Some(Command::PowerDown),
+            _ => {
+                match line.strip_prefix("REPAIR ") {
+                    Some(path) => Some(Command::Repair(path.trim().to_string())),
+                    None => Some(Command::Journal(line.to_string())),
+                }
+            }
+        }
+    }
+
+    fn journal_text(&self) -> Option<&str> {
+        match self {
+            Command::Journal(text) => Some(text.as_str()),
+            _ => None,
+        }
+    }
+
+    fn ends_day(&self) -> bool {
+        !matches!(self, Command::Journal(_) | Command::Status)
+    }
+}
+
+pub fn run_script<R: BufRead>(input: R, station: &mut Station, journal: &mut Journal) -> Result<(), String> {
+    let mut day = DayRhythm::start(station, journal);
+    let mut line_number: usize = 0;
+
+    for line in input.lines() {
+        let line = line.map_err(|e| e.to_string())?;
+        line_number += 1;
+
+        let command = match Command::parse(line.as_str()) {
+            Some(v) => v,
+            None => { continue; }
+        };
+        tl_script::script_command(line_number, line.trim());
+
+        match day.open(command.journal_text(), station, journal) {
+            DayStart::ShutDown => { break; },
+            DayStart::Logged => { continue; },
+            DayStart::Open | DayStart::Unlogged => {},
+        }
+
+        let ends_day = command.ends_day();
+        match command {
+            Command::Journal(text) => {
+                journal_entry(journal, text);
+            },
+            Command::Status => {
+                tl_station::status(&station.status());
+            },
+            Command::Repair(path) => {
+                match station.repair_path(path.as_str()) {
+                    Ok(v) => { tl_station::module_repaired(v); },
+                    Err(e) => {
+                        tl_script::script_error(line_number, e.to_string());
+                        continue;
+                    }
+                }
+            },
+            Command::Science => {
+                station.science();
+            },
+            Command::NewDay => {},
+            Command::PowerDown => {
+                station.power_down();
+            },
+        }
+
+        if ends_day {
+            day.end(station, journal);
+        }
+    }
+
+    tl_script::script_end(line_number);
+
+    return Ok(());
+}
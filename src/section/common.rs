@@ -2,6 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// project
+use crate::station::components::{Name, ModuleCounts,
+                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+use crate::module::common::Module;
+use crate::terminalisp::value::Value;
+
+
 pub trait ModulesContained {
     const MODULES_CONTAINED: u16;
 }
@@ -9,3 +16,31 @@ pub trait ModulesContained {
 pub trait Installed {
     fn installed(&self) -> bool;
 }
+
+pub trait Modules {
+    fn modules(&self) -> Vec<&dyn Module>;
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module>;
+}
+
+pub trait Section: Name + Installed + ModuleCounts + UpdateModules + Modules
+                   + Status + BreakSomething + Repair + PowerDown {}
+
+impl<T> Section for T
+    where T: Name + Installed + ModuleCounts + UpdateModules + Modules
+             + Status + BreakSomething + Repair + PowerDown {}
+
+pub fn section_status<T: Name + Modules + ?Sized>(section: &T) -> Value {
+    let modules = section.modules()
+        .into_iter()
+        .map(|module| module.status())
+        .collect();
+
+    return section_form(section, modules);
+}
+
+pub fn section_form<T: Name + ?Sized>(section: &T, modules: Vec<Value>) -> Value {
+    return Value::form("section", vec![
+        ("name", Value::string(section.name().as_str())),
+        ("modules", Value::List(modules)),
+    ]);
+}
@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::comms;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Antenna Section ============================================================================== */
 
 pub struct AntennaSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_antenna: comms::Antenna,
@@ -38,6 +39,7 @@ impl AntennaSection {
     pub fn new(installed: bool) -> Self {
         let mut section = AntennaSection {
             _name: "Antenna Section",
+            _slug: "antenna",
             _installed: installed,
 
             module_antenna: comms::Antenna::new(installed),
@@ -52,7 +54,10 @@ impl AntennaSection {
     }
 }
 
-impl Name for AntennaSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for AntennaSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for AntennaSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for AntennaSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -71,41 +76,40 @@ impl UpdateModules for AntennaSection {
     }
 }
 
+impl Modules for AntennaSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_antenna
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_antenna
+        ]
+    }
+}
+
 impl Status for AntennaSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_antenna.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for AntennaSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=AntennaSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=AntennaSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_antenna.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -114,7 +118,7 @@ impl Repair for AntennaSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_antenna.repair_display(),
         ];
@@ -123,16 +127,19 @@ impl Repair for AntennaSection {
         if self.module_antenna.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_antenna.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_antenna.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -148,6 +155,7 @@ impl PowerDown for AntennaSection {
 
 pub struct TrackingSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_tracking: comms::Tracking,
@@ -164,6 +172,7 @@ impl TrackingSection {
     pub fn new(installed: bool) -> Self {
         let mut section = TrackingSection {
             _name: "Tracking Section",
+            _slug: "tracking",
             _installed: installed,
 
             module_tracking: comms::Tracking::new(installed),
@@ -178,7 +187,10 @@ impl TrackingSection {
     }
 }
 
-impl Name for TrackingSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for TrackingSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for TrackingSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for TrackingSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -197,41 +209,40 @@ impl UpdateModules for TrackingSection {
     }
 }
 
+impl Modules for TrackingSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_tracking
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_tracking
+        ]
+    }
+}
+
 impl Status for TrackingSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_tracking.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for TrackingSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=TrackingSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=TrackingSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_tracking.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -240,7 +251,7 @@ impl Repair for TrackingSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_tracking.repair_display(),
         ];
@@ -249,16 +260,19 @@ impl Repair for TrackingSection {
         if self.module_tracking.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_tracking.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_tracking.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -274,6 +288,7 @@ impl PowerDown for TrackingSection {
 
 pub struct TransponderSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_transponder: comms::Transponder,
@@ -290,6 +305,7 @@ impl TransponderSection {
     pub fn new(installed: bool) -> Self {
         let mut section = TransponderSection {
             _name: "Transponder Section",
+            _slug: "transponder",
             _installed: installed,
 
             module_transponder: comms::Transponder::new(installed),
@@ -304,7 +320,10 @@ impl TransponderSection {
     }
 }
 
-impl Name for TransponderSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for TransponderSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for TransponderSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for TransponderSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -323,41 +342,40 @@ impl UpdateModules for TransponderSection {
     }
 }
 
+impl Modules for TransponderSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_transponder
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_transponder
+        ]
+    }
+}
+
 impl Status for TransponderSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_transponder.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for TransponderSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=TransponderSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=TransponderSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_transponder.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -366,7 +384,7 @@ impl Repair for TransponderSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_transponder.repair_display(),
         ];
@@ -375,16 +393,19 @@ impl Repair for TransponderSection {
         if self.module_transponder.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_transponder.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_transponder.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
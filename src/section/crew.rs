@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::{crew, misc};
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Crew Module Section ========================================================================== */
 
 pub struct CrewModuleSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_airlock: misc::Airlock,
@@ -45,6 +46,7 @@ impl CrewModuleSection {
     pub fn new(installed: bool) -> Self {
         let mut section = CrewModuleSection {
             _name: "Crew Module Section",
+            _slug: "crew-module",
             _installed: installed,
 
             module_airlock: misc::Airlock::new(installed),
@@ -66,7 +68,10 @@ impl CrewModuleSection {
     }
 }
 
-impl Name for CrewModuleSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for CrewModuleSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for CrewModuleSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for CrewModuleSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -92,41 +97,47 @@ impl UpdateModules for CrewModuleSection {
     }
 }
 
+impl Modules for CrewModuleSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_airlock,
+            &self.module_command_module,
+            &self.module_galley,
+            &self.module_life_support,
+            &self.module_sleeping_pods,
+            &self.module_space_suits,
+            &self.module_temperature_control,
+            &self.module_water_reclamation
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_airlock,
+            &mut self.module_command_module,
+            &mut self.module_galley,
+            &mut self.module_life_support,
+            &mut self.module_sleeping_pods,
+            &mut self.module_space_suits,
+            &mut self.module_temperature_control,
+            &mut self.module_water_reclamation
+        ]
+    }
+}
+
 impl Status for CrewModuleSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_airlock.status(indent + 2),
-                self.module_command_module.status(indent + 2),
-                self.module_galley.status(indent + 2),
-                self.module_life_support.status(indent + 2),
-                self.module_sleeping_pods.status(indent + 2),
-                self.module_space_suits.status(indent + 2),
-                self.module_temperature_control.status(indent + 2),
-                self.module_water_reclamation.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for CrewModuleSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=CrewModuleSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=CrewModuleSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_airlock.break_something(); },
             2 => { broken_module = self.module_command_module.break_something(); },
             3 => { broken_module = self.module_galley.break_something(); },
@@ -140,7 +151,7 @@ impl BreakSomething for CrewModuleSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -149,7 +160,7 @@ impl Repair for CrewModuleSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_airlock.repair_display(),
             self.module_command_module.repair_display(),
@@ -172,23 +183,26 @@ impl Repair for CrewModuleSection {
         if self.module_water_reclamation.repairable()   { options.push(prompts[7].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_airlock.repair(); },
-            _ if chosen == prompts[1] => { self.module_command_module.repair(); },
-            _ if chosen == prompts[2] => { self.module_galley.repair(); },
-            _ if chosen == prompts[3] => { self.module_life_support.repair(); },
-            _ if chosen == prompts[4] => { self.module_sleeping_pods.repair(); },
-            _ if chosen == prompts[5] => { self.module_space_suits.repair(); },
-            _ if chosen == prompts[6] => { self.module_temperature_control.repair(); },
-            _ if chosen == prompts[7] => { self.module_water_reclamation.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_airlock.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_command_module.repair(); },
+            _ if chosen == prompts[2] => { repaired = self.module_galley.repair(); },
+            _ if chosen == prompts[3] => { repaired = self.module_life_support.repair(); },
+            _ if chosen == prompts[4] => { repaired = self.module_sleeping_pods.repair(); },
+            _ if chosen == prompts[5] => { repaired = self.module_space_suits.repair(); },
+            _ if chosen == prompts[6] => { repaired = self.module_temperature_control.repair(); },
+            _ if chosen == prompts[7] => { repaired = self.module_water_reclamation.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::maneuver;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Basic Maneuver Section ======================================================================= */
 
 pub struct BasicManeuverSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_reaction_control_system: maneuver::ReactionControlSystem,
@@ -38,6 +39,7 @@ impl BasicManeuverSection {
     pub fn new(installed: bool) -> Self {
         let mut section = BasicManeuverSection {
             _name: "Basic Maneuver Section",
+            _slug: "basic",
             _installed: installed,
 
             module_reaction_control_system: maneuver::ReactionControlSystem::new(installed),
@@ -52,7 +54,10 @@ impl BasicManeuverSection {
     }
 }
 
-impl Name for BasicManeuverSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for BasicManeuverSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for BasicManeuverSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for BasicManeuverSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -71,41 +76,40 @@ impl UpdateModules for BasicManeuverSection {
     }
 }
 
+impl Modules for BasicManeuverSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_reaction_control_system
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_reaction_control_system
+        ]
+    }
+}
+
 impl Status for BasicManeuverSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_reaction_control_system.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for BasicManeuverSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=BasicManeuverSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=BasicManeuverSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_reaction_control_system.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -114,7 +118,7 @@ impl Repair for BasicManeuverSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_reaction_control_system.repair_display(),
         ];
@@ -123,16 +127,19 @@ impl Repair for BasicManeuverSection {
         if self.module_reaction_control_system.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_reaction_control_system.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_reaction_control_system.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -148,6 +155,7 @@ impl PowerDown for BasicManeuverSection {
 
 pub struct ManeuverWithDockingSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_reaction_control_system: maneuver::ReactionControlSystem,
@@ -165,6 +173,7 @@ impl ManeuverWithDockingSection {
     pub fn new(installed: bool) -> Self {
         let mut section = ManeuverWithDockingSection {
             _name: "Maneuver With Docking Section",
+            _slug: "docking",
             _installed: installed,
 
             module_reaction_control_system: maneuver::ReactionControlSystem::new(installed),
@@ -180,7 +189,10 @@ impl ManeuverWithDockingSection {
     }
 }
 
-impl Name for ManeuverWithDockingSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for ManeuverWithDockingSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for ManeuverWithDockingSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for ManeuverWithDockingSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -200,35 +212,35 @@ impl UpdateModules for ManeuverWithDockingSection {
     }
 }
 
+impl Modules for ManeuverWithDockingSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_reaction_control_system,
+            &self.module_docking_system
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_reaction_control_system,
+            &mut self.module_docking_system
+        ]
+    }
+}
+
 impl Status for ManeuverWithDockingSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_reaction_control_system.status(indent + 2),
-                self.module_docking_system.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for ManeuverWithDockingSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=ManeuverWithDockingSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=ManeuverWithDockingSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_reaction_control_system.break_something(); },
             2 => { broken_module = self.module_docking_system.break_something(); },
             _ => unreachable!(),
@@ -236,7 +248,7 @@ impl BreakSomething for ManeuverWithDockingSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -245,7 +257,7 @@ impl Repair for ManeuverWithDockingSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_reaction_control_system.repair_display(),
             self.module_docking_system.repair_display(),
@@ -256,17 +268,20 @@ impl Repair for ManeuverWithDockingSection {
         if self.module_docking_system.repairable()          { options.push(prompts[1].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_reaction_control_system.repair(); },
-            _ if chosen == prompts[1] => { self.module_docking_system.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_reaction_control_system.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_docking_system.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::{misc, maneuver};
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Cargo Bay Section ============================================================================ */
 
 pub struct CargoBaySection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_airlock: misc::Airlock,
@@ -40,6 +41,7 @@ impl CargoBaySection {
     pub fn new(installed: bool) -> Self {
         let mut section = CargoBaySection {
             _name: "Cargo Bay Section",
+            _slug: "cargo-bay",
             _installed: installed,
 
             module_airlock: misc::Airlock::new(installed),
@@ -56,7 +58,10 @@ impl CargoBaySection {
     }
 }
 
-impl Name for CargoBaySection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for CargoBaySection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for CargoBaySection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for CargoBaySection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -77,36 +82,37 @@ impl UpdateModules for CargoBaySection {
     }
 }
 
+impl Modules for CargoBaySection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_airlock,
+            &self.module_cargo_bay,
+            &self.module_docking_system
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_airlock,
+            &mut self.module_cargo_bay,
+            &mut self.module_docking_system
+        ]
+    }
+}
+
 impl Status for CargoBaySection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_airlock.status(indent + 2),
-                self.module_cargo_bay.status(indent + 2),
-                self.module_docking_system.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for CargoBaySection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=CargoBaySection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=CargoBaySection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_airlock.break_something(); },
             2 => { broken_module = self.module_cargo_bay.break_something(); },
             3 => { broken_module = self.module_docking_system.break_something(); },
@@ -115,7 +121,7 @@ impl BreakSomething for CargoBaySection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -124,7 +130,7 @@ impl Repair for CargoBaySection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_airlock.repair_display(),
             self.module_cargo_bay.repair_display(),
@@ -137,18 +143,21 @@ impl Repair for CargoBaySection {
         if self.module_docking_system.repairable() { options.push(prompts[2].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_airlock.repair(); },
-            _ if chosen == prompts[1] => { self.module_cargo_bay.repair(); },
-            _ if chosen == prompts[2] => { self.module_docking_system.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_airlock.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_cargo_bay.repair(); },
+            _ if chosen == prompts[2] => { repaired = self.module_docking_system.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
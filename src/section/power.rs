@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::power;
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Fossil Power Section ========================================================================= */
 
 pub struct FossilPowerSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_combustion_turbine_generator: power::CombustionTurbineGenerator,
@@ -39,6 +40,7 @@ impl FossilPowerSection {
     pub fn new(installed: bool) -> Self {
         let mut section = FossilPowerSection {
             _name: "Fossil Power Section",
+            _slug: "fossil",
             _installed: installed,
 
             module_combustion_turbine_generator: power::CombustionTurbineGenerator::new(installed),
@@ -54,7 +56,10 @@ impl FossilPowerSection {
     }
 }
 
-impl Name for FossilPowerSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for FossilPowerSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for FossilPowerSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for FossilPowerSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -74,35 +79,35 @@ impl UpdateModules for FossilPowerSection {
     }
 }
 
+impl Modules for FossilPowerSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_combustion_turbine_generator,
+            &self.module_fossil_fuel_storage
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_combustion_turbine_generator,
+            &mut self.module_fossil_fuel_storage
+        ]
+    }
+}
+
 impl Status for FossilPowerSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_combustion_turbine_generator.status(indent + 2),
-                self.module_fossil_fuel_storage.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for FossilPowerSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=FossilPowerSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=FossilPowerSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_combustion_turbine_generator.break_something(); },
             2 => { broken_module = self.module_fossil_fuel_storage.break_something(); },
             _ => unreachable!(),
@@ -110,7 +115,7 @@ impl BreakSomething for FossilPowerSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -119,7 +124,7 @@ impl Repair for FossilPowerSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_combustion_turbine_generator.repair_display(),
             self.module_fossil_fuel_storage.repair_display(),
@@ -130,17 +135,20 @@ impl Repair for FossilPowerSection {
         if self.module_fossil_fuel_storage.repairable()          { options.push(prompts[1].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_combustion_turbine_generator.repair(); },
-            _ if chosen == prompts[1] => { self.module_fossil_fuel_storage.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_combustion_turbine_generator.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_fossil_fuel_storage.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -157,6 +165,7 @@ impl PowerDown for FossilPowerSection {
 
 pub struct FusionPowerSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_fusion_reactor: power::FusionReactor,
@@ -175,6 +184,7 @@ impl FusionPowerSection {
     pub fn new(installed: bool) -> Self {
         let mut section = FusionPowerSection {
             _name: "Fusion Power Section",
+            _slug: "fusion",
             _installed: installed,
 
             module_fusion_reactor: power::FusionReactor::new(installed),
@@ -191,7 +201,10 @@ impl FusionPowerSection {
     }
 }
 
-impl Name for FusionPowerSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for FusionPowerSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for FusionPowerSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for FusionPowerSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -212,36 +225,37 @@ impl UpdateModules for FusionPowerSection {
     }
 }
 
+impl Modules for FusionPowerSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_fusion_reactor,
+            &self.module_steam_turbine_generator,
+            &self.module_fusion_component_storage
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_fusion_reactor,
+            &mut self.module_steam_turbine_generator,
+            &mut self.module_fusion_component_storage
+        ]
+    }
+}
+
 impl Status for FusionPowerSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_fusion_reactor.status(indent + 2),
-                self.module_steam_turbine_generator.status(indent + 2),
-                self.module_fusion_component_storage.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for FusionPowerSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=FusionPowerSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=FusionPowerSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_fusion_reactor.break_something(); },
             2 => { broken_module = self.module_steam_turbine_generator.break_something(); },
             3 => { broken_module = self.module_fusion_component_storage.break_something(); },
@@ -250,7 +264,7 @@ impl BreakSomething for FusionPowerSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -259,7 +273,7 @@ impl Repair for FusionPowerSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_fusion_reactor.repair_display(),
             self.module_steam_turbine_generator.repair_display(),
@@ -272,18 +286,21 @@ impl Repair for FusionPowerSection {
         if self.module_fusion_component_storage.repairable() { options.push(prompts[2].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_fusion_reactor.repair(); },
-            _ if chosen == prompts[1] => { self.module_steam_turbine_generator.repair(); },
-            _ if chosen == prompts[2] => { self.module_fusion_component_storage.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_fusion_reactor.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_steam_turbine_generator.repair(); },
+            _ if chosen == prompts[2] => { repaired = self.module_fusion_component_storage.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -301,6 +318,7 @@ impl PowerDown for FusionPowerSection {
 
 pub struct NuclearPowerSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_nuclear_fuel_storage: power::NuclearFuelStorage,
@@ -320,6 +338,7 @@ impl NuclearPowerSection {
     pub fn new(installed: bool) -> Self {
         let mut section = NuclearPowerSection {
             _name: "Nuclear Power Section",
+            _slug: "nuclear",
             _installed: installed,
 
             module_nuclear_fuel_storage: power::NuclearFuelStorage::new(installed),
@@ -337,7 +356,10 @@ impl NuclearPowerSection {
     }
 }
 
-impl Name for NuclearPowerSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for NuclearPowerSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for NuclearPowerSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for NuclearPowerSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -359,37 +381,39 @@ impl UpdateModules for NuclearPowerSection {
     }
 }
 
+impl Modules for NuclearPowerSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_nuclear_fuel_storage,
+            &self.module_nuclear_reactor,
+            &self.module_steam_turbine_generator,
+            &self.module_nuclear_waste_storage
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_nuclear_fuel_storage,
+            &mut self.module_nuclear_reactor,
+            &mut self.module_steam_turbine_generator,
+            &mut self.module_nuclear_waste_storage
+        ]
+    }
+}
+
 impl Status for NuclearPowerSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_nuclear_fuel_storage.status(indent + 2),
-                self.module_nuclear_reactor.status(indent + 2),
-                self.module_steam_turbine_generator.status(indent + 2),
-                self.module_nuclear_waste_storage.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for NuclearPowerSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=NuclearPowerSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=NuclearPowerSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_nuclear_fuel_storage.break_something(); },
             2 => { broken_module = self.module_nuclear_reactor.break_something(); },
             3 => { broken_module = self.module_steam_turbine_generator.break_something(); },
@@ -399,7 +423,7 @@ impl BreakSomething for NuclearPowerSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -408,7 +432,7 @@ impl Repair for NuclearPowerSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_nuclear_fuel_storage.repair_display(),
             self.module_nuclear_reactor.repair_display(),
@@ -423,19 +447,22 @@ impl Repair for NuclearPowerSection {
         if self.module_nuclear_waste_storage.repairable()   { options.push(prompts[3].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_nuclear_fuel_storage.repair(); },
-            _ if chosen == prompts[1] => { self.module_nuclear_reactor.repair(); },
-            _ if chosen == prompts[2] => { self.module_steam_turbine_generator.repair(); },
-            _ if chosen == prompts[3] => { self.module_nuclear_waste_storage.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_nuclear_fuel_storage.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_nuclear_reactor.repair(); },
+            _ if chosen == prompts[2] => { repaired = self.module_steam_turbine_generator.repair(); },
+            _ if chosen == prompts[3] => { repaired = self.module_nuclear_waste_storage.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -454,6 +481,7 @@ impl PowerDown for NuclearPowerSection {
 
 pub struct RadiationPowerSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_radiation_mirrors: power::RadiationMirrors,
@@ -470,6 +498,7 @@ impl RadiationPowerSection {
     pub fn new(installed: bool) -> Self {
         let mut section = RadiationPowerSection {
             _name: "Radiation Power Section",
+            _slug: "radiation",
             _installed: installed,
 
             module_radiation_mirrors: power::RadiationMirrors::new(installed),
@@ -484,7 +513,10 @@ impl RadiationPowerSection {
     }
 }
 
-impl Name for RadiationPowerSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for RadiationPowerSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for RadiationPowerSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for RadiationPowerSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -503,41 +535,40 @@ impl UpdateModules for RadiationPowerSection {
     }
 }
 
+impl Modules for RadiationPowerSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_radiation_mirrors
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_radiation_mirrors
+        ]
+    }
+}
+
 impl Status for RadiationPowerSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_radiation_mirrors.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for RadiationPowerSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=RadiationPowerSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=RadiationPowerSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_radiation_mirrors.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -546,7 +577,7 @@ impl Repair for RadiationPowerSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_radiation_mirrors.repair_display(),
         ];
@@ -555,16 +586,19 @@ impl Repair for RadiationPowerSection {
         if self.module_radiation_mirrors.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_radiation_mirrors.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_radiation_mirrors.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -580,6 +614,7 @@ impl PowerDown for RadiationPowerSection {
 
 pub struct SolarPowerSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_solar_panel: power::SolarPanels,
@@ -596,6 +631,7 @@ impl SolarPowerSection {
     pub fn new(installed: bool) -> Self {
         let mut section = SolarPowerSection {
             _name: "Solar Power Section",
+            _slug: "solar",
             _installed: installed,
 
             module_solar_panel: power::SolarPanels::new(installed),
@@ -610,7 +646,10 @@ impl SolarPowerSection {
     }
 }
 
-impl Name for SolarPowerSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for SolarPowerSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for SolarPowerSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for SolarPowerSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -629,41 +668,40 @@ impl UpdateModules for SolarPowerSection {
     }
 }
 
+impl Modules for SolarPowerSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_solar_panel
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_solar_panel
+        ]
+    }
+}
+
 impl Status for SolarPowerSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_solar_panel.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for SolarPowerSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=SolarPowerSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=SolarPowerSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_solar_panel.break_something(); },
             _ => unreachable!(),
         }
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -672,7 +710,7 @@ impl Repair for SolarPowerSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_solar_panel.repair_display(),
         ];
@@ -681,16 +719,19 @@ impl Repair for SolarPowerSection {
         if self.module_solar_panel.repairable() { options.push(prompts[0].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_solar_panel.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_solar_panel.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -4,24 +4,25 @@
 
 // dependencies
 use rand::Rng;
-use rand::thread_rng;
 
 // project
+use crate::station::rng::StationRng;
 use crate::station::components::{Name, ModuleCounts,
                                  UpdateModules, Status, BreakSomething, Repair, PowerDown};
-use crate::module::common::{Active, StatusModule, BreakModule, RepairModule, PowerDownModule};
+use crate::module::common::{Active, Module, BreakModule, RepairModule, PowerDownModule};
 use crate::module::{research, misc};
-use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
 
 // module
-use crate::section::common::{ModulesContained, Installed};
+use crate::section::common::{ModulesContained, Installed, Modules, section_status};
 
 
 /* Astronomy Section ============================================================================ */
 
 pub struct AstronomySection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_astronomy_lab: research::AstronomyLab,
@@ -39,6 +40,7 @@ impl AstronomySection {
     pub fn new(installed: bool) -> Self {
         let mut section = AstronomySection {
             _name: "Astronomy Section",
+            _slug: "astronomy",
             _installed: installed,
 
             module_astronomy_lab: research::AstronomyLab::new(installed),
@@ -54,7 +56,10 @@ impl AstronomySection {
     }
 }
 
-impl Name for AstronomySection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for AstronomySection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for AstronomySection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for AstronomySection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -74,35 +79,35 @@ impl UpdateModules for AstronomySection {
     }
 }
 
+impl Modules for AstronomySection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_astronomy_lab,
+            &self.module_mainframe
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_astronomy_lab,
+            &mut self.module_mainframe
+        ]
+    }
+}
+
 impl Status for AstronomySection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_astronomy_lab.status(indent + 2),
-                self.module_mainframe.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for AstronomySection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=AstronomySection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=AstronomySection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_astronomy_lab.break_something(); },
             2 => { broken_module = self.module_mainframe.break_something(); },
             _ => unreachable!(),
@@ -110,7 +115,7 @@ impl BreakSomething for AstronomySection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -119,7 +124,7 @@ impl Repair for AstronomySection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_astronomy_lab.repair_display(),
             self.module_mainframe.repair_display(),
@@ -130,17 +135,20 @@ impl Repair for AstronomySection {
         if self.module_mainframe.repairable()     { options.push(prompts[1].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_astronomy_lab.repair(); },
-            _ if chosen == prompts[0] => { self.module_mainframe.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_astronomy_lab.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_mainframe.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -157,6 +165,7 @@ impl PowerDown for AstronomySection {
 
 pub struct GreenhouseSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_greenhouse: research::Greenhouse,
@@ -176,6 +185,7 @@ impl GreenhouseSection {
     pub fn new(installed: bool) -> Self {
         let mut section = GreenhouseSection {
             _name: "Greenhouse Section",
+            _slug: "greenhouse",
             _installed: installed,
 
             module_greenhouse: research::Greenhouse::new(installed),
@@ -193,7 +203,10 @@ impl GreenhouseSection {
     }
 }
 
-impl Name for GreenhouseSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for GreenhouseSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for GreenhouseSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for GreenhouseSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -215,37 +228,39 @@ impl UpdateModules for GreenhouseSection {
     }
 }
 
+impl Modules for GreenhouseSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_greenhouse,
+            &self.module_mainframe,
+            &self.module_airlock,
+            &self.module_temperature_control
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_greenhouse,
+            &mut self.module_mainframe,
+            &mut self.module_airlock,
+            &mut self.module_temperature_control
+        ]
+    }
+}
+
 impl Status for GreenhouseSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_greenhouse.status(indent + 2),
-                self.module_mainframe.status(indent + 2),
-                self.module_airlock.status(indent + 2),
-                self.module_temperature_control.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for GreenhouseSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=GreenhouseSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=GreenhouseSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_greenhouse.break_something(); },
             2 => { broken_module = self.module_mainframe.break_something(); },
             3 => { broken_module = self.module_airlock.break_something(); },
@@ -255,7 +270,7 @@ impl BreakSomething for GreenhouseSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -264,7 +279,7 @@ impl Repair for GreenhouseSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_greenhouse.repair_display(),
             self.module_mainframe.repair_display(),
@@ -279,19 +294,22 @@ impl Repair for GreenhouseSection {
         if self.module_temperature_control.repairable() { options.push(prompts[3].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_greenhouse.repair(); },
-            _ if chosen == prompts[1] => { self.module_mainframe.repair(); },
-            _ if chosen == prompts[2] => { self.module_airlock.repair(); },
-            _ if chosen == prompts[3] => { self.module_temperature_control.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_greenhouse.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_mainframe.repair(); },
+            _ if chosen == prompts[2] => { repaired = self.module_airlock.repair(); },
+            _ if chosen == prompts[3] => { repaired = self.module_temperature_control.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -310,6 +328,7 @@ impl PowerDown for GreenhouseSection {
 
 pub struct WeatherObservationSection {
     _name: &'static str,
+    _slug: &'static str,
     _installed: bool,
 
     pub module_weather_observation: research::WeatherObservation,
@@ -327,6 +346,7 @@ impl WeatherObservationSection {
     pub fn new(installed: bool) -> Self {
         let mut section = WeatherObservationSection {
             _name: "Weather Observation Section",
+            _slug: "weather-observation",
             _installed: installed,
 
             module_weather_observation: research::WeatherObservation::new(installed),
@@ -342,7 +362,10 @@ impl WeatherObservationSection {
     }
 }
 
-impl Name for WeatherObservationSection { fn name(&self) -> String { self._name.to_string() } }
+impl Name for WeatherObservationSection {
+    fn name(&self) -> String { self._name.to_string() }
+    fn slug(&self) -> String { self._slug.to_string() }
+}
 impl Installed for WeatherObservationSection { fn installed(&self) -> bool { self._installed } }
 impl ModuleCounts for WeatherObservationSection {
     fn total_modules(&self) -> u16 { self._total_modules }
@@ -362,35 +385,35 @@ impl UpdateModules for WeatherObservationSection {
     }
 }
 
+impl Modules for WeatherObservationSection {
+    fn modules(&self) -> Vec<&dyn Module> {
+        vec![
+            &self.module_weather_observation,
+            &self.module_mainframe
+        ]
+    }
+
+    fn modules_mut(&mut self) -> Vec<&mut dyn Module> {
+        vec![
+            &mut self.module_weather_observation,
+            &mut self.module_mainframe
+        ]
+    }
+}
+
 impl Status for WeatherObservationSection {
-    fn status(&self, indent: u8) -> String {
-        tl_station::status(
-            String::from("section"),
-            true,
-            vec![
-                String::from(":name")
-            ],
-            vec![
-                format!("\"{}\"", self.name())
-            ],
-            true,
-            String::from(":modules"),
-            vec![
-                self.module_weather_observation.status(indent + 2),
-                self.module_mainframe.status(indent + 2)
-            ],
-            indent
-        )
+    fn status(&self) -> Value {
+        section_status(self)
     }
 }
 
 impl BreakSomething for WeatherObservationSection {
-    fn break_something(&mut self) -> Result<String, String> {
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String> {
         if !self.installed() { return Err("not installed".to_string()); }
 
         let broken_module: String;
 
-        match thread_rng().gen_range(1..=WeatherObservationSection::MODULES_CONTAINED) {
+        match rng.gen_range(1..=WeatherObservationSection::MODULES_CONTAINED) {
             1 => { broken_module = self.module_weather_observation.break_something(); },
             2 => { broken_module = self.module_mainframe.break_something(); },
             _ => unreachable!(),
@@ -398,7 +421,7 @@ impl BreakSomething for WeatherObservationSection {
 
         self.update_active_modules();
 
-        return Ok(broken_module);
+        return Ok(format!("{} / {}", self.name(), broken_module));
     }
 }
 
@@ -407,7 +430,7 @@ impl Repair for WeatherObservationSection {
         self.installed() && self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String> {
         let prompts: Vec<String> = vec![
             self.module_weather_observation.repair_display(),
             self.module_mainframe.repair_display(),
@@ -418,17 +441,20 @@ impl Repair for WeatherObservationSection {
         if self.module_mainframe.repairable()           { options.push(prompts[1].clone()) }
 
         let chosen: String;
-        match tli_menu("Select module to repair:", options) {
+        match prompter.select("Select module to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: String;
         match chosen {
-            _ if chosen == prompts[0] => { self.module_weather_observation.repair(); },
-            _ if chosen == prompts[1] => { self.module_mainframe.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.module_weather_observation.repair(); },
+            _ if chosen == prompts[1] => { repaired = self.module_mainframe.repair(); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        return Some(format!("{} / {}", self.name(), repaired));
     }
 }
 
@@ -2,8 +2,27 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// project
+use crate::prompt::prompt::Prompter;
+use crate::terminalisp::value::Value;
+
+// module
+use crate::station::rng::StationRng;
+
+
 pub trait Name {
     fn name(&self) -> String;
+
+    fn slug(&self) -> String {
+        slugify(self.name().as_str())
+    }
+}
+
+pub fn slugify(name: &str) -> String {
+    name.split_whitespace()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>()
+        .join("-")
 }
 
 pub trait SectionCounts {
@@ -30,23 +49,29 @@ pub trait UpdateModules {
     fn active_module_sum(&self) -> u16 {
         self.active_module_counts()
             .iter()
-            .fold(0, |acc, e| acc + e)
+            .sum()
     }
 }
 
 pub trait Status {
-    fn status(&self, indent: u8) -> String;
+    fn status(&self) -> Value;
 }
 
 pub trait BreakSomething {
-    fn break_something(&mut self) -> Result<String, String>;
+    fn break_something(&mut self, rng: &mut StationRng) -> Result<String, String>;
 }
 
 pub trait Repair {
     fn repairable(&self) -> bool;
-    fn repair(&mut self);
+    fn repair(&mut self, prompter: &mut dyn Prompter) -> Option<String>;
 }
 
 pub trait PowerDown {
     fn power_down(&mut self);
 }
+
+// 15-bit address for routing packets, see protocol::port_address
+pub trait PortAddress {
+    fn port_address(&self) -> Option<u16>;
+    fn set_port_address(&mut self, port_address: Option<u16>);
+}
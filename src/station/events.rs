@@ -0,0 +1,57 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fmt;
+
+// project
+use crate::event::event::Event;
+
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SystemEvent {
+    ModuleFailure(String),
+    ModuleRepaired(String),
+    ModuleRestored(String),
+    StationEvent(Event),
+    IncomingMessage(String),
+    PowerDown,
+    Shutdown,
+    FinalTransmissionCountdown(u16),
+    FinalTransmission,
+}
+
+impl fmt::Display for SystemEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SystemEvent::ModuleFailure(path) => {
+                write!(f, "Module failure: {path}")
+            },
+            SystemEvent::ModuleRepaired(path) => {
+                write!(f, "Module repaired: {path}")
+            },
+            SystemEvent::ModuleRestored(path) => {
+                write!(f, "Module restored: {path}")
+            },
+            SystemEvent::StationEvent(event) => {
+                write!(f, "Event: {}", event.description())
+            },
+            SystemEvent::IncomingMessage(message) => {
+                write!(f, "Incoming message: {message}")
+            },
+            SystemEvent::PowerDown => {
+                write!(f, "All modules powered down")
+            },
+            SystemEvent::Shutdown => {
+                write!(f, "Station shut down, no active modules left")
+            },
+            SystemEvent::FinalTransmissionCountdown(count) => {
+                write!(f, "{count} days until final transmission")
+            },
+            SystemEvent::FinalTransmission => {
+                write!(f, "Final transmission sent")
+            },
+        }
+    }
+}
@@ -6,3 +6,9 @@ pub mod station;
 
 mod name;
 pub mod components;
+pub mod events;
+pub mod path;
+pub mod rng;
+pub mod save;
+pub mod snapshot;
+pub mod query;
@@ -4,10 +4,10 @@
 
 // dependencies
 use rand_derive2::RandGen;
-use strum_macros::Display;
+use strum_macros::{Display, EnumString};
 
 
-#[derive(RandGen, Display)]
+#[derive(RandGen, Display, EnumString)]
 pub enum StationName {
     // manga - cyberpunk
     Akira, Avalon,
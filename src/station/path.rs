@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::error::Error;
+use std::fmt;
+
+
+// "category", "category/section" or "category/section/module", all slugs
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StationPath {
+    pub category: String,
+    pub section: Option<String>,
+    pub module: Option<String>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PathError {
+    Malformed(String),
+    UnknownCategory(String),
+    UnknownSection(String),
+    UnknownModule(String),
+    NotInstalled(String),
+    NotAModule(String),
+    AlreadyActive(String),
+    AlreadyInactive(String),
+}
+
+impl StationPath {
+    pub fn parse(path: &str) -> Result<Self, PathError> {
+        let parts: Vec<&str> = path.trim().trim_matches('/').split('/').collect();
+
+        let valid = |part: &&str| {
+            !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+        };
+        if parts.len() > 3 || !parts.iter().all(valid) {
+            return Err(PathError::Malformed(path.to_string()));
+        }
+
+        Ok(StationPath {
+            category: parts[0].to_string(),
+            section: parts.get(1).map(|part| part.to_string()),
+            module: parts.get(2).map(|part| part.to_string()),
+        })
+    }
+
+    pub fn parse_module(path: &str) -> Result<Self, PathError> {
+        let parsed = StationPath::parse(path)?;
+        if parsed.module.is_none() {
+            return Err(PathError::NotAModule(path.to_string()));
+        }
+        return Ok(parsed);
+    }
+}
+
+impl fmt::Display for StationPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.category)?;
+        if let Some(section) = &self.section { write!(f, "/{section}")?; }
+        if let Some(module) = &self.module { write!(f, "/{module}")?; }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PathError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathError::Malformed(path) => write!(f, "malformed path \"{path}\""),
+            PathError::UnknownCategory(path) => write!(f, "unknown category \"{path}\""),
+            PathError::UnknownSection(path) => write!(f, "unknown section \"{path}\""),
+            PathError::UnknownModule(path) => write!(f, "unknown module \"{path}\""),
+            PathError::NotInstalled(path) => write!(f, "section not installed \"{path}\""),
+            PathError::NotAModule(path) => write!(f, "not a module path \"{path}\""),
+            PathError::AlreadyActive(path) => write!(f, "module already active \"{path}\""),
+            PathError::AlreadyInactive(path) => write!(f, "module already inactive \"{path}\""),
+        }
+    }
+}
+
+impl Error for PathError {}
@@ -0,0 +1,115 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::category::common::{Category, category_form};
+use crate::section::common::{Section, section_form};
+use crate::module::common::Module;
+use crate::prompt::prompt::Prompter;
+use crate::terminalisp::value::Value;
+
+
+const VIEWS: [&str; 4] = ["FULL", "INACTIVE MODULES", "INSTALLED SECTIONS", "SUMMARY"];
+const STATION_SCOPE: &str = "STATION";
+
+// A focused status view. The default lists everything, uninstalled sections included;
+// with `hide_uninstalled` alone it matches `Station::status`.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct StatusQuery {
+    // a category, section or module path, `None` for the whole station
+    pub scope: Option<String>,
+    pub inactive_only: bool,
+    pub hide_uninstalled: bool,
+    // one line per category, or for the scoped category or section
+    pub summary: bool,
+}
+
+impl StatusQuery {
+    pub fn new() -> Self {
+        StatusQuery::default()
+    }
+
+    // The menu's STATUS choice: a view, then the station or one of `scopes`.
+    pub fn prompt(prompter: &mut dyn Prompter, scopes: Vec<String>) -> Result<Self, String> {
+        let views: Vec<String> = VIEWS.iter().map(|view| view.to_string()).collect();
+        let view = prompter.select("Status view:", views)?;
+
+        let mut options = vec![STATION_SCOPE.to_string()];
+        options.extend(scopes);
+        let scope = prompter.select("Status of:", options)?;
+
+        return Ok(StatusQuery {
+            scope: if scope == STATION_SCOPE { None } else { Some(scope) },
+            inactive_only: view == VIEWS[1],
+            hide_uninstalled: view == VIEWS[2],
+            summary: view == VIEWS[3],
+        });
+    }
+
+    fn keep_category(&self, category: &dyn Category) -> bool {
+        !self.inactive_only || category.sections().into_iter().any(|section| self.keep_section(section))
+    }
+
+    fn keep_section(&self, section: &dyn Section) -> bool {
+        if !section.installed() {
+            return !self.hide_uninstalled && !self.inactive_only;
+        }
+        return !self.inactive_only || section.active_modules() < section.total_modules();
+    }
+
+    fn keep_module(&self, module: &dyn Module) -> bool {
+        !self.inactive_only || !module.active()
+    }
+}
+
+// The scoped node itself is always returned, only its children are filtered.
+pub fn query_category(category: &dyn Category, query: &StatusQuery) -> Value {
+    if query.summary {
+        return summary("category", category.name(), category.active_modules(), category.total_modules());
+    }
+
+    let sections = category.sections()
+        .into_iter()
+        .filter(|section| query.keep_section(*section))
+        .map(|section| query_section(section, query))
+        .collect();
+
+    return category_form(category, sections);
+}
+
+pub fn query_section(section: &dyn Section, query: &StatusQuery) -> Value {
+    if !section.installed() {
+        return Value::form("section", vec![
+            ("name", Value::string(section.name().as_str())),
+            ("status", Value::quoted("uninstalled")),
+        ]);
+    }
+    if query.summary {
+        return summary("section", section.name(), section.active_modules(), section.total_modules());
+    }
+
+    let modules = section.modules()
+        .into_iter()
+        .filter(|module| query.keep_module(*module))
+        .map(|module| module.status())
+        .collect();
+
+    return section_form(section, modules);
+}
+
+pub fn query_categories(categories: Vec<&dyn Category>, query: &StatusQuery) -> Vec<Value> {
+    categories
+        .into_iter()
+        .filter(|category| query.keep_category(*category))
+        .map(|category| query_category(category, query))
+        .collect()
+}
+
+fn summary(head: &str, name: String, active: u16, total: u16) -> Value {
+    Value::form(head, vec![
+        ("name", Value::string(name.as_str())),
+        ("active-modules", Value::integer(active)),
+        ("total-modules", Value::integer(total)),
+    ])
+}
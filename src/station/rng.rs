@@ -0,0 +1,20 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use rand::random;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+
+// ChaCha8 yields the same stream on every platform, so a seed reproduces a mission
+pub type StationRng = ChaCha8Rng;
+
+pub fn station_rng(seed: u64) -> StationRng {
+    StationRng::seed_from_u64(seed)
+}
+
+pub fn random_seed() -> u64 {
+    random()
+}
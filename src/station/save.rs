@@ -0,0 +1,204 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fs;
+use std::path::Path;
+
+// dependencies
+use serde::{Serialize, Deserialize};
+
+// project
+use crate::category::common::{Category, SectionsAvailable};
+use crate::section::common::Section;
+use crate::module::common::Module;
+use crate::protocol::port_address::check_port_address;
+
+
+#[derive(Serialize, Deserialize)]
+pub struct StationSave {
+    pub name: String,
+    pub version: u8,
+    pub mission_day: u16,
+    pub disabled: bool,
+    #[serde(default)]
+    pub port_address: u16,
+    #[serde(default)]
+    pub last_command_sequence: Option<u64>,
+
+    pub seed: u64,
+    pub rng_word_pos: u128,
+
+    pub total_sections: u16,
+    pub installed_sections: u16,
+    pub total_modules: u16,
+    pub active_modules: u16,
+
+    pub categories: Vec<CategorySave>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CategorySave {
+    pub name: String,
+    #[serde(default)]
+    pub port_address: Option<u16>,
+
+    pub total_sections: u16,
+    pub installed_sections: u16,
+    pub total_modules: u16,
+    pub active_modules: u16,
+
+    pub sections: Vec<SectionSave>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct SectionSave {
+    pub name: String,
+    pub installed: bool,
+
+    pub total_modules: u16,
+    pub active_modules: u16,
+
+    pub modules: Vec<ModuleSave>,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ModuleSave {
+    pub name: String,
+    pub active: bool,
+}
+
+impl StationSave {
+    pub fn write(&self, path: &Path) -> Result<(), String> {
+        let encoded = serde_json::to_string_pretty(self)
+            .map_err(|e| e.to_string())?;
+
+        fs::write(path, encoded)
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let encoded = fs::read_to_string(path)
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        serde_json::from_str(encoded.as_str())
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+pub fn save_category(category: &dyn Category) -> CategorySave {
+    CategorySave {
+        name: category.name(),
+        port_address: category.port_address(),
+
+        total_sections: category.total_sections(),
+        installed_sections: category.installed_sections(),
+        total_modules: category.total_modules(),
+        active_modules: category.active_modules(),
+
+        sections: category.sections()
+            .into_iter()
+            .map(save_section)
+            .collect(),
+    }
+}
+
+fn save_section(section: &dyn Section) -> SectionSave {
+    SectionSave {
+        name: section.name(),
+        installed: section.installed(),
+
+        total_modules: section.total_modules(),
+        active_modules: section.active_modules(),
+
+        modules: section.modules()
+            .into_iter()
+            .map(save_module)
+            .collect(),
+    }
+}
+
+fn save_module(module: &dyn Module) -> ModuleSave {
+    ModuleSave {
+        name: module.name(),
+        active: module.active(),
+    }
+}
+
+pub fn load_category<C: Category + SectionsAvailable>(save: &CategorySave,
+                                                      build: fn(Vec<bool>) -> C) -> Result<C, String> {
+
+    // `build` indexes the installation by section, so the count goes first
+    if C::SECTIONS_AVAILABLE as usize != save.sections.len() {
+        return Err(format!("{}: expected {} sections, found {}",
+                           save.name, C::SECTIONS_AVAILABLE, save.sections.len()));
+    }
+
+    let installation: Vec<bool> = save.sections
+        .iter()
+        .map(|section| section.installed)
+        .collect();
+
+    let mut category = build(installation);
+
+    check_name(category.name(), &save.name)?;
+
+    for (section, section_save) in category.sections_mut().into_iter().zip(&save.sections) {
+        load_section(section, section_save)?;
+    }
+
+    category.update_active_modules();
+    if let Some(v) = save.port_address {
+        category.set_port_address(Some(check_port_address(v)?));
+    }
+
+    check_count(&save.name, "installed-sections",
+                category.installed_sections(), save.installed_sections)?;
+    check_count(&save.name, "total-modules",
+                category.total_modules(), save.total_modules)?;
+    check_count(&save.name, "active-modules",
+                category.active_modules(), save.active_modules)?;
+
+    return Ok(category);
+}
+
+fn load_section(section: &mut dyn Section, save: &SectionSave) -> Result<(), String> {
+    check_name(section.name(), &save.name)?;
+    if section.total_modules() as usize != save.modules.len() {
+        return Err(format!("{}: expected {} modules, found {}",
+                           save.name, section.total_modules(), save.modules.len()));
+    }
+
+    for (module, module_save) in section.modules_mut().into_iter().zip(&save.modules) {
+        check_name(module.name(), &module_save.name)?;
+        if module_save.active && !save.installed {
+            return Err(format!("{}: module \"{}\" active in uninstalled section",
+                               save.name, module_save.name));
+        }
+        if module_save.active { module.activate(); } else { module.deactivate(); }
+    }
+
+    section.update_active_modules();
+
+    check_count(&save.name, "total-modules",
+                section.total_modules(), save.total_modules)?;
+    check_count(&save.name, "active-modules",
+                section.active_modules(), save.active_modules)?;
+
+    return Ok(());
+}
+
+fn check_name(expected: String, found: &String) -> Result<(), String> {
+    if &expected != found {
+        return Err(format!("expected \"{expected}\", found \"{found}\""));
+    }
+    return Ok(());
+}
+
+pub fn check_count(name: &String, counter: &str, counted: u16, saved: u16) -> Result<(), String> {
+    if counted != saved {
+        return Err(format!("{name}: {counter} is {saved}, modules give {counted}"));
+    }
+    return Ok(());
+}
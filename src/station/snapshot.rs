@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use serde::{Serialize, Deserialize};
+
+// project
+use crate::category::common::Category;
+use crate::section::common::Section;
+use crate::module::common::Module;
+
+
+// The status tree for dashboards: the same fields as `Station::status`, typed. Unlike
+// `StationSave` it only lists installed sections and carries no RNG state.
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StationSnapshot {
+    pub name: String,
+    pub version: u8,
+    pub mission_day: u16,
+    pub total_modules: u16,
+    pub active_modules: u16,
+
+    pub categories: Vec<CategorySnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CategorySnapshot {
+    pub name: String,
+    pub installed_sections: u16,
+    pub total_modules: u16,
+    pub active_modules: u16,
+
+    pub sections: Vec<SectionSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SectionSnapshot {
+    pub name: String,
+
+    pub modules: Vec<ModuleSnapshot>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ModuleSnapshot {
+    pub name: String,
+    pub status: ModuleStatus,
+}
+
+#[derive(Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModuleStatus {
+    Ok,
+    Inactive,
+}
+
+impl StationSnapshot {
+    // snapshots only hold strings and integers, serialization cannot fail
+    pub fn to_json(&self) -> String {
+        let encoded = serde_json::to_string_pretty(self)
+            .unwrap_or_else(|_| String::from("{}"));
+
+        return format!("{encoded}\n");
+    }
+
+    pub fn to_yaml(&self) -> String {
+        serde_yaml::to_string(self)
+            .unwrap_or_else(|_| String::from("{}\n"))
+    }
+}
+
+pub fn snapshot_category(category: &dyn Category) -> CategorySnapshot {
+    CategorySnapshot {
+        name: category.name(),
+        installed_sections: category.installed_sections(),
+        total_modules: category.total_modules(),
+        active_modules: category.active_modules(),
+
+        sections: category.sections()
+            .into_iter()
+            .filter(|section| section.installed())
+            .map(snapshot_section)
+            .collect(),
+    }
+}
+
+fn snapshot_section(section: &dyn Section) -> SectionSnapshot {
+    SectionSnapshot {
+        name: section.name(),
+
+        modules: section.modules()
+            .into_iter()
+            .map(snapshot_module)
+            .collect(),
+    }
+}
+
+fn snapshot_module(module: &dyn Module) -> ModuleSnapshot {
+    ModuleSnapshot {
+        name: module.name(),
+        status: if module.active() { ModuleStatus::Ok } else { ModuleStatus::Inactive },
+    }
+}
@@ -2,20 +2,39 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// library
+use std::path::Path;
+use std::str::FromStr;
+
 // dependencies
-use rand::random;
 use rand::Rng;
-use rand::thread_rng;
 
 // project
 use crate::category;
+use crate::category::common::Category;
+use crate::event::event::{Event, roll_events};
+use crate::event::effect::Effect;
+use crate::section::common::Section;
+use crate::module::common::{Module, Active};
 use crate::terminalisp::station as tl_station;
-use crate::terminalisp::menu::tli_menu;
+use crate::terminalisp::event as tl_event;
+use crate::terminalisp::value::Value;
+use crate::prompt::prompt::Prompter;
+use crate::prompt::interactive::InquirePrompter;
+use crate::protocol::telemetry::Telemetry;
+use crate::protocol::constants::PORT_ADDRESS_MIN;
+use crate::protocol::port_address::check_port_address;
 
 // module
 use crate::station::name::StationName;
+use crate::station::events::SystemEvent;
+use crate::station::path::{StationPath, PathError};
+use crate::station::rng::{StationRng, station_rng, random_seed};
+use crate::station::save::{StationSave, save_category, load_category, check_count};
+use crate::station::snapshot::{StationSnapshot, snapshot_category};
+use crate::station::query::{StatusQuery, query_categories, query_category, query_section};
 use crate::station::components::{Name, SectionCounts, ModuleCounts,
-                                 UpdateModules, Status, BreakSomething, Repair, PowerDown};
+                                 UpdateModules, BreakSomething, Repair, PowerDown};
 
 
 pub trait SectionGroups {
@@ -23,11 +42,17 @@ pub trait SectionGroups {
 }
 
 pub struct Station {
+    _seed: u64,
+    _rng: StationRng,
+
     _name: StationName,
     pub version: u8,
     pub mission_day: u16,
     pub disabled: bool,
 
+    _port_address: u16,
+    _last_command_sequence: Option<u64>,
+
     pub sections_comm: category::comms::CommsCategory,
     pub sections_crew: category::crew::CrewCategory,
     pub sections_maneuver: category::maneuver::ManeuverCategory,
@@ -39,68 +64,109 @@ pub struct Station {
     _installed_sections: u16,
     _total_modules: u16,
     _active_modules: u16,
+
+    _events: Vec<SystemEvent>,
+    _prompter: Box<dyn Prompter>,
+    _telemetry: Option<Box<dyn Telemetry>>,
 }
 
 impl SectionGroups for Station {
     const SECTION_GROUPS: u8 = 6;
 }
 
+impl Default for Station {
+    fn default() -> Self {
+        Station::new()
+    }
+}
+
 impl Station {
     pub fn new() -> Self {
+        Station::from_seed(random_seed())
+    }
+
+    pub fn from_seed(seed: u64) -> Self {
+        let mut rng = station_rng(seed);
+
         let mut station = Station {
-            _name: random(),
-            version: random(),
+            _name: rng.gen(),
+            version: rng.gen(),
             mission_day: 0,
             disabled: false,
 
-            sections_comm: category::comms::CommsCategory::new(1, 100),
-            sections_crew: category::crew::CrewCategory::new(0, 100),
-            sections_maneuver: category::maneuver::ManeuverCategory::new(1, 100),
-            sections_misc: category::misc::MiscCategory::new(1, 100),
-            sections_power: category::power::PowerCategory::new(1, 100),
-            sections_research: category::research::ResearchCategory::new(0, 100),
+            _port_address: PORT_ADDRESS_MIN,
+            _last_command_sequence: None,
+
+            sections_comm: category::comms::CommsCategory::new(1, 100, &mut rng),
+            sections_crew: category::crew::CrewCategory::new(0, 100, &mut rng),
+            sections_maneuver: category::maneuver::ManeuverCategory::new(1, 100, &mut rng),
+            sections_misc: category::misc::MiscCategory::new(1, 100, &mut rng),
+            sections_power: category::power::PowerCategory::new(1, 100, &mut rng),
+            sections_research: category::research::ResearchCategory::new(0, 100, &mut rng),
 
             _total_sections: 0,
             _installed_sections: 0,
             _total_modules: 0,
             _active_modules: 0,
+
+            _seed: seed,
+            _rng: rng,
+
+            _events: vec![],
+            _prompter: Box::new(InquirePrompter::new()),
+            _telemetry: None,
         };
 
-        station._total_sections =
-            station.sections_comm.total_sections() +
-            station.sections_crew.total_sections() +
-            station.sections_maneuver.total_sections() +
-            station.sections_misc.total_sections() +
-            station.sections_power.total_sections() +
-            station.sections_research.total_sections();
-
-        station._installed_sections =
-            station.sections_comm.installed_sections() +
-            station.sections_crew.installed_sections() +
-            station.sections_maneuver.installed_sections() +
-            station.sections_misc.installed_sections() +
-            station.sections_power.installed_sections() +
-            station.sections_research.installed_sections();
-
-        station._total_modules =
-            station.sections_comm.total_modules() +
-            station.sections_crew.total_modules() +
-            station.sections_maneuver.total_modules() +
-            station.sections_misc.total_modules() +
-            station.sections_power.total_modules() +
-            station.sections_research.total_modules();
-
-        station.update_active_modules();
-
-        station.status(0, true, false);
+        station.update_counts();
 
         return station;
     }
 
+    fn update_counts(&mut self) {
+        self._total_sections = self.categories()
+            .iter()
+            .map(|category| category.total_sections())
+            .sum();
+
+        self._installed_sections = self.categories()
+            .iter()
+            .map(|category| category.installed_sections())
+            .sum();
+
+        self._total_modules = self.categories()
+            .iter()
+            .map(|category| category.total_modules())
+            .sum();
+
+        self.update_active_modules();
+    }
+
+    pub fn categories(&self) -> Vec<&dyn Category> {
+        vec![
+            &self.sections_comm,
+            &self.sections_crew,
+            &self.sections_maneuver,
+            &self.sections_misc,
+            &self.sections_power,
+            &self.sections_research
+        ]
+    }
+
+    pub fn categories_mut(&mut self) -> Vec<&mut dyn Category> {
+        vec![
+            &mut self.sections_comm,
+            &mut self.sections_crew,
+            &mut self.sections_maneuver,
+            &mut self.sections_misc,
+            &mut self.sections_power,
+            &mut self.sections_research
+        ]
+    }
+
     fn days_left(&self) -> u16 { self.active_modules() }
 
     pub fn is_shut_down(&self) -> bool {
-        return self.days_left() < {1 as u16};
+        return self.days_left() < 1;
     }
 
     pub fn new_day(&mut self) {
@@ -110,21 +176,130 @@ impl Station {
 
         if self.active_modules() == 0 {
             tl_station::end_transmission();
+            self.push_event(SystemEvent::FinalTransmission);
             self.disabled = true;
             return;
         }
 
         self.increment_mission_day();
 
-        self.status(0, false, false);
         self.break_something();
-        tl_station::until_final_transmission(self.days_left() as u16);
+        self.daily_events();
+        // a station that went down today has nothing left to count
+        if !self.is_shut_down() {
+            tl_station::until_final_transmission(self.days_left());
+            self.push_event(SystemEvent::FinalTransmissionCountdown(self.days_left()));
+        }
+        self.update_telemetry();
+    }
+
+    pub fn set_prompter(&mut self, prompter: Box<dyn Prompter>) {
+        self._prompter = prompter;
+    }
+
+    pub fn prompter(&mut self) -> &mut dyn Prompter {
+        self._prompter.as_mut()
+    }
+
+    pub fn set_telemetry(&mut self, telemetry: Box<dyn Telemetry>) {
+        self._telemetry = Some(telemetry);
+        self.update_telemetry();
+    }
+
+    pub fn update_telemetry(&mut self) {
+        if let Some(mut telemetry) = self._telemetry.take() {
+            telemetry.update(self);
+            self._telemetry = Some(telemetry);
+        }
+    }
+
+    // Every system event is also offered to telemetry as a diagnostic.
+    fn push_event(&mut self, event: SystemEvent) {
+        if let Some(mut telemetry) = self._telemetry.take() {
+            telemetry.event(self, &event);
+            self._telemetry = Some(telemetry);
+        }
+        self._events.push(event);
+    }
+
+    fn report_failure(&mut self, path: &str, port_address: u16) {
+        if let Some(mut telemetry) = self._telemetry.take() {
+            telemetry.module_failure(self, path, port_address);
+            self._telemetry = Some(telemetry);
+        }
+    }
+
+    pub fn port_address(&self) -> u16 { self._port_address }
+
+    pub fn last_command_sequence(&self) -> Option<u64> { self._last_command_sequence }
+
+    pub fn set_last_command_sequence(&mut self, sequence: u64) {
+        self._last_command_sequence = Some(sequence);
+    }
+
+    pub fn set_port_address(&mut self, port_address: u16) -> Result<(), String> {
+        self._port_address = check_port_address(port_address)?;
+        self.update_telemetry();
+        return Ok(());
+    }
+
+    pub fn set_category_port_address(&mut self, path: &str, port_address: Option<u16>) -> Result<(), String> {
+        if let Some(v) = port_address {
+            check_port_address(v)?;
+        }
+        let parsed = StationPath::parse(path).map_err(|e| e.to_string())?;
+        if parsed.section.is_some() {
+            return Err(format!("{path}: port addresses belong to categories"));
+        }
+        let (category, _, _) = self.resolve(&parsed).map_err(|e| e.to_string())?;
+
+        self.categories_mut().swap_remove(category).set_port_address(port_address);
+        self.update_telemetry();
+
+        return Ok(());
+    }
+
+    // A category without its own port address is reached through the station's.
+    pub fn category_port_address(&self, category: usize) -> u16 {
+        self.categories()[category]
+            .port_address()
+            .unwrap_or(self._port_address)
+    }
+
+    pub fn port_addresses(&self) -> Vec<u16> {
+        let mut port_addresses = vec![self._port_address];
+        for category in self.categories() {
+            if let Some(v) = category.port_address() {
+                if !port_addresses.contains(&v) {
+                    port_addresses.push(v);
+                }
+            }
+        }
+        return port_addresses;
+    }
+
+    pub fn take_events(&mut self) -> Vec<SystemEvent> {
+        std::mem::take(&mut self._events)
+    }
+
+    fn check_shutdown(&mut self) {
+        if self.is_shut_down() {
+            self.push_event(SystemEvent::Shutdown);
+        }
+    }
+
+    // journals go down to mission control through either comms link
+    pub fn downlink_available(&self) -> bool {
+        self.sections_comm.section_antenna.module_antenna.active()
+            || self.sections_comm.section_transponder.module_transponder.active()
     }
 
     pub fn science(&mut self) {
         self.break_something();
     }
 
+    pub fn seed(&self) -> u64 { self._seed }
+
     pub fn name_display(&self) -> String {
         return format!("Station \"{}\" v{}", &self.name(), &self.version);
     }
@@ -167,36 +342,160 @@ impl UpdateModules for Station {
 }
 
 impl Station {
-    pub fn status(&self, indent: u8, show_fields: bool, show_inner: bool) -> String {
-        tl_station::status(
-            String::from("station"),
-            show_fields,
-            vec![
-                String::from(":name"),
-                String::from(":version"),
-                String::from(":mission-day"),
-                String::from(":total-modules"),
-                String::from(":active-modules")
-            ],
-            vec![
-                format!("\"{}\"", self.name()),
-                format!("{}", self.version),
-                format!("{}", self.mission_day),
-                format!("{}", self.total_modules()),
-                format!("{}", self.active_modules())
-            ],
-            show_inner,
-            String::from(":categories"),
-            vec![
-                self.sections_comm.status(indent + 2),
-                self.sections_crew.status(indent + 2),
-                self.sections_maneuver.status(indent + 2),
-                self.sections_misc.status(indent + 2),
-                self.sections_power.status(indent + 2),
-                self.sections_research.status(indent + 2)
-            ],
-            indent
-        )
+    // the station's own fields, without the category tree
+    pub fn status_header(&self) -> Value {
+        Value::form("station", self.status_fields())
+    }
+
+    pub fn status(&self) -> Value {
+        let categories = self.categories()
+            .into_iter()
+            .map(|category| category.status())
+            .collect();
+
+        let mut fields = self.status_fields();
+        fields.push(("categories", Value::List(categories)));
+        return Value::form("station", fields);
+    }
+
+    pub fn status_query(&self, query: &StatusQuery) -> Result<Value, PathError> {
+        let scope = match &query.scope {
+            Some(v) => v.as_str(),
+            None => {
+                let mut fields = self.status_fields();
+                fields.push(("categories", Value::List(query_categories(self.categories(), query))));
+                return Ok(Value::form("station", fields));
+            }
+        };
+
+        let parsed = StationPath::parse(scope)?;
+        if parsed.module.is_some() {
+            return Ok(self.module(scope)?.status());
+        }
+        if parsed.section.is_some() {
+            return Ok(query_section(self.section(scope)?, query));
+        }
+        return Ok(query_category(self.category(scope)?, query));
+    }
+
+    pub fn snapshot(&self) -> StationSnapshot {
+        StationSnapshot {
+            name: self.name(),
+            version: self.version,
+            mission_day: self.mission_day,
+            total_modules: self.total_modules(),
+            active_modules: self.active_modules(),
+
+            categories: self.categories()
+                .into_iter()
+                .map(snapshot_category)
+                .collect(),
+        }
+    }
+
+    fn status_fields(&self) -> Vec<(&'static str, Value)> {
+        vec![
+            ("name", Value::string(self.name().as_str())),
+            ("version", Value::integer(self.version)),
+            ("mission-day", Value::integer(self.mission_day)),
+            ("total-modules", Value::integer(self.total_modules())),
+            ("active-modules", Value::integer(self.active_modules())),
+        ]
+    }
+}
+
+impl Station {
+    pub fn to_save(&self) -> StationSave {
+        StationSave {
+            name: self.name(),
+            version: self.version,
+            mission_day: self.mission_day,
+            disabled: self.disabled,
+            port_address: self._port_address,
+            last_command_sequence: self._last_command_sequence,
+
+            seed: self._seed,
+            rng_word_pos: self._rng.get_word_pos(),
+
+            total_sections: self.total_sections(),
+            installed_sections: self.installed_sections(),
+            total_modules: self.total_modules(),
+            active_modules: self.active_modules(),
+
+            categories: self.categories()
+                .into_iter()
+                .map(save_category)
+                .collect(),
+        }
+    }
+
+    pub fn from_save(save: &StationSave) -> Result<Self, String> {
+        if save.categories.len() != Station::SECTION_GROUPS as usize {
+            return Err(format!("expected {} categories, found {}",
+                               Station::SECTION_GROUPS, save.categories.len()));
+        }
+
+        let name = StationName::from_str(save.name.as_str())
+            .map_err(|_| format!("unknown station name \"{}\"", save.name))?;
+
+        let mut rng = station_rng(save.seed);
+        rng.set_word_pos(save.rng_word_pos);
+
+        let mut station = Station {
+            _name: name,
+            version: save.version,
+            mission_day: save.mission_day,
+            disabled: save.disabled,
+
+            _port_address: check_port_address(save.port_address)?,
+            _last_command_sequence: save.last_command_sequence,
+
+            sections_comm: load_category(&save.categories[0],
+                                         category::comms::CommsCategory::with_installation)?,
+            sections_crew: load_category(&save.categories[1],
+                                         category::crew::CrewCategory::with_installation)?,
+            sections_maneuver: load_category(&save.categories[2],
+                                             category::maneuver::ManeuverCategory::with_installation)?,
+            sections_misc: load_category(&save.categories[3],
+                                         category::misc::MiscCategory::with_installation)?,
+            sections_power: load_category(&save.categories[4],
+                                          category::power::PowerCategory::with_installation)?,
+            sections_research: load_category(&save.categories[5],
+                                             category::research::ResearchCategory::with_installation)?,
+
+            _total_sections: 0,
+            _installed_sections: 0,
+            _total_modules: 0,
+            _active_modules: 0,
+
+            _seed: save.seed,
+            _rng: rng,
+
+            _events: vec![],
+            _prompter: Box::new(InquirePrompter::new()),
+            _telemetry: None,
+        };
+
+        station.update_counts();
+
+        check_count(&save.name, "total-sections",
+                    station.total_sections(), save.total_sections)?;
+        check_count(&save.name, "installed-sections",
+                    station.installed_sections(), save.installed_sections)?;
+        check_count(&save.name, "total-modules",
+                    station.total_modules(), save.total_modules)?;
+        check_count(&save.name, "active-modules",
+                    station.active_modules(), save.active_modules)?;
+
+        return Ok(station);
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        self.to_save().write(path)
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        Station::from_save(&StationSave::read(path)?)
     }
 }
 
@@ -204,13 +503,15 @@ impl Station {
     fn break_something(&mut self) {
         let broken_module: Result<String, String>;
 
-        match thread_rng().gen_range(1..=6) {
-            1 => { broken_module = self.sections_comm.break_something(); },
-            2 => { broken_module = self.sections_crew.break_something(); },
-            3 => { broken_module = self.sections_maneuver.break_something(); },
-            4 => { broken_module = self.sections_misc.break_something(); },
-            5 => { broken_module = self.sections_power.break_something(); },
-            6 => { broken_module = self.sections_research.break_something(); },
+        let rng = &mut self._rng;
+        let category = rng.gen_range(1..=6);
+        match category {
+            1 => { broken_module = self.sections_comm.break_something(rng); },
+            2 => { broken_module = self.sections_crew.break_something(rng); },
+            3 => { broken_module = self.sections_maneuver.break_something(rng); },
+            4 => { broken_module = self.sections_misc.break_something(rng); },
+            5 => { broken_module = self.sections_power.break_something(rng); },
+            6 => { broken_module = self.sections_research.break_something(rng); },
             _ => unreachable!(),
         }
 
@@ -219,7 +520,10 @@ impl Station {
         match broken_module {
             Ok(v) => {
                 self.update_active_modules();
-                tl_station::section_failure(v);
+                tl_station::section_failure(v.clone());
+                self.report_failure(v.as_str(), self.category_port_address(category - 1));
+                self.push_event(SystemEvent::ModuleFailure(v));
+                self.check_shutdown();
             },
             Err(_) => {
                 if self.active_modules() == self.total_modules() {
@@ -230,12 +534,248 @@ impl Station {
     }
 }
 
-impl Repair for Station {
-    fn repairable(&self) -> bool {
+impl Station {
+    fn daily_events(&mut self) {
+        for event in roll_events(&mut self._rng) {
+            if self.is_shut_down() {
+                break;
+            }
+            self.apply_event(event);
+        }
+    }
+
+    pub fn apply_event(&mut self, event: Event) {
+        tl_event::station_event(event);
+
+        match event.effect(&mut self._rng) {
+            Effect::BreakModules(count) => {
+                self.push_event(SystemEvent::StationEvent(event));
+                for _ in 0..count {
+                    let active = self.module_paths(true);
+                    if active.is_empty() { break; }
+                    let path = &active[self._rng.gen_range(0..active.len())];
+                    let _ = self.break_path(path);
+                }
+            },
+            Effect::RestoreModule => {
+                self.push_event(SystemEvent::StationEvent(event));
+                let inactive = self.module_paths(false);
+                if inactive.is_empty() {
+                    tl_event::module_restored_none();
+                    return;
+                }
+                let path = &inactive[self._rng.gen_range(0..inactive.len())];
+                if let Ok(v) = self.restore_path(path) {
+                    tl_event::module_restored(v);
+                }
+            },
+            Effect::PowerDown => {
+                self.push_event(SystemEvent::StationEvent(event));
+                tl_event::total_power_failure();
+                self.power_down();
+            },
+            Effect::Message(message) => {
+                tl_event::incoming_message(message.as_str());
+                self.push_event(SystemEvent::IncomingMessage(message));
+            },
+            Effect::Reroll => {
+                self.push_event(SystemEvent::StationEvent(event));
+            },
+        }
+    }
+
+    fn module_paths(&self, active: bool) -> Vec<String> {
+        self.paths()
+            .into_iter()
+            .filter(|path| match self.module(path) {
+                Ok(module) => module.active() == active,
+                Err(_) => false,
+            })
+            .collect()
+    }
+
+    fn restore_path(&mut self, path: &str) -> Result<String, PathError> {
+        let parsed = StationPath::parse_module(path)?;
+
+        let restored = self.update_module(&parsed, |module| {
+            module.activate();
+            return Ok(());
+        })?;
+
+        self.push_event(SystemEvent::ModuleRestored(restored.clone()));
+        self.update_telemetry();
+
+        return Ok(restored);
+    }
+}
+
+impl Station {
+    pub fn category(&self, path: &str) -> Result<&dyn Category, PathError> {
+        let parsed = StationPath::parse(path)?;
+        let (category, _, _) = self.resolve(&parsed)?;
+
+        return Ok(self.categories().swap_remove(category));
+    }
+
+    pub fn section(&self, path: &str) -> Result<&dyn Section, PathError> {
+        let parsed = StationPath::parse(path)?;
+        let (category, section, _) = self.resolve(&parsed)?;
+        let section = section.ok_or(PathError::UnknownSection(path.to_string()))?;
+
+        return Ok(self.categories().swap_remove(category).sections().swap_remove(section));
+    }
+
+    pub fn module(&self, path: &str) -> Result<&dyn Module, PathError> {
+        let parsed = StationPath::parse_module(path)?;
+        let (category, section, module) = self.resolve(&parsed)?;
+        let (section, module) = (section.unwrap_or(0), module.unwrap_or(0));
+
+        return Ok(self.categories()
+            .swap_remove(category)
+            .sections()
+            .swap_remove(section)
+            .modules()
+            .swap_remove(module));
+    }
+
+    pub fn status_path(&self, path: &str) -> Result<Value, PathError> {
+        let parsed = StationPath::parse(path)?;
+
+        if parsed.module.is_some() {
+            return Ok(self.module(path)?.status());
+        }
+        if parsed.section.is_some() {
+            return Ok(self.section(path)?.status());
+        }
+        return Ok(self.category(path)?.status());
+    }
+
+    pub fn repair_path(&mut self, path: &str) -> Result<String, PathError> {
+        let parsed = StationPath::parse_module(path)?;
+
+        let repaired = self.update_module(&parsed, |module| {
+            if module.active() {
+                return Err(PathError::AlreadyActive(parsed.to_string()));
+            }
+            module.activate();
+            return Ok(());
+        })?;
+
+        self.push_event(SystemEvent::ModuleRepaired(repaired.clone()));
+        self.update_telemetry();
+
+        return Ok(repaired);
+    }
+
+    pub fn break_path(&mut self, path: &str) -> Result<String, PathError> {
+        let parsed = StationPath::parse_module(path)?;
+        let (category, _, _) = self.resolve(&parsed)?;
+
+        let broken = self.update_module(&parsed, |module| {
+            if !module.active() {
+                return Err(PathError::AlreadyInactive(parsed.to_string()));
+            }
+            module.deactivate();
+            return Ok(());
+        })?;
+
+        tl_station::section_failure(broken.clone());
+        self.report_failure(broken.as_str(), self.category_port_address(category));
+        self.push_event(SystemEvent::ModuleFailure(broken.clone()));
+        self.check_shutdown();
+
+        return Ok(broken);
+    }
+
+    pub fn paths(&self) -> Vec<String> {
+        let mut paths: Vec<String> = vec![];
+
+        for category in self.categories() {
+            paths.push(category.slug());
+
+            for section in category.sections() {
+                if !section.installed() { continue; }
+                paths.push(format!("{}/{}", category.slug(), section.slug()));
+
+                for module in section.modules() {
+                    paths.push(format!("{}/{}/{}", category.slug(), section.slug(), module.slug()));
+                }
+            }
+        }
+
+        return paths;
+    }
+
+    fn resolve(&self, path: &StationPath) -> Result<(usize, Option<usize>, Option<usize>), PathError> {
+        let categories = self.categories();
+        let category_index = categories
+            .iter()
+            .position(|category| category.slug() == path.category)
+            .ok_or(PathError::UnknownCategory(path.to_string()))?;
+
+        let section_slug = match &path.section {
+            Some(v) => v,
+            None => { return Ok((category_index, None, None)); }
+        };
+
+        let sections = categories[category_index].sections();
+        let section_index = sections
+            .iter()
+            .position(|section| &section.slug() == section_slug)
+            .ok_or(PathError::UnknownSection(path.to_string()))?;
+        if !sections[section_index].installed() {
+            return Err(PathError::NotInstalled(path.to_string()));
+        }
+
+        let module_slug = match &path.module {
+            Some(v) => v,
+            None => { return Ok((category_index, Some(section_index), None)); }
+        };
+
+        let module_index = sections[section_index].modules()
+            .iter()
+            .position(|module| &module.slug() == module_slug)
+            .ok_or(PathError::UnknownModule(path.to_string()))?;
+
+        return Ok((category_index, Some(section_index), Some(module_index)));
+    }
+
+    fn update_module<F>(&mut self, path: &StationPath, update: F) -> Result<String, PathError>
+        where F: FnOnce(&mut dyn Module) -> Result<(), PathError> {
+
+        let (category_index, section_index, module_index) = self.resolve(path)?;
+        let (section_index, module_index) = (section_index.unwrap_or(0), module_index.unwrap_or(0));
+
+        let category = self.categories_mut().swap_remove(category_index);
+        let module_name: String;
+        let section_name: String;
+        {
+            let section = category.sections_mut().swap_remove(section_index);
+            {
+                let module = section.modules_mut().swap_remove(module_index);
+                update(module)?;
+                module_name = module.name();
+            }
+            section.update_active_modules();
+            section_name = section.name();
+        }
+        category.update_active_modules();
+        let display = format!("{} / {} / {}", category.name(), section_name, module_name);
+
+        self.update_active_modules();
+
+        return Ok(display);
+    }
+}
+
+impl Station {
+    pub fn repairable(&self) -> bool {
         self.active_modules() < self.total_modules()
     }
 
-    fn repair(&mut self) {
+    pub fn repair(&mut self) -> Option<String> {
+        let prompter = self._prompter.as_mut();
+
         let prompts: Vec<String> = vec![
             self.sections_comm.repair_display(),
             self.sections_crew.repair_display(),
@@ -254,21 +794,29 @@ impl Repair for Station {
         if self.sections_research.repairable() { options.push(prompts[5].clone()) }
 
         let chosen: String;
-        match tli_menu("Select section category to repair:", options) {
+        match prompter.select("Select section category to repair:", options) {
             Ok(v) => { chosen = v; },
-            Err(_) => { return; }
+            Err(_) => { return None; }
         }
+        let repaired: Option<String>;
         match chosen {
-            _ if chosen == prompts[0] => { self.sections_comm.repair(); },
-            _ if chosen == prompts[1] => { self.sections_crew.repair(); },
-            _ if chosen == prompts[2] => { self.sections_maneuver.repair(); },
-            _ if chosen == prompts[3] => { self.sections_misc.repair(); },
-            _ if chosen == prompts[4] => { self.sections_power.repair(); },
-            _ if chosen == prompts[5] => { self.sections_research.repair(); },
+            _ if chosen == prompts[0] => { repaired = self.sections_comm.repair(prompter); },
+            _ if chosen == prompts[1] => { repaired = self.sections_crew.repair(prompter); },
+            _ if chosen == prompts[2] => { repaired = self.sections_maneuver.repair(prompter); },
+            _ if chosen == prompts[3] => { repaired = self.sections_misc.repair(prompter); },
+            _ if chosen == prompts[4] => { repaired = self.sections_power.repair(prompter); },
+            _ if chosen == prompts[5] => { repaired = self.sections_research.repair(prompter); },
             _ => unreachable!()
         }
 
         self.update_active_modules();
+
+        if let Some(v) = &repaired {
+            self.push_event(SystemEvent::ModuleRepaired(v.clone()));
+            self.update_telemetry();
+        }
+
+        return repaired;
     }
 }
 
@@ -282,5 +830,9 @@ impl PowerDown for Station {
         self.sections_research.power_down();
 
         self.update_active_modules();
+
+        self.push_event(SystemEvent::PowerDown);
+        self.check_shutdown();
+        self.update_telemetry();
     }
 }
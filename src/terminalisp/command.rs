@@ -0,0 +1,31 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::packets::ack::AckPacket;
+
+// module
+use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
+pub fn command_received(source: String, sequence: u64, command: String) {
+    emit(format!("(command :source {} :sequence {} :command {})",
+                 quoted(source.as_str()), sequence, quoted(command.as_str())));
+}
+
+pub fn command_rejected(source: String, error: String) {
+    emit(format!("(command-error {} :source {} :reason {})",
+                 symbols::ERROR, quoted(source.as_str()), quoted(error.as_str())));
+}
+
+pub fn command_ack(ack: &AckPacket) {
+    emit(format!("(command-ack :sequence {} :status '{} :message {})",
+                 ack.sequence, ack.status, quoted(ack.message.as_str())));
+}
+
+pub fn command_error(error: String) {
+    emit(format!("(command-error {} {})", symbols::ERROR, quoted(error.as_str())));
+}
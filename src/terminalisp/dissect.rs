@@ -0,0 +1,135 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::packets::ping_reply::PingReply;
+use crate::protocol::capture::CapturedDatagram;
+use crate::protocol::diagnostics::priority_name;
+use crate::protocol::dissect::{Dissection, Fields, MacCheck, Problem, op_code_name};
+
+// module
+use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
+fn priority(value: u8) -> String {
+    match priority_name(value) {
+        Some(v) => format!("'{v}"),
+        None => value.to_string(),
+    }
+}
+
+fn mac(check: MacCheck) -> &'static str {
+    match check {
+        MacCheck::Verified => "'verified",
+        MacCheck::Unchecked => "'unchecked",
+    }
+}
+
+fn station(reply: &PingReply) -> String {
+    format!(":name {} :version {} :mission-day {} :active-modules {} :total-modules {} :port-address {}",
+            quoted(reply.name.as_str()),
+            reply.version,
+            reply.mission_day,
+            reply.active_modules,
+            reply.total_modules,
+            reply.port_address)
+}
+
+fn fields(fields: &Fields) -> String {
+    match fields {
+        Fields::Ping(v) => {
+            let range = v.target_range();
+            format!(":flags \"0b{:08b}\" :diag-priority {} :target-bottom {} :target-top {}",
+                    v.flags, priority(v.diag_priority), range.bottom, range.top)
+        },
+        Fields::PingReply(v) => station(v),
+        Fields::Failure(v) => {
+            format!("{} :failure-port-address {} :path {}",
+                    station(&v.station), v.port_address, quoted(v.path.as_str()))
+        },
+        Fields::Diagnostic(v) => {
+            format!(":priority {} :port-address {} :message {}",
+                    priority(v.priority), v.port_address, quoted(v.message.as_str()))
+        },
+        Fields::Command(v, check) => {
+            format!(":sequence {} :target-bottom {} :target-top {} :command {} :mac {}",
+                    v.sequence, v.target_range.bottom, v.target_range.top,
+                    quoted(v.command.to_string().as_str()), mac(*check))
+        },
+        Fields::Ack(v, check) => {
+            format!(":sequence {} :status '{} :message {} :mac {}",
+                    v.sequence, v.status, quoted(v.message.as_str()), mac(*check))
+        },
+        Fields::JournalChunk(v) => {
+            format!(":transfer {} :sequence {} :total {} :payload {}",
+                    v.transfer, v.sequence, v.total,
+                    quoted(String::from_utf8_lossy(&v.payload).as_ref()))
+        },
+        Fields::JournalResend(v) => {
+            let missing: Vec<String> = v.missing.iter().map(|sequence| sequence.to_string()).collect();
+            format!(":transfer {} :missing ({})", v.transfer, missing.join(" "))
+        },
+    }
+}
+
+fn problem(problem: &Problem) -> String {
+    let (symbol, reason) = match problem {
+        Problem::NotStationPacket => ("'not-station-packet", None),
+        Problem::Truncated(v) => ("'truncated", Some(v.clone())),
+        Problem::Malformed(v) => ("'malformed", Some(v.to_string())),
+        Problem::VersionMismatch => ("'version-mismatch", None),
+        Problem::UnknownOpCode => ("'unknown-op-code", None),
+        Problem::BadMac(v) => ("'bad-mac", Some(v.to_string())),
+    };
+
+    match reason {
+        Some(v) => format!("{} {} :reason {}", symbols::ERROR, symbol, quoted(v.as_str())),
+        None => format!("{} {}", symbols::ERROR, symbol),
+    }
+}
+
+pub fn packet(index: usize, datagram: Option<&CapturedDatagram>, dissection: &Dissection) {
+    let mut form = format!("(packet :index {index}");
+
+    if let Some(v) = datagram {
+        form += &format!(" :frame {} :source {} :destination {}",
+                         v.frame,
+                         quoted(v.source.to_string().as_str()),
+                         quoted(v.destination.to_string().as_str()));
+    }
+    form += &format!(" :length {}", dissection.length);
+
+    if let Some(op_code) = dissection.op_code {
+        match op_code_name(op_code) {
+            Some(v) => { form += &format!(" :op '{v}"); },
+            None => { form += &format!(" :op {}", symbols::NONE); },
+        }
+        form += &format!(" :op-code \"0x{op_code:04x}\"");
+    }
+    if let Some((hi, lo)) = dissection.version {
+        form += &format!(" :version \"{hi}.{lo}\"");
+    }
+
+    match &dissection.result {
+        Ok(v) => { form += &format!(" {}", fields(v)); },
+        Err(v) => { form += &format!(" {}", problem(v)); },
+    }
+    form += ")";
+
+    emit(form);
+}
+
+pub fn input_error(line: usize, error: String) {
+    emit(format!("(dissect {} :line {} :reason {})", symbols::ERROR, line, quoted(error.as_str())));
+}
+
+pub fn dissect_error(error: String) {
+    emit(format!("(dissect {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+pub fn summary(packets: usize, errors: usize) {
+    emit(format!("(dissect :packets {packets} :errors {errors})"));
+}
@@ -0,0 +1,21 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+pub fn quoted(value: &str) -> String {
+    let mut result = String::with_capacity(value.len() + 2);
+
+    result.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => { result.push_str("\\\""); },
+            '\\' => { result.push_str("\\\\"); },
+            '\n' => { result.push_str("\\n"); },
+            '\t' => { result.push_str("\\t"); },
+            _ => { result.push(c); },
+        }
+    }
+    result.push('"');
+
+    return result;
+}
@@ -0,0 +1,32 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::event::event::Event;
+
+// module
+use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
+pub fn station_event(event: Event) {
+    emit(format!("(station-event '{} {})", event, quoted(event.description())));
+}
+
+pub fn incoming_message(message: &str) {
+    emit(format!("(incoming-message {})", quoted(message)));
+}
+
+pub fn module_restored(path: String) {
+    emit(format!("(module-restored {})", quoted(path.as_str())));
+}
+
+pub fn module_restored_none() {
+    emit(format!("(module-restored {})", symbols::NONE));
+}
+
+pub fn total_power_failure() {
+    emit(String::from("(total-power-failure)"));
+}
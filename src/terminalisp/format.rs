@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use serde_json::{Map, Value as Json};
+use strum_macros::{Display, EnumString};
+
+// module
+use crate::terminalisp::reader::read;
+use crate::terminalisp::value::Value;
+
+
+#[derive(Display, EnumString)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[strum(serialize_all = "lowercase")]
+pub enum OutputFormat {
+    Terminalisp,
+    Json,
+    Yaml,
+}
+
+// One emitted form as one JSON line or one YAML document.
+pub fn render(form: &str, format: OutputFormat) -> String {
+    if format == OutputFormat::Terminalisp {
+        return form.to_string();
+    }
+
+    // every emitter prints readable forms, the fallback keeps the text if one does not
+    let json = match read(form) {
+        Ok(v) => to_json(&v),
+        Err(_) => Json::String(form.to_string()),
+    };
+
+    match format {
+        OutputFormat::Json => json.to_string(),
+        _ => format!("---\n{}", serde_yaml::to_string(&json).unwrap_or_default()),
+    }
+}
+
+// A form becomes an object tagged with its head under "form". Its `:key value` pairs
+// become snake_case fields and anything else goes to "args". Forms nested in fields
+// are left untagged, so a status tree has the shape of `StationSnapshot`. A `:form` or
+// `:args` keyword that would clash with those keeps its colon, as ":form" or ":args".
+pub fn to_json(value: &Value) -> Json {
+    match (value.head(), value.as_list()) {
+        (Some(head), Some(items)) => object(Some(head), &items[1..]),
+        _ => data(value),
+    }
+}
+
+fn data(value: &Value) -> Json {
+    match value {
+        Value::Symbol(v) | Value::Quoted(v) | Value::Str(v) => Json::String(v.clone()),
+        Value::Keyword(v) => Json::String(format!(":{v}")),
+        Value::Integer(v) => {
+            match (i64::try_from(*v), u64::try_from(*v)) {
+                (Ok(v), _) => Json::from(v),
+                (_, Ok(v)) => Json::from(v),
+                _ => Json::String(v.to_string()),
+            }
+        },
+        Value::List(items) => {
+            match value.head() {
+                Some(_) => object(None, &items[1..]),
+                None => Json::Array(items.iter().map(data).collect()),
+            }
+        },
+    }
+}
+
+fn object(head: Option<&str>, items: &[Value]) -> Json {
+    let mut fields = Map::new();
+    if let Some(head) = head {
+        fields.insert(String::from("form"), Json::String(head.to_string()));
+    }
+    let mut args: Vec<Json> = vec![];
+
+    let mut index = 0;
+    while index < items.len() {
+        match (&items[index], items.get(index + 1)) {
+            (Value::Keyword(key), Some(value)) if !matches!(value, Value::Keyword(_)) => {
+                let key = key.replace('-', "_");
+                let reserved = key == "args" || (key == "form" && head.is_some());
+                let key = if reserved { format!(":{key}") } else { key };
+                fields.insert(key, data(value));
+                index += 2;
+            },
+            (item, _) => {
+                args.push(data(item));
+                index += 1;
+            }
+        }
+    }
+
+    if !args.is_empty() {
+        fields.insert(String::from("args"), Json::Array(args));
+    }
+
+    return Json::Object(fields);
+}
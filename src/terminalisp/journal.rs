@@ -2,18 +2,66 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at http://mozilla.org/MPL/2.0/.
 
+// project
+use crate::journal::entry::JournalEntry;
+
 // module
 use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
 
 
 fn journal_entry_status(message: String) {
-    println!("(journal-entry-status {})", message);
+    emit(format!("(journal-entry-status {})", message));
 }
 
 pub fn journal_entry_status_error(error: String) {
-    journal_entry_status(format!("{} \"{}\"", symbols::ERROR, error));
+    journal_entry_status(format!("{} {}", symbols::ERROR, quoted(error.as_str())));
 }
 
 pub fn journal_entry_status_saved() {
     journal_entry_status(String::from(symbols::SAVED));
 }
+
+pub fn journal_file_loaded() {
+    emit(format!("(journal-file {})", symbols::LOADED));
+}
+
+pub fn journal_file_error(error: String) {
+    emit(format!("(journal-file {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+pub fn journal_file_torn_tail(line: usize, error: String) {
+    emit(format!("(journal-file {} :line {} :message {})", symbols::TORN_TAIL, line, quoted(error.as_str())));
+}
+
+pub fn journal_export_saved() {
+    emit(format!("(journal-export {})", symbols::SAVED));
+}
+
+pub fn journal_export_error(error: String) {
+    emit(format!("(journal-export {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+// The journal as one form, printed at the end of a session in whatever format the sink renders.
+pub fn journal_printout(header: &str, title: &str, entries: &[JournalEntry]) {
+    emit(journal(header, title, entries));
+}
+
+pub fn journal(header: &str, title: &str, entries: &[JournalEntry]) -> String {
+    let mut result: String = String::new();
+
+    result.push_str(format!("(journal :header {} :title {}\n", quoted(header), quoted(title)).as_str());
+    result.push_str("    :entries (\n");
+    for entry in entries {
+        result.push_str(format!("        (entry :mission-day {} :timestamp {} :kind '{} :body {})\n",
+                                entry.mission_day,
+                                quoted(entry.timestamp_display().as_str()),
+                                entry.kind,
+                                quoted(entry.body.as_str())).as_str());
+    }
+    result.push_str("    )\n");
+    result.push_str(")\n");
+
+    return result;
+}
@@ -6,6 +6,11 @@
 use inquire::Select;
 
 
+// module
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
 pub fn tli_menu(title: &str, options: Vec<String>) -> Result<String, String> {
     match Select::new(title, options).prompt() {
         Ok(v) =>  { Ok(v) },
@@ -17,5 +22,5 @@ pub fn tli_menu(title: &str, options: Vec<String>) -> Result<String, String> {
 }
 
 pub fn menu_error(error: String) {
-    println!("(menu-error \"{}\")", error);
+    emit(format!("(menu-error {})", quoted(error.as_str())));
 }
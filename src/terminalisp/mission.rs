@@ -0,0 +1,94 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::protocol::mission::StationRow;
+use crate::protocol::diagnostics::priority_name;
+use crate::packets::diagnostic::DiagnosticPacket;
+use crate::protocol::downlink::DownlinkReport;
+use crate::journal::journal::Journal;
+
+// module
+use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
+fn station_row(row: &StationRow) -> String {
+    let failure = match &row.latest_failure {
+        Some(v) => quoted(v.as_str()),
+        None => String::from(symbols::NONE),
+    };
+
+    format!("(station :address {} :name {} :version {} :port-address {} :mission-day {} :active-modules {} :total-modules {} :latest-failure {})",
+            quoted(row.address.to_string().as_str()),
+            quoted(row.reply.name.as_str()),
+            row.reply.version,
+            row.reply.port_address,
+            row.reply.mission_day,
+            row.reply.active_modules,
+            row.reply.total_modules,
+            failure)
+}
+
+pub fn station_table(rows: &[StationRow]) {
+    let mut form = String::from("(mission-control\n    :stations (\n");
+    for row in rows {
+        form += &format!("        {}\n", station_row(row));
+    }
+    form += "    )\n)";
+    emit(form);
+}
+
+pub fn station_failure(row: &StationRow, path: &str) {
+    emit(format!("(station-failure :address {} :name {} :path {})",
+                 quoted(row.address.to_string().as_str()),
+                 quoted(row.reply.name.as_str()),
+                 quoted(path)));
+}
+
+pub fn mission_error(error: String) {
+    emit(format!("(mission-control {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+pub fn beacon_started(address: String) {
+    emit(format!("(station-beacon :address {})", quoted(address.as_str())));
+}
+
+pub fn beacon_error(error: String) {
+    emit(format!("(station-beacon {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+pub fn diagnostic(address: String, packet: &DiagnosticPacket) {
+    let priority = match priority_name(packet.priority) {
+        Some(v) => format!("'{v}"),
+        None => packet.priority.to_string(),
+    };
+
+    emit(format!("(diagnostic :address {} :priority {} :port-address {} :message {})",
+                 quoted(address.as_str()),
+                 priority,
+                 packet.port_address,
+                 quoted(packet.message.as_str())));
+}
+
+pub fn journal_downlink(target: String, report: &DownlinkReport) {
+    let confirmed = match report.confirmed {
+        true => symbols::OK,
+        false => "'unconfirmed",
+    };
+
+    emit(format!("(journal-downlink :target {} :transfer {} :chunks {} :resent {} :status {})",
+                 quoted(target.as_str()), report.transfer, report.chunks, report.resent, confirmed));
+}
+
+pub fn journal_downlink_error(error: String) {
+    emit(format!("(journal-downlink {} {})", symbols::ERROR, quoted(error.as_str())));
+}
+
+pub fn journal_received(address: String, journal: &Journal) {
+    emit(format!("(journal-received :address {} :entries {})",
+                 quoted(address.as_str()), journal.entries().len()));
+    emit(journal.to_terminalisp());
+}
@@ -5,7 +5,19 @@
 pub mod station;
 pub mod journal;
 pub mod menu;
+pub mod script;
+pub mod repl;
+pub mod event;
+pub mod mission;
+pub mod command;
+pub mod dissect;
 
 pub mod symbols;
+pub mod escape;
+pub mod value;
+pub mod reader;
+pub mod pretty;
+pub mod format;
+pub mod sink;
 
 pub mod original;
@@ -4,10 +4,12 @@
 
 // module
 use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
 
 
 pub fn station_header(name: String, version: u8) {
-    println!("(station :name {} :version {})", name, version);
+    emit(format!("(station :name {} :version {})", quoted(name.as_str()), version));
 }
 
 pub fn station_status(keys: Vec<String>,
@@ -15,23 +17,24 @@ pub fn station_status(keys: Vec<String>,
                       section_names: Vec<String>,
                       section_statuses: Vec<bool>) {
 
-    println!("(station-status");
+    let mut form = String::from("(station-status\n");
     for tuple in keys.iter().zip(values) {
-        println!("    :{} {}", tuple.0, tuple.1);
+        form += &format!("    :{} {}\n", tuple.0, tuple.1);
     }
-    println!("    :sections (");
+    form += "    :sections (\n";
     for tuple in section_names.iter().zip(section_statuses) {
         let active = if tuple.1 { symbols::OK } else { symbols::INACTIVE };
-        println!("        (section :status {:<9} :name {}", active, tuple.0);
+        form += &format!("        (section :status {:<9} :name {})\n", active, quoted(tuple.0.as_str()));
     }
-    println!("    )");
-    println!(")");
+    form += "    )\n";
+    form += ")";
+    emit(form);
 }
 
 pub fn section_failure_none() {
-    println!("(section-failure {})", symbols::NONE);
+    emit(format!("(section-failure {})", symbols::NONE));
 }
 
 pub fn section_to_repair_invalid() {
-    println!("(section-to-repair {})", symbols::INVALID);
+    emit(format!("(section-to-repair {})", symbols::INVALID));
 }
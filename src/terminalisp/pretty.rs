@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// module
+use crate::terminalisp::value::Value;
+
+
+const INDENT: usize = 2;
+
+// Lays a value out for `width` columns. Lists that fit stay on one line; a form that does
+// not fit puts each `:key value` on its own line with the keywords aligned, and a value
+// too long for its keyword's line moves below it. Atoms are never split, so a single long
+// string can still overflow.
+pub fn pretty(value: &Value, width: usize) -> String {
+    let mut out = String::new();
+    layout(value, 0, width, &mut out);
+    return out;
+}
+
+fn layout(value: &Value, column: usize, width: usize, out: &mut String) {
+    let flat = value.to_string();
+    let items = match value {
+        Value::List(items) if !items.is_empty() && column + flat.chars().count() > width => items,
+        _ => {
+            out.push_str(flat.as_str());
+            return;
+        }
+    };
+
+    match value.head() {
+        Some(head) => { layout_form(head, &items[1..], column, width, out); },
+        None => { layout_list(items, column, width, out); },
+    }
+}
+
+fn layout_form(head: &str, items: &[Value], column: usize, width: usize, out: &mut String) {
+    let inner = column + INDENT;
+
+    out.push('(');
+    out.push_str(head);
+
+    let mut index = 0;
+    while index < items.len() {
+        newline(inner, out);
+
+        match (&items[index], items.get(index + 1)) {
+            (Value::Keyword(key), Some(value)) if !matches!(value, Value::Keyword(_)) => {
+                let key = format!(":{key}");
+                out.push_str(key.as_str());
+
+                let after_key = inner + key.chars().count() + 1;
+                if after_key + value.to_string().chars().count() <= width || !matches!(value, Value::List(_)) {
+                    out.push(' ');
+                    layout(value, after_key, width, out);
+                } else {
+                    newline(inner + INDENT, out);
+                    layout(value, inner + INDENT, width, out);
+                }
+                index += 2;
+            },
+            (item, _) => {
+                layout(item, inner, width, out);
+                index += 1;
+            }
+        }
+    }
+
+    out.push(')');
+}
+
+fn layout_list(items: &[Value], column: usize, width: usize, out: &mut String) {
+    out.push('(');
+    for (index, item) in items.iter().enumerate() {
+        if index > 0 {
+            newline(column + 1, out);
+        }
+        layout(item, column + 1, width, out);
+    }
+    out.push(')');
+}
+
+fn newline(column: usize, out: &mut String) {
+    out.push('\n');
+    out.push_str(" ".repeat(column).as_str());
+}
@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::error::Error;
+use std::fmt;
+use std::iter::Peekable;
+use std::str::CharIndices;
+
+// module
+use crate::terminalisp::value::Value;
+
+
+// Lists nest at most this deep, the reader recurses once per level.
+pub const DEPTH_MAX : usize = 128;
+
+// Positions are byte offsets into the input.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ReadError {
+    UnexpectedEnd,
+    UnexpectedClose(usize),
+    UnterminatedString(usize),
+    InvalidEscape(usize),
+    InvalidToken(usize, String),
+    TrailingInput(usize),
+    TooDeep(usize),
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::UnexpectedEnd => write!(f, "unexpected end of input"),
+            ReadError::UnexpectedClose(at) => write!(f, "unexpected \")\" at {at}"),
+            ReadError::UnterminatedString(at) => write!(f, "unterminated string starting at {at}"),
+            ReadError::InvalidEscape(at) => write!(f, "invalid escape at {at}"),
+            ReadError::InvalidToken(at, token) => write!(f, "invalid token \"{token}\" at {at}"),
+            ReadError::TrailingInput(at) => write!(f, "trailing input at {at}"),
+            ReadError::TooDeep(at) => write!(f, "lists nested deeper than {DEPTH_MAX} at {at}"),
+        }
+    }
+}
+
+impl Error for ReadError {}
+
+// Exactly one form, surrounding whitespace allowed.
+pub fn read(input: &str) -> Result<Value, ReadError> {
+    let mut reader = Reader { chars: input.char_indices().peekable(), depth: 0 };
+
+    let value = reader.value()?;
+    reader.skip_whitespace();
+    if let Some((at, _)) = reader.chars.peek() {
+        return Err(ReadError::TrailingInput(*at));
+    }
+
+    return Ok(value);
+}
+
+// Any number of forms, like the output of a whole session.
+pub fn read_all(input: &str) -> Result<Vec<Value>, ReadError> {
+    let mut reader = Reader { chars: input.char_indices().peekable(), depth: 0 };
+    let mut values: Vec<Value> = vec![];
+
+    loop {
+        reader.skip_whitespace();
+        if reader.chars.peek().is_none() {
+            break;
+        }
+        values.push(reader.value()?);
+    }
+
+    return Ok(values);
+}
+
+fn delimiter(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '(' | ')' | '"' | '\'' | ';')
+}
+
+struct Reader<'a> {
+    chars: Peekable<CharIndices<'a>>,
+    depth: usize,
+}
+
+impl Reader<'_> {
+    // whitespace and `;` comments up to the end of the line
+    fn skip_whitespace(&mut self) {
+        while let Some((_, c)) = self.chars.peek() {
+            match c {
+                ';' => {
+                    for (_, c) in self.chars.by_ref() {
+                        if c == '\n' { break; }
+                    }
+                },
+                _ if c.is_whitespace() => { self.chars.next(); },
+                _ => { break; }
+            }
+        }
+    }
+
+    fn value(&mut self) -> Result<Value, ReadError> {
+        self.skip_whitespace();
+
+        let (at, c) = *self.chars.peek().ok_or(ReadError::UnexpectedEnd)?;
+        match c {
+            '(' => {
+                if self.depth == DEPTH_MAX {
+                    return Err(ReadError::TooDeep(at));
+                }
+                self.chars.next();
+                self.depth += 1;
+                let list = self.list();
+                self.depth -= 1;
+                list
+            },
+            ')' => Err(ReadError::UnexpectedClose(at)),
+            '"' => {
+                self.chars.next();
+                self.string(at)
+            },
+            '\'' => {
+                self.chars.next();
+                let name = self.token();
+                if name.is_empty() {
+                    return Err(ReadError::InvalidToken(at, String::from("'")));
+                }
+                Ok(Value::Quoted(name))
+            },
+            ':' => {
+                self.chars.next();
+                let name = self.token();
+                if name.is_empty() {
+                    return Err(ReadError::InvalidToken(at, String::from(":")));
+                }
+                Ok(Value::Keyword(name))
+            },
+            _ => {
+                let token = self.token();
+                let digits = token.strip_prefix('-').unwrap_or(token.as_str());
+                if !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()) {
+                    return token.parse::<i128>()
+                        .map(Value::Integer)
+                        .map_err(|_| ReadError::InvalidToken(at, token));
+                }
+                Ok(Value::Symbol(token))
+            },
+        }
+    }
+
+    fn list(&mut self) -> Result<Value, ReadError> {
+        let mut items: Vec<Value> = vec![];
+
+        loop {
+            self.skip_whitespace();
+            match self.chars.peek() {
+                Some((_, ')')) => {
+                    self.chars.next();
+                    return Ok(Value::List(items));
+                },
+                Some(_) => { items.push(self.value()?); },
+                None => { return Err(ReadError::UnexpectedEnd); }
+            }
+        }
+    }
+
+    fn string(&mut self, start: usize) -> Result<Value, ReadError> {
+        let mut result = String::new();
+
+        loop {
+            let (at, c) = self.chars.next().ok_or(ReadError::UnterminatedString(start))?;
+            match c {
+                '"' => { return Ok(Value::Str(result)); },
+                '\\' => {
+                    let (_, escaped) = self.chars.next().ok_or(ReadError::UnterminatedString(start))?;
+                    match escaped {
+                        '"' => { result.push('"'); },
+                        '\\' => { result.push('\\'); },
+                        'n' => { result.push('\n'); },
+                        't' => { result.push('\t'); },
+                        _ => { return Err(ReadError::InvalidEscape(at)); }
+                    }
+                },
+                _ => { result.push(c); }
+            }
+        }
+    }
+
+    fn token(&mut self) -> String {
+        let mut token = String::new();
+        while let Some((_, c)) = self.chars.peek() {
+            if delimiter(*c) { break; }
+            token.push(*c);
+            self.chars.next();
+        }
+        return token;
+    }
+}
@@ -0,0 +1,22 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use crate::repl::error::ReplError;
+
+// module
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::value::Value;
+
+
+pub fn repl_error(error: &ReplError) {
+    emit(Value::form("error", vec![
+        ("kind", Value::quoted(error.kind())),
+        ("message", Value::string(error.to_string().as_str())),
+    ]).to_string());
+}
+
+pub fn repl_end(mission_day: u16) {
+    emit(format!("(repl-end :mission-day {mission_day})"));
+}
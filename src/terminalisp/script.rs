@@ -0,0 +1,20 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// module
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+
+
+pub fn script_command(line: usize, command: &str) {
+    emit(format!("(script-command :line {} {})", line, quoted(command)));
+}
+
+pub fn script_error(line: usize, error: String) {
+    emit(format!("(script-error :line {} {})", line, quoted(error.as_str())));
+}
+
+pub fn script_end(line: usize) {
+    emit(format!("(script-end :line {line})"));
+}
@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::Write;
+use std::mem;
+use std::path::Path;
+use std::sync::{Arc, Mutex, OnceLock};
+
+// module
+use crate::terminalisp::format::{OutputFormat, render};
+
+
+pub trait TerminalispSink: Send {
+    fn emit(&mut self, form: &str);
+}
+
+static SINK: OnceLock<Mutex<Box<dyn TerminalispSink>>> = OnceLock::new();
+
+thread_local! {
+    static THREAD_SINK: RefCell<Option<Box<dyn TerminalispSink>>> = const { RefCell::new(None) };
+}
+
+fn sink() -> &'static Mutex<Box<dyn TerminalispSink>> {
+    SINK.get_or_init(|| Mutex::new(Box::new(StdoutSink::new())))
+}
+
+// Every emitter in `terminalisp` writes through here. The sink is process-wide, so the
+// beacon thread and embedder threads share it, and defaults to stdout; `set_sink` returns
+// the previous one so it can be restored.
+pub fn emit(form: String) {
+    let form = form.trim_end_matches('\n');
+    let scoped = THREAD_SINK.with(|current| match current.borrow_mut().as_mut() {
+        Some(sink) => { sink.emit(form); true },
+        None => false,
+    });
+    if !scoped {
+        sink().lock().unwrap().emit(form);
+    }
+}
+
+pub fn set_sink(sink: Box<dyn TerminalispSink>) -> Box<dyn TerminalispSink> {
+    mem::replace(&mut *self::sink().lock().unwrap(), sink)
+}
+
+// Sends what `f` emits on the calling thread to `sink` instead, without touching other
+// threads; tests capture output this way while running in parallel.
+pub fn with_sink<T, F: FnOnce() -> T>(sink: Box<dyn TerminalispSink>, f: F) -> T {
+    let _restore = RestoreThreadSink(THREAD_SINK.with(|current| current.replace(Some(sink))));
+    return f();
+}
+
+// puts the previous thread sink back, also when `f` panics
+struct RestoreThreadSink(Option<Box<dyn TerminalispSink>>);
+
+impl Drop for RestoreThreadSink {
+    fn drop(&mut self) {
+        let previous = self.0.take();
+        THREAD_SINK.with(|current| current.replace(previous));
+    }
+}
+
+/* Stdout ======================================================================================= */
+
+#[derive(Default)]
+pub struct StdoutSink {}
+
+impl StdoutSink {
+    pub fn new() -> Self {
+        StdoutSink {}
+    }
+}
+
+impl TerminalispSink for StdoutSink {
+    fn emit(&mut self, form: &str) {
+        println!("{form}");
+    }
+}
+
+/* Buffer ======================================================================================= */
+
+// Clones share the buffer, so one handle can be installed and another read back.
+#[derive(Clone, Default)]
+pub struct BufferSink {
+    forms: Arc<Mutex<Vec<String>>>,
+}
+
+impl BufferSink {
+    pub fn new() -> Self {
+        BufferSink::default()
+    }
+
+    pub fn forms(&self) -> Vec<String> {
+        self.forms.lock().unwrap().clone()
+    }
+
+    pub fn take(&self) -> Vec<String> {
+        mem::take(&mut *self.forms.lock().unwrap())
+    }
+
+    pub fn contents(&self) -> String {
+        self.forms
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|form| format!("{form}\n"))
+            .collect()
+    }
+}
+
+impl TerminalispSink for BufferSink {
+    fn emit(&mut self, form: &str) {
+        self.forms.lock().unwrap().push(form.to_string());
+    }
+}
+
+/* File ========================================================================================= */
+
+pub struct FileSink {
+    file: File,
+}
+
+impl FileSink {
+    pub fn create(path: &Path) -> Result<Self, String> {
+        File::create(path)
+            .map(|file| FileSink { file })
+            .map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+impl TerminalispSink for FileSink {
+    fn emit(&mut self, form: &str) {
+        // a sink has nowhere to report its own failure, a lost line beats a crashed station
+        let _ = writeln!(self.file, "{form}").and_then(|_| self.file.flush());
+    }
+}
+
+/* Multiplex ==================================================================================== */
+
+#[derive(Default)]
+pub struct MultiSink {
+    sinks: Vec<Box<dyn TerminalispSink>>,
+}
+
+impl MultiSink {
+    pub fn new(sinks: Vec<Box<dyn TerminalispSink>>) -> Self {
+        MultiSink { sinks }
+    }
+
+    pub fn add(&mut self, sink: Box<dyn TerminalispSink>) {
+        self.sinks.push(sink);
+    }
+}
+
+impl TerminalispSink for MultiSink {
+    fn emit(&mut self, form: &str) {
+        for sink in self.sinks.iter_mut() {
+            sink.emit(form);
+        }
+    }
+}
+
+/* Format ======================================================================================= */
+
+// Re-renders every form for machine readers before passing it on.
+pub struct FormatSink {
+    format: OutputFormat,
+    sink: Box<dyn TerminalispSink>,
+}
+
+impl FormatSink {
+    pub fn new(format: OutputFormat, sink: Box<dyn TerminalispSink>) -> Self {
+        FormatSink { format, sink }
+    }
+}
+
+impl TerminalispSink for FormatSink {
+    fn emit(&mut self, form: &str) {
+        let rendered = render(form, self.format);
+        self.sink.emit(rendered.trim_end_matches('\n'));
+    }
+}
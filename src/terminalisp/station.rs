@@ -4,62 +4,55 @@
 
 // module
 use crate::terminalisp::symbols;
+use crate::terminalisp::sink::emit;
+use crate::terminalisp::escape::quoted;
+use crate::terminalisp::pretty::pretty;
+use crate::terminalisp::value::Value;
 
 
-pub fn status(header: String,
-              show_fields: bool,
-              keys: Vec<String>,
-              values: Vec<String>,
-              show_inner: bool,
-              inner_key: String,
-              inner_values: Vec<String>,
-              indent: u8) -> String {
+// the column width status trees are laid out for
+pub const STATUS_WIDTH: usize = 80;
 
-    let mut result: String = String::new();
-
-    for _ in 1..=indent { result.push_str("    ") }
-    result.push_str(format!("({}", header).as_str());
-    if show_inner { result.push('\n'); }
+pub fn status(value: &Value) {
+    emit(pretty(value, STATUS_WIDTH));
+}
 
-    if show_fields {
-        for tuple in keys.iter().zip(values) {
-            if show_inner { for _ in 1..=indent+1 { result.push_str("    ") } }
-            if !show_inner { result.push(' '); }
-            result.push_str(format!("{} {}", tuple.0, tuple.1).as_str());
-            if show_inner { result.push('\n'); }
-        }
-    }
+pub fn sections_ok() {
+    emit(format!("(sections {})", symbols::OK));
+}
 
-    if show_inner {
-        for _ in 1..=indent+1 { result.push_str("    ") }
-        result.push_str(format!("{} (\n", inner_key).as_str());
+pub fn section_failure(name: String) {
+    emit(format!("(section-failure {})", quoted(name.as_str())));
+}
 
-        for inner_value in inner_values.iter() {
-            result.push_str(inner_value.as_str());
-        }
+pub fn module_repaired(path: String) {
+    emit(format!("(module-repaired {})", quoted(path.as_str())));
+}
 
-        for _ in 1..=indent+1 { result.push_str("    ") }
-        result.push_str(")\n");
-    }
+pub fn until_final_transmission(count: u16) {
+    emit(format!("(until-final-transmission {count})"));
+}
 
-    if show_inner { for _ in 1..=indent { result.push_str("    ") } }
-    result.push_str(")\n");
+pub fn end_transmission() {
+    emit(String::from("(end-transmission)"));
+}
 
-    return result;
+pub fn station_seed(seed: u64) {
+    emit(format!("(station-seed {seed})"));
 }
 
-pub fn sections_ok() {
-    println!("(sections {})", symbols::OK);
+pub fn station_file_saved() {
+    emit(format!("(station-file {})", symbols::SAVED));
 }
 
-pub fn section_failure(name: String) {
-    println!("(section-failure \"{name}\")");
+pub fn station_file_loaded() {
+    emit(format!("(station-file {})", symbols::LOADED));
 }
 
-pub fn until_final_transmission(count: u16) {
-    println!("(until-final-transmission {count})");
+pub fn station_file_error(error: String) {
+    emit(format!("(station-file {} {})", symbols::ERROR, quoted(error.as_str())));
 }
 
-pub fn end_transmission() {
-    println!("(end-transmission)");
+pub fn argument_error(error: String) {
+    emit(format!("(argument-error {} {})", symbols::ERROR, quoted(error.as_str())));
 }
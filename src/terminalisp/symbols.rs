@@ -6,8 +6,10 @@
 pub const ERROR: &str = "'error";
 pub const INACTIVE: &str = "'inactive";
 pub const INVALID: &str = "'invalid";
+pub const LOADED: &str = "'loaded";
 pub const OK: &str = "'ok";
 pub const SAVED: &str = "'saved";
+pub const TORN_TAIL: &str = "'torn-tail";
 
 // selector
 pub const NONE: &str = "'none";
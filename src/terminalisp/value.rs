@@ -0,0 +1,96 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fmt;
+
+// module
+use crate::terminalisp::escape::quoted;
+
+
+// Keywords and quoted symbols are stored without their ':' and '\'' prefix.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Value {
+    Symbol(String),
+    Keyword(String),
+    Str(String),
+    // wide enough for every u64 and i64 the crate prints
+    Integer(i128),
+    List(Vec<Value>),
+    Quoted(String),
+}
+
+impl Value {
+    pub fn symbol(name: &str) -> Self { Value::Symbol(name.to_string()) }
+    pub fn keyword(name: &str) -> Self { Value::Keyword(name.to_string()) }
+    pub fn string(value: &str) -> Self { Value::Str(value.to_string()) }
+    pub fn quoted(name: &str) -> Self { Value::Quoted(name.to_string()) }
+    pub fn integer<T: Into<i128>>(value: T) -> Self { Value::Integer(value.into()) }
+
+    // `(head :key value ...)`, the shape of every form the crate emits
+    pub fn form(head: &str, properties: Vec<(&str, Value)>) -> Self {
+        let mut items = vec![Value::symbol(head)];
+        for (key, value) in properties {
+            items.push(Value::keyword(key));
+            items.push(value);
+        }
+        return Value::List(items);
+    }
+
+    pub fn as_list(&self) -> Option<&[Value]> {
+        match self {
+            Value::List(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::Str(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    pub fn as_integer(&self) -> Option<i128> {
+        match self {
+            Value::Integer(v) => Some(*v),
+            _ => None,
+        }
+    }
+
+    // the symbol a list starts with
+    pub fn head(&self) -> Option<&str> {
+        match self.as_list()?.first()? {
+            Value::Symbol(v) => Some(v),
+            _ => None,
+        }
+    }
+
+    // the value after `:key` in a list
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        let items = self.as_list()?;
+        let index = items.iter().position(|item| matches!(item, Value::Keyword(k) if k == key))?;
+        return items.get(index + 1);
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Symbol(v) => write!(f, "{v}"),
+            Value::Keyword(v) => write!(f, ":{v}"),
+            Value::Str(v) => write!(f, "{}", quoted(v)),
+            Value::Integer(v) => write!(f, "{v}"),
+            Value::Quoted(v) => write!(f, "'{v}"),
+            Value::List(items) => {
+                write!(f, "(")?;
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 { write!(f, " ")?; }
+                    write!(f, "{item}")?;
+                }
+                write!(f, ")")
+            },
+        }
+    }
+}
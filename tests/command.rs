@@ -0,0 +1,216 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::SocketAddr;
+use std::sync::{mpsc, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::ModuleCounts;
+use found_terminal::station::events::SystemEvent;
+use found_terminal::packets::command::{Command, CommandPacket, COMMAND_OP_CODES, PATH_SIZE_MAX};
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::ack::{AckPacket, AckStatus};
+use found_terminal::protocol::beacon::StationBeacon;
+use found_terminal::protocol::command::{sequence_now, CommandInbox, CommandSender, COMMAND_WINDOW};
+use found_terminal::protocol::error::DecodeError;
+use found_terminal::protocol::op_codes::OP_PING;
+use found_terminal::protocol::port_address::PortAddressRange;
+use found_terminal::protocol::udp;
+
+
+const KEY: &[u8] = b"shared mission key";
+
+fn source() -> SocketAddr {
+    "127.0.0.1:9".parse().unwrap()
+}
+
+fn inbox() -> CommandInbox {
+    let (_, receiver) = mpsc::channel();
+    return CommandInbox::new(receiver, udp::bind("127.0.0.1:0").unwrap(), KEY.to_vec());
+}
+
+// sequences count from one clock reading, so they keep their order and stay in the window
+fn packet(sequence: u64, command: Command) -> CommandPacket {
+    static START: OnceLock<u64> = OnceLock::new();
+    let sequence = START.get_or_init(sequence_now) + sequence;
+    CommandPacket { sequence, target_range: PortAddressRange::all(), command }
+}
+
+fn broken_station(seed: u64) -> (Station, String) {
+    let mut station = Station::from_seed(seed);
+    let path = station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2)
+        .expect("an installed module");
+    station.break_path(&path).unwrap();
+    station.take_events();
+    return (station, path);
+}
+
+#[test]
+fn command_packets_round_trip() {
+    for command in [Command::Repair(String::from("power/fusion/fusion-reactor")),
+                    Command::PowerDown, Command::NewDay, Command::StatusRequest] {
+        let packet = packet(77, command);
+        assert_eq!(CommandPacket::decode(&packet.encode(KEY).unwrap(), KEY), Ok(packet));
+    }
+}
+
+#[test]
+fn command_packets_need_the_key() {
+    let bytes = packet(1, Command::PowerDown).encode(KEY).unwrap();
+    assert!(CommandPacket::decode(&bytes, b"another key").is_err());
+
+    let mut tampered = packet(1, Command::Repair(String::from("comms/antenna/antenna"))).encode(KEY).unwrap();
+    tampered[30] ^= 0x01;
+    assert!(CommandPacket::decode(&tampered, KEY).is_err());
+}
+
+#[test]
+fn overlong_paths_are_refused() {
+    let longest = "a".repeat(PATH_SIZE_MAX);
+    let packet_longest = packet(1, Command::Repair(longest.clone()));
+    assert_eq!(CommandPacket::decode(&packet_longest.encode(KEY).unwrap(), KEY), Ok(packet_longest));
+
+    // one more byte, and a multi-byte character straddling the limit
+    for path in [format!("{longest}a"), format!("{}é", "a".repeat(PATH_SIZE_MAX - 1))] {
+        assert!(packet(1, Command::Repair(path.clone())).encode(KEY).is_err());
+        assert!(Command::parse(format!("repair {path}").as_str()).is_err());
+    }
+}
+
+#[test]
+fn other_packets_are_not_commands() {
+    let error = CommandPacket::decode(&PingPacket::new().encode(), KEY).unwrap_err();
+    assert_eq!(error, DecodeError::UnexpectedOpCodes { found: OP_PING, expected: &COMMAND_OP_CODES });
+    assert_eq!(error.to_string(), "unexpected op code 0x1000, expected one of 0x3000, 0x3001, 0x3002, 0x3003");
+}
+
+#[test]
+fn parses_commands() {
+    assert_eq!(Command::parse("repair comms/antenna/antenna"),
+               Ok(Command::Repair(String::from("comms/antenna/antenna"))));
+    assert_eq!(Command::parse("new-day"), Ok(Command::NewDay));
+    assert!(Command::parse("repair").is_err());
+    assert!(Command::parse("self-destruct").is_err());
+}
+
+#[test]
+fn acks_round_trip() {
+    let ack = AckPacket { sequence: 5, status: AckStatus::Failed, message: String::from("no such module") };
+
+    assert_eq!(AckPacket::decode(&ack.encode(KEY), KEY), Ok(ack.clone()));
+    assert!(AckPacket::decode(&ack.encode(KEY), b"another key").is_err());
+}
+
+#[test]
+fn repair_command_goes_through_repair_path() {
+    let (mut station, path) = broken_station(6);
+    let mut inbox = inbox();
+
+    let ack = inbox.handle(&mut station, &packet(1, Command::Repair(path)).encode(KEY).unwrap(), source()).unwrap();
+
+    assert_eq!(ack.status, AckStatus::Applied);
+    assert_eq!(station.active_modules(), station.total_modules());
+    assert_eq!(station.take_events(), vec![SystemEvent::ModuleRepaired(ack.message)]);
+}
+
+#[test]
+fn failed_commands_are_acknowledged_as_failed() {
+    let mut station = Station::from_seed(6);
+    let mut inbox = inbox();
+    let command = Command::Repair(String::from("power/warp/warp-core"));
+
+    let ack = inbox.handle(&mut station, &packet(1, command).encode(KEY).unwrap(), source()).unwrap();
+
+    assert_eq!(ack.status, AckStatus::Failed);
+}
+
+#[test]
+fn replayed_and_unauthenticated_commands_are_rejected() {
+    let mut station = Station::from_seed(6);
+    let mut inbox = inbox();
+    let bytes = packet(10, Command::NewDay).encode(KEY).unwrap();
+
+    assert_eq!(inbox.handle(&mut station, &bytes, source()).unwrap().status, AckStatus::Applied);
+    assert_eq!(station.mission_day, 1);
+
+    assert_eq!(inbox.handle(&mut station, &bytes, source()).unwrap().status, AckStatus::Rejected);
+    let older = packet(9, Command::NewDay).encode(KEY).unwrap();
+    assert_eq!(inbox.handle(&mut station, &older, source()).unwrap().status, AckStatus::Rejected);
+
+    let forged = packet(11, Command::NewDay).encode(b"guessed key").unwrap();
+    assert_eq!(inbox.handle(&mut station, &forged, source()), None);
+    assert_eq!(station.mission_day, 1);
+}
+
+#[test]
+fn replays_are_rejected_after_a_restart() {
+    let mut station = Station::from_seed(6);
+    let bytes = packet(20, Command::NewDay).encode(KEY).unwrap();
+    assert_eq!(inbox().handle(&mut station, &bytes, source()).unwrap().status, AckStatus::Applied);
+
+    // a restarted station comes back from its save with a new inbox
+    let mut restarted = Station::from_save(&station.to_save()).unwrap();
+    let ack = inbox().handle(&mut restarted, &bytes, source()).unwrap();
+    assert_eq!(ack.status, AckStatus::Rejected);
+    assert!(ack.message.starts_with("replayed sequence"), "{}", ack.message);
+    assert_eq!(restarted.mission_day, 1);
+}
+
+#[test]
+fn commands_outside_the_window_are_rejected() {
+    let window = COMMAND_WINDOW.as_micros() as u64;
+
+    // captured long ago for another station, this one has never seen a command
+    for sequence in [sequence_now() - 2 * window, sequence_now() + 2 * window] {
+        let mut station = Station::from_seed(6);
+        let old = CommandPacket { sequence, target_range: PortAddressRange::all(), command: Command::PowerDown };
+        let ack = inbox().handle(&mut station, &old.encode(KEY).unwrap(), source()).unwrap();
+
+        assert_eq!(ack.status, AckStatus::Rejected);
+        assert!(ack.message.ends_with("outside the command window"), "{}", ack.message);
+        assert_eq!(station.active_modules(), station.total_modules());
+        assert_eq!(station.last_command_sequence(), None);
+    }
+}
+
+#[test]
+fn commands_for_other_port_addresses_are_ignored() {
+    let mut station = Station::from_seed(6);
+    station.set_port_address(10).unwrap();
+    let mut inbox = inbox();
+    let mut packet = packet(1, Command::PowerDown);
+    packet.target_range = PortAddressRange::new(20, 30).unwrap();
+
+    assert_eq!(inbox.handle(&mut station, &packet.encode(KEY).unwrap(), source()), None);
+    assert_eq!(station.active_modules(), station.total_modules());
+}
+
+#[test]
+fn mission_control_commands_a_station_over_udp() {
+    let (mut station, path) = broken_station(6);
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+    let mut inbox = beacon.command_inbox(KEY.to_vec()).unwrap();
+    station.set_telemetry(Box::new(beacon));
+
+    let mission = thread::spawn(move || {
+        let mut sender = CommandSender::new("127.0.0.1:0", KEY.to_vec()).unwrap();
+        sender.send(address, PortAddressRange::all(), Command::Repair(path), Duration::from_secs(5))
+    });
+
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while inbox.process(&mut station).is_empty() && Instant::now() < deadline {
+        thread::sleep(Duration::from_millis(10));
+    }
+
+    let ack = mission.join().unwrap().unwrap();
+    assert_eq!(ack.status, AckStatus::Applied);
+    assert_eq!(station.active_modules(), station.total_modules());
+}
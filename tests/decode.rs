@@ -0,0 +1,242 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// dependencies
+use proptest::prelude::*;
+
+// project
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::ping_reply::PingReply;
+use found_terminal::packets::failure::FailurePacket;
+use found_terminal::packets::diagnostic::DiagnosticPacket;
+use found_terminal::packets::command::{Command, CommandPacket};
+use found_terminal::packets::ack::{AckPacket, AckStatus};
+use found_terminal::packets::journal_chunk::JournalChunkPacket;
+use found_terminal::packets::journal_resend::JournalResendPacket;
+use found_terminal::protocol::capture::read_capture;
+use found_terminal::protocol::error::DecodeError;
+use found_terminal::protocol::op_codes::{OP_PING, OP_PING_REPLY};
+use found_terminal::protocol::port_address::PortAddressRange;
+
+
+const KEY: &[u8] = b"shared mission key";
+
+fn ping() -> impl Strategy<Value = PingPacket> {
+    (any::<u8>(), any::<u8>(), any::<u16>(), any::<u16>()).prop_map(|(flags, diag_priority, bottom, top)| {
+        let mut ping = PingPacket::new();
+        ping.flags = flags;
+        ping.diag_priority = diag_priority;
+        ping.set_target_range(PortAddressRange { bottom, top });
+        return ping;
+    })
+}
+
+// names are null-padded, so they can't hold a null and keep the last byte free
+fn ping_reply() -> impl Strategy<Value = PingReply> {
+    ("[^\u{0}]{0,7}", any::<u8>(), any::<u16>(), any::<u16>(), any::<u16>(), any::<u16>())
+        .prop_map(|(name, version, mission_day, active_modules, total_modules, port_address)| PingReply {
+            name, version, mission_day, active_modules, total_modules, port_address,
+        })
+}
+
+fn command() -> impl Strategy<Value = Command> {
+    prop_oneof![
+        "[a-z/-]{0,64}".prop_map(Command::Repair),
+        Just(Command::PowerDown),
+        Just(Command::NewDay),
+        Just(Command::StatusRequest),
+    ]
+}
+
+fn ack_status() -> impl Strategy<Value = AckStatus> {
+    prop_oneof![Just(AckStatus::Applied), Just(AckStatus::Failed), Just(AckStatus::Rejected)]
+}
+
+// section header and an Ethernet interface, the same seed the dissect fuzz target starts from
+const PCAPNG_SEED: [u8; 48] = [
+    0x0a, 0x0d, 0x0d, 0x0a, 28, 0, 0, 0, 0x4d, 0x3c, 0x2b, 0x1a, 1, 0, 0, 0,
+    0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 28, 0, 0, 0,
+    1, 0, 0, 0, 20, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0,
+];
+
+// pcap global header with an Ethernet link type
+fn pcap_header() -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![];
+    bytes.extend_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    bytes.extend_from_slice(&[2, 0, 4, 0]);
+    bytes.extend_from_slice(&[0; 8]);
+    bytes.extend_from_slice(&65535u32.to_le_bytes());
+    bytes.extend_from_slice(&1u32.to_le_bytes());
+    return bytes;
+}
+
+// Packet blocks with a claimed length that need not match the body, down to shorter than the fixed fields.
+fn pcapng_block() -> impl Strategy<Value = Vec<u8>> {
+    (prop_oneof![Just(1u32), Just(3u32), Just(6u32), any::<u32>()], 0u32..64,
+     proptest::collection::vec(any::<u8>(), 0..64))
+        .prop_map(|(block_type, length, body)| {
+            let length = length * 4;
+            let mut block: Vec<u8> = vec![];
+            block.extend_from_slice(&block_type.to_le_bytes());
+            block.extend_from_slice(&length.to_le_bytes());
+            block.extend(body);
+            block.extend_from_slice(&length.to_le_bytes());
+            return block;
+        })
+}
+
+fn pcap_record() -> impl Strategy<Value = Vec<u8>> {
+    (any::<u16>(), proptest::collection::vec(any::<u8>(), 0..64)).prop_map(|(captured, data)| {
+        let mut record: Vec<u8> = vec![0; 8];
+        record.extend_from_slice(&(captured as u32).to_le_bytes());
+        record.extend_from_slice(&(data.len() as u32).to_le_bytes());
+        record.extend(data);
+        return record;
+    })
+}
+
+fn decode_all(bytes: &[u8]) {
+    let _ = PingPacket::decode(bytes);
+    let _ = PingReply::decode(bytes);
+    let _ = FailurePacket::decode(bytes);
+    let _ = DiagnosticPacket::decode(bytes);
+    let _ = CommandPacket::decode(bytes, KEY);
+    let _ = CommandPacket::decode_unverified(bytes);
+    let _ = AckPacket::decode(bytes, KEY);
+    let _ = AckPacket::decode_unverified(bytes);
+    let _ = JournalChunkPacket::decode(bytes);
+    let _ = JournalResendPacket::decode(bytes);
+}
+
+proptest! {
+    #[test]
+    fn ping_encode_decode_is_identity(ping in ping()) {
+        prop_assert_eq!(PingPacket::decode(&ping.encode()), Ok(ping));
+    }
+
+    #[test]
+    fn ping_reply_encode_decode_is_identity(reply in ping_reply()) {
+        prop_assert_eq!(PingReply::decode(&reply.encode()), Ok(reply));
+    }
+
+    #[test]
+    fn failure_encode_decode_is_identity(station in ping_reply(), port_address in any::<u16>(),
+                                         path in "[a-z/-]{0,255}") {
+        let packet = FailurePacket { station, port_address, path };
+        prop_assert_eq!(FailurePacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn diagnostic_encode_decode_is_identity(priority in any::<u8>(), port_address in any::<u16>(),
+                                            message in ".{0,128}") {
+        let packet = DiagnosticPacket { priority, port_address, message };
+        prop_assert_eq!(DiagnosticPacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn command_encode_decode_is_identity(sequence in any::<u64>(), bottom in any::<u16>(),
+                                         top in any::<u16>(), command in command()) {
+        let packet = CommandPacket { sequence, target_range: PortAddressRange { bottom, top }, command };
+        prop_assert_eq!(CommandPacket::decode(&packet.encode(KEY).unwrap(), KEY), Ok(packet));
+    }
+
+    #[test]
+    fn ack_encode_decode_is_identity(sequence in any::<u64>(), status in ack_status(),
+                                     message in ".{0,128}") {
+        let packet = AckPacket { sequence, status, message };
+        prop_assert_eq!(AckPacket::decode(&packet.encode(KEY), KEY), Ok(packet));
+    }
+
+    #[test]
+    fn journal_chunk_encode_decode_is_identity(transfer in any::<u32>(), (sequence, total) in (1u16..=u16::MAX)
+                                                   .prop_flat_map(|total| (0..total, Just(total))),
+                                               payload in proptest::collection::vec(any::<u8>(), 0..1024)) {
+        let packet = JournalChunkPacket { transfer, sequence, total, payload };
+        prop_assert_eq!(JournalChunkPacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn journal_resend_encode_decode_is_identity(transfer in any::<u32>(),
+                                                missing in proptest::collection::vec(any::<u16>(), 0..256)) {
+        let packet = JournalResendPacket { transfer, missing };
+        prop_assert_eq!(JournalResendPacket::decode(&packet.encode()), Ok(packet));
+    }
+
+    #[test]
+    fn decoders_never_panic_on_noise(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        decode_all(&bytes);
+    }
+
+    // noise behind a valid header gets past the id and version checks
+    #[test]
+    fn decoders_never_panic_behind_a_header(op_code in prop_oneof![
+                                                Just(0x1000u16), Just(0x2100u16), Just(0x2200u16),
+                                                Just(0x2300u16), Just(0x2400u16), Just(0x2401u16),
+                                                Just(0x3000u16), Just(0x3003u16), Just(0x3100u16)],
+                                            body in proptest::collection::vec(any::<u8>(), 0..128)) {
+        let mut bytes = b"Station\0".to_vec();
+        bytes.extend_from_slice(&op_code.to_le_bytes());
+        bytes.extend_from_slice(&[0, 14]);
+        bytes.extend(body);
+        decode_all(&bytes);
+    }
+
+    #[test]
+    fn read_capture_never_panics_on_pcapng_blocks(blocks in proptest::collection::vec(pcapng_block(), 0..8),
+                                                  cut in any::<prop::sample::Index>()) {
+        let mut bytes = PCAPNG_SEED.to_vec();
+        bytes.extend(blocks.concat());
+        let _ = read_capture(&bytes);
+        let _ = read_capture(&bytes[..cut.index(bytes.len() + 1)]);
+    }
+
+    #[test]
+    fn read_capture_never_panics_on_pcap_records(records in proptest::collection::vec(pcap_record(), 0..8),
+                                                 cut in any::<prop::sample::Index>()) {
+        let mut bytes = pcap_header();
+        bytes.extend(records.concat());
+        let _ = read_capture(&bytes);
+        let _ = read_capture(&bytes[..cut.index(bytes.len() + 1)]);
+    }
+
+    #[test]
+    fn read_capture_never_panics_on_noise(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        let _ = read_capture(&bytes);
+        let _ = read_capture(&[&PCAPNG_SEED[..], &bytes].concat());
+    }
+
+    #[test]
+    fn truncation_is_a_short_buffer(reply in ping_reply(), cut in 0usize..53) {
+        let bytes = reply.encode();
+        let result = PingReply::decode(&bytes[..cut.min(bytes.len() - 1)]);
+        let short = matches!(result, Err(DecodeError::ShortBuffer { .. }));
+        prop_assert!(short, "{:?}", result);
+    }
+}
+
+#[test]
+fn capture_seed_is_a_valid_capture() {
+    assert_eq!(read_capture(&PCAPNG_SEED), Ok(vec![]));
+}
+
+#[test]
+fn decode_errors_are_typed() {
+    let bytes = PingPacket::new().encode();
+
+    assert_eq!(PingPacket::decode(b"Nope, not a station"), Err(DecodeError::BadMagic));
+    assert_eq!(PingPacket::decode(b""), Err(DecodeError::ShortBuffer { length: 0, expected: 12 }));
+    assert_eq!(PingReply::decode(&bytes),
+               Err(DecodeError::UnexpectedOpCode { found: OP_PING, expected: OP_PING_REPLY }));
+
+    let mut tampered = AckPacket { sequence: 1, status: AckStatus::Applied, message: String::new() }.encode(KEY);
+    tampered[20] ^= 0xff;
+    assert_eq!(AckPacket::decode(&tampered, KEY), Err(DecodeError::AuthenticationFailed));
+    assert_eq!(AckPacket::decode_unverified(&tampered),
+               Err(DecodeError::InvalidValue { field: "acknowledgement status", value: 0xff }));
+
+    let mut long = DiagnosticPacket { priority: 0, port_address: 0, message: String::from("x") }.encode();
+    long.push(b'y');
+    assert_eq!(DiagnosticPacket::decode(&long),
+               Err(DecodeError::LengthMismatch { field: "diagnostic message", length: 1 }));
+}
@@ -0,0 +1,90 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::time::Duration;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::diagnostic::DiagnosticPacket;
+use found_terminal::protocol::beacon::StationBeacon;
+use found_terminal::protocol::constants::{DP_LOW, DP_HIGH, FLAG_SEND_DIAGNOSTICS};
+use found_terminal::protocol::diagnostics::{parse_priority, priority_name};
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+
+
+fn listener(target: std::net::SocketAddr, flags: u8, diag_priority: u8) -> MissionControl {
+    let mut listener = MissionControl::new("127.0.0.1:0", vec![target]).unwrap();
+    let mut ping = PingPacket::new();
+    ping.flags = flags;
+    ping.diag_priority = diag_priority;
+    listener.set_ping(ping);
+
+    listener.ping_all().unwrap();
+    assert_eq!(listener.poll(Duration::from_millis(500)).unwrap(), vec![MissionUpdate::Station(target)]);
+
+    return listener;
+}
+
+fn diagnostics(listener: &mut MissionControl) -> Vec<DiagnosticPacket> {
+    listener.poll(Duration::from_millis(300))
+        .unwrap()
+        .into_iter()
+        .filter_map(|update| match update {
+            MissionUpdate::Diagnostic(_, packet) => Some(packet),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn diagnostic_packets_round_trip() {
+    let packet = DiagnosticPacket {
+        priority: DP_HIGH,
+        port_address: 42,
+        message: String::from("Module failure: Comms Category / Antenna Section / Antenna"),
+    };
+
+    assert_eq!(DiagnosticPacket::decode(&packet.encode()), Ok(packet));
+}
+
+#[test]
+fn parses_priority_names_and_numbers() {
+    assert_eq!(parse_priority("high"), Ok(DP_HIGH));
+    assert_eq!(parse_priority("7"), Ok(7));
+    assert!(parse_priority("urgent").is_err());
+    assert_eq!(priority_name(DP_LOW), Some("low"));
+    assert_eq!(priority_name(7), None);
+}
+
+#[test]
+fn diagnostics_honour_flags_and_priority() {
+    let mut station = Station::from_seed(8);
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+    station.set_telemetry(Box::new(beacon));
+
+    let mut everything = listener(address, FLAG_SEND_DIAGNOSTICS, DP_LOW);
+    let mut urgent = listener(address, FLAG_SEND_DIAGNOSTICS, DP_HIGH);
+    let mut silent = listener(address, 0, DP_LOW);
+
+    let path = station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2)
+        .unwrap();
+    station.break_path(&path).unwrap();
+    station.repair_path(&path).unwrap();
+
+    let received = diagnostics(&mut everything);
+    assert_eq!(received.len(), 2);
+    assert!(received[0].message.starts_with("Module failure: "));
+    assert!(received[1].message.starts_with("Module repaired: "));
+
+    let received = diagnostics(&mut urgent);
+    assert_eq!(received.len(), 1);
+    assert_eq!(received[0].priority, DP_HIGH);
+
+    assert!(diagnostics(&mut silent).is_empty());
+}
@@ -0,0 +1,93 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::thread;
+use std::time::Duration;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::{Name, ModuleCounts};
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::ping_reply::{PingReply, PING_REPLY_PACKET_SIZE};
+use found_terminal::protocol::discovery::{discover_at, answer_ping};
+use found_terminal::protocol::udp;
+
+
+fn reply(name: &str, port_address: u16) -> PingReply {
+    PingReply {
+        name: String::from(name),
+        version: 3,
+        mission_day: 412,
+        active_modules: 17,
+        total_modules: 40,
+        port_address,
+    }
+}
+
+#[test]
+fn ping_reply_round_trips() {
+    let reply = reply("Kepler", 0x0102);
+    let bytes = reply.encode();
+
+    assert_eq!(bytes.len(), PING_REPLY_PACKET_SIZE);
+    assert_eq!(&bytes[8..10], &[0x00, 0x21]);
+    assert_eq!(PingReply::decode(&bytes), Ok(reply));
+}
+
+#[test]
+fn ping_reply_is_not_a_ping() {
+    assert!(PingPacket::decode(&reply("Kepler", 0).encode()).is_err());
+    assert!(PingReply::decode(&PingPacket::new().encode()).is_err());
+}
+
+#[test]
+fn ping_reply_from_station() {
+    let mut station = Station::from_seed(9);
+    station.set_port_address(7).unwrap();
+    let reply = PingReply::from_station(&station);
+
+    assert_eq!(reply.name, station.name());
+    assert_eq!(reply.version, station.version);
+    assert_eq!(reply.active_modules, station.active_modules());
+    assert_eq!(reply.total_modules, station.total_modules());
+    assert_eq!(reply.port_address, 7);
+}
+
+#[test]
+fn discovers_stations_on_localhost() {
+    let mut targets = vec![];
+    let mut responders = vec![];
+
+    for (name, port_address) in [("Kepler", 1), ("Sagan", 2)] {
+        let socket = udp::bind("127.0.0.1:0").unwrap();
+        targets.push(socket.local_addr().unwrap());
+        let reply = reply(name, port_address);
+        responders.push(thread::spawn(move || {
+            answer_ping(&socket, &reply, Some(Duration::from_secs(5))).unwrap()
+        }));
+    }
+
+    let mut stations = discover_at(&targets, &PingPacket::new(), Duration::from_millis(500)).unwrap();
+    for responder in responders {
+        assert!(responder.join().unwrap());
+    }
+
+    stations.sort_by_key(|station| station.reply.port_address);
+    assert_eq!(stations.len(), 2);
+    assert_eq!(stations[0].address, targets[0]);
+    assert_eq!(stations[0].reply, reply("Kepler", 1));
+    assert_eq!(stations[1].address, targets[1]);
+    assert_eq!(stations[1].reply, reply("Sagan", 2));
+}
+
+#[test]
+fn discovery_times_out_without_stations() {
+    let silent = udp::bind("127.0.0.1:0").unwrap();
+    let target = silent.local_addr().unwrap();
+
+    let stations = discover_at(&[target], &PingPacket::new(), Duration::from_millis(100)).unwrap();
+
+    assert!(stations.is_empty());
+}
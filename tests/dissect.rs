@@ -0,0 +1,240 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::net::SocketAddr;
+
+// project
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::packets::diagnostic::DiagnosticPacket;
+use found_terminal::packets::command::{Command, CommandPacket};
+use found_terminal::protocol::capture::{read_capture, parse_hex};
+use found_terminal::protocol::dissect::{dissect, Fields, MacCheck, Problem};
+use found_terminal::protocol::op_codes::OP_PING;
+use found_terminal::protocol::port_address::PortAddressRange;
+use found_terminal::terminalisp::dissect as tl_dissect;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+
+
+const KEY: &[u8] = b"shared mission key";
+
+fn command() -> CommandPacket {
+    CommandPacket {
+        sequence: 7,
+        target_range: PortAddressRange::all(),
+        command: Command::Repair(String::from("power/nuclear/nuclear-reactor")),
+    }
+}
+
+// Ethernet, IPv4 and UDP headers around a payload, checksums left at zero.
+fn ethernet_frame(payload: &[u8]) -> Vec<u8> {
+    let mut frame: Vec<u8> = vec![0xff; 6];
+    frame.extend_from_slice(&[0x02, 0, 0, 0, 0, 1]);
+    frame.extend_from_slice(&0x0800u16.to_be_bytes());
+
+    let total = (20 + 8 + payload.len()) as u16;
+    frame.extend_from_slice(&[0x45, 0]);
+    frame.extend_from_slice(&total.to_be_bytes());
+    frame.extend_from_slice(&[0, 0, 0, 0, 64, 17, 0, 0]);
+    frame.extend_from_slice(&[127, 0, 0, 1]);
+    frame.extend_from_slice(&[127, 0, 0, 2]);
+
+    frame.extend_from_slice(&40000u16.to_be_bytes());
+    frame.extend_from_slice(&0x1936u16.to_be_bytes());
+    frame.extend_from_slice(&((8 + payload.len()) as u16).to_be_bytes());
+    frame.extend_from_slice(&[0, 0]);
+    frame.extend_from_slice(payload);
+
+    return frame;
+}
+
+fn pcap(frames: &[Vec<u8>]) -> Vec<u8> {
+    let mut bytes: Vec<u8> = vec![];
+    bytes.extend_from_slice(&0xa1b2c3d4u32.to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&4u16.to_le_bytes());
+    bytes.extend_from_slice(&[0; 8]);
+    bytes.extend_from_slice(&65535u32.to_le_bytes());
+    bytes.extend_from_slice(&1u32.to_le_bytes());
+
+    for frame in frames {
+        bytes.extend_from_slice(&[0; 8]);
+        bytes.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(frame);
+    }
+
+    return bytes;
+}
+
+fn pcapng_block(block_type: u32, body: &[u8]) -> Vec<u8> {
+    let mut padded = body.to_vec();
+    while !padded.len().is_multiple_of(4) {
+        padded.push(0);
+    }
+    let length = (padded.len() + 12) as u32;
+
+    let mut block: Vec<u8> = vec![];
+    block.extend_from_slice(&block_type.to_le_bytes());
+    block.extend_from_slice(&length.to_le_bytes());
+    block.extend_from_slice(&padded);
+    block.extend_from_slice(&length.to_le_bytes());
+
+    return block;
+}
+
+fn pcapng(frames: &[Vec<u8>]) -> Vec<u8> {
+    let mut section: Vec<u8> = vec![];
+    section.extend_from_slice(&0x1a2b3c4du32.to_le_bytes());
+    section.extend_from_slice(&1u16.to_le_bytes());
+    section.extend_from_slice(&0u16.to_le_bytes());
+    section.extend_from_slice(&(-1i64).to_le_bytes());
+
+    let mut interface: Vec<u8> = vec![];
+    interface.extend_from_slice(&1u16.to_le_bytes());
+    interface.extend_from_slice(&0u16.to_le_bytes());
+    interface.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut bytes = pcapng_block(0x0a0d0d0a, &section);
+    bytes.extend(pcapng_block(1, &interface));
+
+    for frame in frames {
+        let mut packet: Vec<u8> = vec![];
+        packet.extend_from_slice(&0u32.to_le_bytes());
+        packet.extend_from_slice(&[0; 8]);
+        packet.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        packet.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+        packet.extend_from_slice(frame);
+        bytes.extend(pcapng_block(6, &packet));
+    }
+
+    return bytes;
+}
+
+#[test]
+fn dissects_a_ping() {
+    let ping = PingPacket::new();
+    let dissection = dissect(&ping.encode(), None);
+
+    assert_eq!(dissection.op_code, Some(OP_PING));
+    assert_eq!(dissection.version, Some((ping.prot_ver_hi, ping.prot_ver_lo)));
+    assert_eq!(dissection.result, Ok(Fields::Ping(ping)));
+}
+
+#[test]
+fn flags_truncated_packets() {
+    let bytes = PingPacket::new().encode();
+
+    assert!(matches!(dissect(&bytes[..10], None).result, Err(Problem::Truncated(_))));
+    assert!(matches!(dissect(&bytes[..15], None).result, Err(Problem::Truncated(_))));
+    assert_eq!(dissect(b"Nope, not a station", None).result, Err(Problem::NotStationPacket));
+}
+
+#[test]
+fn flags_malformed_packets() {
+    let mut bytes = DiagnosticPacket {
+        priority: 0x80,
+        port_address: 3,
+        message: String::from("module failure"),
+    }.encode();
+    bytes.pop();
+
+    assert!(matches!(dissect(&bytes, None).result, Err(Problem::Malformed(_))));
+}
+
+#[test]
+fn reports_version_mismatch_and_unknown_op_codes() {
+    let mut bytes = PingPacket::new().encode();
+    bytes[11] += 1;
+
+    let dissection = dissect(&bytes, None);
+    assert_eq!(dissection.result, Err(Problem::VersionMismatch));
+    assert_eq!(dissection.version, Some((bytes[10], bytes[11])));
+
+    let mut bytes = PingPacket::new().encode();
+    bytes[8] = 0xee;
+    assert_eq!(dissect(&bytes, None).result, Err(Problem::UnknownOpCode));
+}
+
+#[test]
+fn checks_command_macs_only_with_a_key() {
+    let bytes = command().encode(KEY).unwrap();
+
+    assert_eq!(dissect(&bytes, None).result, Ok(Fields::Command(command(), MacCheck::Unchecked)));
+    assert_eq!(dissect(&bytes, Some(KEY)).result, Ok(Fields::Command(command(), MacCheck::Verified)));
+    assert!(matches!(dissect(&bytes, Some(b"wrong key")).result, Err(Problem::BadMac(_))));
+}
+
+#[test]
+fn parses_hex_dumps() {
+    assert_eq!(parse_hex("53 74:61-74").unwrap(), b"Stat".to_vec());
+    assert!(parse_hex("537").is_err());
+    assert!(parse_hex("5g").is_err());
+
+    let hex: String = PingPacket::new().encode().iter().map(|v| format!("{v:02x} ")).collect();
+    assert_eq!(parse_hex(hex.as_str()).unwrap(), PingPacket::new().encode());
+}
+
+#[test]
+fn reads_udp_payloads_from_pcap() {
+    let ping = PingPacket::new().encode();
+    let mut other = ethernet_frame(b"unrelated");
+    // not UDP
+    other[23] = 6;
+
+    let datagrams = read_capture(&pcap(&[other, ethernet_frame(&ping)])).unwrap();
+    assert_eq!(datagrams.len(), 1);
+    assert_eq!(datagrams[0].frame, 2);
+    assert_eq!(datagrams[0].source, "127.0.0.1:40000".parse::<SocketAddr>().unwrap());
+    assert_eq!(datagrams[0].destination, "127.0.0.2:6454".parse::<SocketAddr>().unwrap());
+    assert_eq!(datagrams[0].payload, ping);
+}
+
+#[test]
+fn reads_udp_payloads_from_pcapng() {
+    let ping = PingPacket::new().encode();
+    let bytes = command().encode(KEY).unwrap();
+
+    let datagrams = read_capture(&pcapng(&[ethernet_frame(&ping), ethernet_frame(&bytes)])).unwrap();
+    assert_eq!(datagrams.len(), 2);
+    assert_eq!(datagrams[0].payload, ping);
+    assert_eq!(datagrams[1].frame, 2);
+    assert_eq!(datagrams[1].payload, bytes);
+}
+
+#[test]
+fn rejects_damaged_captures() {
+    let mut bytes = pcap(&[ethernet_frame(&PingPacket::new().encode())]);
+    bytes.truncate(bytes.len() - 4);
+
+    assert!(read_capture(&bytes).is_err());
+    assert!(read_capture(b"not a capture").is_err());
+}
+
+#[test]
+fn rejects_packet_blocks_shorter_than_their_fields() {
+    // section header, interface description and a 12-byte simple packet block
+    let mut bytes = pcapng(&[]);
+    bytes.extend(pcapng_block(3, &[]));
+    assert_eq!(read_capture(&bytes), Err(String::from("invalid pcapng block length 12 at byte 48")));
+
+    let mut bytes = pcapng(&[]);
+    bytes.extend(pcapng_block(6, &[0; 16]));
+    assert!(read_capture(&bytes).is_err());
+}
+
+#[test]
+fn emits_packet_forms() {
+    let buffer = BufferSink::new();
+    with_sink(Box::new(buffer.clone()), || {
+        let mut bytes = PingPacket::new().encode();
+        tl_dissect::packet(1, None, &dissect(&bytes, None));
+        bytes.truncate(14);
+        tl_dissect::packet(2, None, &dissect(&bytes, None));
+    });
+
+    let forms = buffer.take();
+    assert!(forms[0].starts_with("(packet :index 1 :length 18 :op 'ping :op-code \"0x1000\""));
+    assert!(forms[1].contains("'error 'truncated :reason"));
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::thread;
+use std::time::{Duration, Instant};
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::journal::journal::Journal;
+use found_terminal::packets::journal_resend::JournalResendPacket;
+use found_terminal::packets::journal_chunk::JournalChunkPacket;
+use found_terminal::protocol::downlink::{split_journal, DownlinkReceiver, JournalDownlink, Reassembly};
+use found_terminal::protocol::downlink::{TRANSFERS_MAX, TRANSFER_CHUNKS_MAX};
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+use found_terminal::protocol::udp;
+
+
+fn station_with_comms() -> Station {
+    (0..)
+        .map(Station::from_seed)
+        .find(|station| station.downlink_available())
+        .unwrap()
+}
+
+fn journal(entries: usize) -> Journal {
+    let mut journal = Journal::new(String::from("STATION LOG"), String::from("Station \"Kepler\" v3"));
+    journal.day(1).unwrap();
+    for index in 0..entries {
+        journal.add_entry(format!("Entry {index}: the \"antenna\" still points at Earth.")).unwrap();
+    }
+    journal.add_system_entry(String::from("power/nuclear/nuclear-reactor failed")).unwrap();
+    return journal;
+}
+
+fn receive_journal(mission: &mut MissionControl) -> Option<Journal> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while Instant::now() < deadline {
+        for update in mission.poll(Duration::from_millis(100)).unwrap() {
+            if let MissionUpdate::Journal(_, journal) = update {
+                return Some(journal);
+            }
+        }
+    }
+    return None;
+}
+
+#[test]
+fn journal_lines_round_trip() {
+    let journal = journal(3);
+
+    assert_eq!(Journal::from_lines(journal.to_lines().unwrap().as_str()), Ok(journal));
+    assert!(Journal::from_lines("").is_err());
+}
+
+#[test]
+fn chunks_reassemble_in_any_order() {
+    let journal = journal(100);
+    let chunks = split_journal(&journal, 9).unwrap();
+    assert!(chunks.len() > 3);
+
+    let mut reassembly = Reassembly::new(9, chunks.len() as u16).unwrap();
+    for chunk in chunks.iter().skip(1).rev() {
+        reassembly.add(chunk).unwrap();
+    }
+    assert_eq!(reassembly.missing(), vec![0]);
+    assert!(reassembly.journal().is_err());
+
+    reassembly.add(&chunks[0]).unwrap();
+    assert!(reassembly.is_complete());
+    assert_eq!(reassembly.journal(), Ok(journal));
+}
+
+#[test]
+fn reassembly_rejects_foreign_chunks() {
+    let chunks = split_journal(&journal(1), 9).unwrap();
+    let mut reassembly = Reassembly::new(10, chunks.len() as u16).unwrap();
+
+    assert!(reassembly.add(&chunks[0]).is_err());
+}
+
+#[test]
+fn oversized_transfers_are_refused() {
+    assert!(Reassembly::new(1, 0).is_err());
+    assert!(Reassembly::new(1, TRANSFER_CHUNKS_MAX).is_ok());
+    assert!(Reassembly::new(1, TRANSFER_CHUNKS_MAX + 1).is_err());
+}
+
+#[test]
+fn pending_transfers_are_capped() {
+    let socket = udp::bind("127.0.0.1:0").unwrap();
+    let mut receiver = DownlinkReceiver::new();
+    let chunk = |transfer| JournalChunkPacket { transfer, sequence: 0, total: 2, payload: vec![b'{'] };
+    let source = |port| format!("127.0.0.1:{port}").parse().unwrap();
+
+    for port in 0..TRANSFERS_MAX as u16 {
+        assert_eq!(receiver.chunk(&socket, source(1000 + port), &chunk(1)), Ok(None));
+    }
+    assert!(receiver.chunk(&socket, source(2000), &chunk(1)).is_err());
+
+    // a station starting over replaces its own transfer
+    assert_eq!(receiver.chunk(&socket, source(1000), &chunk(2)), Ok(None));
+    assert!(receiver.chunk(&socket, source(2000), &chunk(1)).is_err());
+}
+
+#[test]
+fn downlink_needs_an_active_antenna_or_transponder() {
+    let mut station = station_with_comms();
+    for path in ["comms/antenna/antenna", "comms/transponder/transponder"] {
+        let _ = station.break_path(path);
+    }
+    assert!(!station.downlink_available());
+
+    let socket = udp::bind("127.0.0.1:0").unwrap();
+    assert!(JournalDownlink::new(&station, &journal(1), socket).is_err());
+}
+
+#[test]
+fn mission_control_receives_a_journal() {
+    let station = station_with_comms();
+    let journal = journal(40);
+
+    let mut mission = MissionControl::new("127.0.0.1:0", vec![]).unwrap();
+    let target = mission.local_addr().unwrap();
+    let downlink = JournalDownlink::new(&station, &journal, udp::bind("127.0.0.1:0").unwrap()).unwrap();
+
+    let sender = thread::spawn(move || downlink.send(target, Duration::from_secs(5)));
+
+    assert_eq!(receive_journal(&mut mission), Some(journal));
+    let report = sender.join().unwrap().unwrap();
+    assert!(report.confirmed);
+    assert_eq!(report.resent, 0);
+}
+
+#[test]
+fn missing_chunks_are_requested_again() {
+    let station = station_with_comms();
+    let journal = journal(100);
+
+    let mut mission = MissionControl::new("127.0.0.1:0", vec![]).unwrap();
+    let target = mission.local_addr().unwrap();
+    let downlink = JournalDownlink::new(&station, &journal, udp::bind("127.0.0.1:0").unwrap()).unwrap();
+    let total = downlink.chunks().len() as u16;
+
+    let sender = thread::spawn(move || {
+        // chunk 1 is lost on the way
+        for sequence in (0..total).filter(|sequence| *sequence != 1) {
+            downlink.send_chunk(target, sequence).unwrap();
+        }
+        downlink.serve(target, Duration::from_secs(5))
+    });
+
+    assert_eq!(receive_journal(&mut mission), Some(journal));
+    let report = sender.join().unwrap().unwrap();
+    assert!(report.confirmed);
+    assert_eq!(report.resent, 1);
+}
+
+#[test]
+fn resend_request_marks_completion() {
+    assert!(JournalResendPacket { transfer: 1, missing: vec![] }.complete());
+    assert!(!JournalResendPacket { transfer: 1, missing: vec![2] }.complete());
+}
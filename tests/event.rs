@@ -0,0 +1,114 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use found_terminal::event::event::{Event, roll_events};
+use found_terminal::station::station::Station;
+use found_terminal::station::components::ModuleCounts;
+use found_terminal::station::events::SystemEvent;
+use found_terminal::station::rng::station_rng;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+
+
+#[test]
+fn power_surge_breaks_one_module() {
+    let mut station = Station::from_seed(11);
+    let active = station.active_modules();
+
+    station.apply_event(Event::PowerSurgeKillsAModule);
+
+    assert_eq!(station.active_modules(), active - 1);
+    let events = station.take_events();
+    assert_eq!(events[0], SystemEvent::StationEvent(Event::PowerSurgeKillsAModule));
+    assert!(matches!(events[1], SystemEvent::ModuleFailure(_)));
+}
+
+#[test]
+fn a_module_comes_back_restores_a_broken_module() {
+    let mut station = Station::from_seed(11);
+    station.apply_event(Event::PowerSurgeKillsAModule);
+    station.take_events();
+
+    station.apply_event(Event::AModuleComesBack);
+
+    assert_eq!(station.active_modules(), station.total_modules());
+    assert!(station.take_events()
+        .iter()
+        .any(|event| matches!(event, SystemEvent::ModuleRestored(_))));
+}
+
+#[test]
+fn total_power_failure_shuts_the_station_down() {
+    let mut station = Station::from_seed(11);
+
+    station.apply_event(Event::TotalPowerFailure);
+
+    assert_eq!(station.active_modules(), 0);
+    assert!(station.take_events().contains(&SystemEvent::Shutdown));
+}
+
+#[test]
+fn no_countdown_after_a_power_failure() {
+    // the first day of any mission that rolls a total power failure
+    let (mut station, events, forms) = (0..)
+        .find_map(|seed| {
+            let mut station = Station::from_seed(seed);
+            for _ in 0..10 {
+                let buffer = BufferSink::new();
+                with_sink(Box::new(buffer.clone()), || station.new_day());
+                let events = station.take_events();
+                if events.contains(&SystemEvent::StationEvent(Event::TotalPowerFailure)) {
+                    return Some((station, events, buffer.take()));
+                }
+            }
+            None
+        })
+        .unwrap();
+
+    assert_eq!(events.last(), Some(&SystemEvent::Shutdown));
+    assert!(!forms.iter().any(|form| form.starts_with("(until-final-transmission")), "{forms:?}");
+
+    station.new_day();
+    assert_eq!(station.take_events(), vec![SystemEvent::FinalTransmission]);
+    station.new_day();
+    assert!(station.take_events().is_empty());
+}
+
+#[test]
+fn rerolls_are_station_events() {
+    let mut station = Station::from_seed(11);
+
+    station.apply_event(Event::NullEvent);
+
+    assert_eq!(station.take_events(), vec![SystemEvent::StationEvent(Event::NullEvent)]);
+}
+
+#[test]
+fn message_events_queue_an_incoming_message() {
+    let mut station = Station::from_seed(11);
+    let active = station.active_modules();
+
+    station.apply_event(Event::MoonBaseSignal);
+
+    assert_eq!(station.active_modules(), active);
+    assert_eq!(station.take_events(),
+               vec![SystemEvent::IncomingMessage(String::from("Moon base signal!"))]);
+}
+
+#[test]
+fn rolls_are_reproducible_from_a_seed() {
+    let mut first = station_rng(5);
+    let mut second = station_rng(5);
+    let mut eventful_days = 0;
+
+    for _ in 0..100 {
+        let events = roll_events(&mut first);
+        assert_eq!(events, roll_events(&mut second));
+        if !events.is_empty() {
+            eventful_days += 1;
+        }
+    }
+
+    assert!(eventful_days > 0);
+}
@@ -0,0 +1,282 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// project
+use found_terminal::journal::journal::Journal;
+use found_terminal::journal::entry::{EntryKind, JournalEntry};
+use found_terminal::journal::export::ExportFormat;
+use found_terminal::terminalisp::reader::read;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+use found_terminal::terminalisp::value::Value;
+
+
+// one file per test, the tests run in parallel
+fn journal_path(name: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("found-terminal-{}-{}.jsonl", name, std::process::id()));
+    let _ = fs::remove_file(&path);
+    return path;
+}
+
+fn create(path: &Path) -> Journal {
+    let mut journal = Journal::create(path, String::from("STATION LOG"), String::from("Kepler")).unwrap();
+    journal.day(1).unwrap();
+    journal.add_entry(String::from("Solar panels deployed.")).unwrap();
+    journal.add_system_entry(String::from("power/solar/solar-panel failed")).unwrap();
+    return journal;
+}
+
+// entries with fixed timestamps, 2000-02-29 at noon and a minute later
+fn fixed_journal(bodies: &[(u16, &str, &str)]) -> Journal {
+    let mut lines = String::from("{\"header\":\"STATION LOG\",\"title\":\"Station \\\"Kepler\\\" v3\"}\n");
+    for (index, (mission_day, kind, body)) in bodies.iter().enumerate() {
+        let entry = JournalEntry {
+            mission_day: *mission_day,
+            timestamp: 951825600 + 60 * index as u64,
+            kind: EntryKind::from_str(kind).unwrap(),
+            body: body.to_string(),
+        };
+        lines += &serde_json::to_string(&entry).unwrap();
+        lines.push('\n');
+    }
+    return Journal::from_lines(lines.as_str()).unwrap();
+}
+
+fn mission_journal() -> Journal {
+    fixed_journal(&[
+        (1, "day-marker", "Mission Day 1"),
+        (1, "crew-log", "Solar panels deployed."),
+        (1, "system-event", "Module failure: Comms Category / Antenna Section / Antenna"),
+        (2, "day-marker", "Mission Day 2"),
+        (2, "crew-log", "Antenna back."),
+    ])
+}
+
+#[test]
+fn entries_filter_by_day_and_kind() {
+    let journal = mission_journal();
+
+    let day: Vec<&str> = journal.entries_for_day(1).iter().map(|entry| entry.body.as_str()).collect();
+    assert_eq!(day, vec!["Mission Day 1", "Solar panels deployed.",
+                         "Module failure: Comms Category / Antenna Section / Antenna"]);
+    assert_eq!(journal.entries_of_kind(EntryKind::CrewLog).len(), 2);
+    assert_eq!(journal.entries_of_kind(EntryKind::SystemEvent)[0].mission_day, 1);
+    assert!(journal.entries_for_day(3).is_empty());
+    assert_eq!(journal.mission_day(), 2);
+}
+
+#[test]
+fn entries_display_by_kind() {
+    let journal = mission_journal();
+    let shown: Vec<String> = journal.entries()[..3].iter().map(|entry| entry.to_string()).collect();
+
+    assert_eq!(shown, vec![
+        "Mission Day 1 (2000-02-29T12:00:00Z)",
+        "[2000-02-29T12:01:00Z] Solar panels deployed.",
+        "[2000-02-29T12:02:00Z] (system) Module failure: Comms Category / Antenna Section / Antenna",
+    ]);
+    assert_eq!(EntryKind::SystemEvent.to_string(), "system-event");
+    assert_eq!(serde_json::to_string(&EntryKind::DayMarker).unwrap(), "\"day-marker\"");
+}
+
+#[test]
+fn journal_days_are_marked_once() {
+    let mut journal = Journal::new(String::from("STATION LOG"), String::from("Kepler"));
+    journal.day(1).unwrap();
+    journal.add_entry(String::from("Morning.")).unwrap();
+    journal.day(1).unwrap();
+    journal.day(2).unwrap();
+    journal.add_system_entry(String::from("Antenna repaired")).unwrap();
+
+    let kinds: Vec<EntryKind> = journal.entries().iter().map(|entry| entry.kind).collect();
+    assert_eq!(kinds, vec![EntryKind::DayMarker, EntryKind::CrewLog, EntryKind::DayMarker, EntryKind::SystemEvent]);
+    assert_eq!(journal.entries()[3].mission_day, 2);
+}
+
+#[test]
+fn exports_markdown() {
+    let markdown = mission_journal().export(ExportFormat::Markdown);
+
+    assert_eq!(markdown, "# STATION LOG: Station \"Kepler\" v3\n\
+                          \n## Mission Day 1\n\n_2000-02-29T12:00:00Z_\n\
+                          \n**2000-02-29T12:01:00Z**\n\nSolar panels deployed.\n\
+                          \n> **2000-02-29T12:02:00Z** (system) Module failure: Comms Category / Antenna Section / Antenna\n\
+                          \n## Mission Day 2\n\n_2000-02-29T12:03:00Z_\n\
+                          \n**2000-02-29T12:04:00Z**\n\nAntenna back.\n");
+}
+
+#[test]
+fn markdown_bodies_cannot_add_structure() {
+    let journal = fixed_journal(&[
+        (1, "day-marker", "Mission Day 1"),
+        (1, "crew-log", "# not a heading"),
+        (1, "crew-log", "---"),
+        (1, "crew-log", "- not a list\n1. nor this\n\n    nor code\n\t- nor this\n"),
+        (1, "crew-log", "*plain* _text_ with <b>tags</b> & [links](x) | `ticks`"),
+        (1, "system-event", "first line\n## second line"),
+    ]);
+    let markdown = journal.to_markdown();
+    let lines: Vec<&str> = markdown.lines().collect();
+
+    assert_eq!(lines.iter().filter(|line| line.starts_with('#')).count(), 2, "{markdown}");
+    assert!(lines.contains(&"\\# not a heading"));
+    assert!(lines.contains(&"\\---"));
+    assert!(lines.contains(&"\\- not a list\\"));
+    assert!(lines.contains(&"1\\. nor this\\"));
+    assert!(lines.contains(&"&nbsp;\\"));
+    assert!(lines.contains(&"&nbsp;&nbsp;&nbsp;&nbsp;nor code\\"));
+    assert!(lines.contains(&"&nbsp;&nbsp;&nbsp;&nbsp;\\- nor this\\"));
+    assert!(lines.contains(&"&nbsp;"), "{markdown}");
+    assert!(lines.contains(&"\\*plain\\* \\_text\\_ with \\<b\\>tags\\</b\\> \\& \\[links\\](x) \\| \\`ticks\\`"));
+    assert!(lines.contains(&"> \\#\\# second line"), "{markdown}");
+}
+
+#[test]
+fn exports_json() {
+    let journal = mission_journal();
+    let json = journal.export(ExportFormat::Json);
+
+    let entries: Vec<JournalEntry> = serde_json::from_str(json.as_str()).unwrap();
+    assert_eq!(entries, journal.entries());
+    let value: serde_json::Value = serde_json::from_str(json.as_str()).unwrap();
+    assert_eq!(value[2]["kind"], "system-event");
+    assert_eq!(value[2]["mission_day"], 1);
+}
+
+#[test]
+fn exports_terminalisp() {
+    let journal = mission_journal();
+    let exported = read(journal.export(ExportFormat::Terminalisp).as_str()).unwrap();
+
+    assert_eq!(exported.head(), Some("journal"));
+    assert_eq!(exported.get("title").and_then(Value::as_str), Some("Station \"Kepler\" v3"));
+    let entries = exported.get("entries").and_then(Value::as_list).unwrap();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[2].to_string(),
+               "(entry :mission-day 1 :timestamp \"2000-02-29T12:02:00Z\" :kind 'system-event \
+                :body \"Module failure: Comms Category / Antenna Section / Antenna\")");
+}
+
+#[test]
+fn export_formats_parse() {
+    assert_eq!(ExportFormat::from_str("markdown"), Ok(ExportFormat::Markdown));
+    assert_eq!(ExportFormat::from_str("terminalisp"), Ok(ExportFormat::Terminalisp));
+    assert!(ExportFormat::from_str("pdf").is_err());
+}
+
+#[test]
+fn reopened_journal_keeps_appending() {
+    let path = journal_path("append");
+    let journal = create(&path);
+    drop(journal);
+
+    let mut reopened = Journal::open(&path).unwrap();
+    assert_eq!(reopened.entries().len(), 3);
+    assert_eq!(reopened.mission_day(), 1);
+    reopened.day(2).unwrap();
+    reopened.add_entry(String::from("Reactor back online.")).unwrap();
+    let expected = reopened.clone();
+    drop(reopened);
+
+    let reopened = Journal::open(&path).unwrap();
+    assert_eq!(reopened, expected);
+    assert_eq!(reopened.entries().len(), 5);
+    assert_eq!(reopened.mission_day(), 2);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn torn_tail_is_dropped_and_reported() {
+    let path = journal_path("torn");
+    let expected = create(&path).clone();
+
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(b"{\"mission_day\":1,\"timest").unwrap();
+    drop(file);
+
+    let buffer = BufferSink::new();
+    let reopened = with_sink(Box::new(buffer.clone()), || Journal::open(&path));
+
+    let mut reopened = reopened.unwrap();
+    assert_eq!(reopened, expected);
+    let forms = buffer.take();
+    assert_eq!(forms.len(), 1);
+    assert!(forms[0].starts_with("(journal-file 'torn-tail :line 5 :message "), "{}", forms[0]);
+
+    // the torn bytes are gone, so the next entry lands on a line of its own
+    reopened.add_entry(String::from("After the crash.")).unwrap();
+    drop(reopened);
+    let reopened = Journal::open(&path).unwrap();
+    assert_eq!(reopened.entries().len(), 4);
+    assert_eq!(reopened.entries()[3].body, "After the crash.");
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn missing_final_newline_is_repaired() {
+    let path = journal_path("newline");
+    let expected = create(&path).clone();
+
+    let content = fs::read_to_string(&path).unwrap();
+    fs::write(&path, content.trim_end()).unwrap();
+
+    let mut reopened = Journal::open(&path).unwrap();
+    assert_eq!(reopened, expected);
+    reopened.add_entry(String::from("Still here.")).unwrap();
+    drop(reopened);
+    assert_eq!(Journal::open(&path).unwrap().entries().len(), 4);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn corruption_before_the_last_line_is_rejected() {
+    let path = journal_path("corrupt");
+    drop(create(&path));
+
+    let content = fs::read_to_string(&path).unwrap();
+    let mut lines: Vec<&str> = content.lines().collect();
+    lines[2] = "{\"mission_day\":1,";
+    fs::write(&path, lines.join("\n") + "\n").unwrap();
+
+    let error = Journal::open(&path).unwrap_err();
+    assert!(error.contains("line 3: "), "{error}");
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn complete_unreadable_last_line_is_kept() {
+    let path = journal_path("unreadable");
+    drop(create(&path));
+
+    // a newer build may log kinds this one doesn't know
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(b"{\"mission_day\":1,\"timestamp\":0,\"kind\":\"weather\",\"body\":\"Clear.\"}\n\n").unwrap();
+    drop(file);
+    let content = fs::read_to_string(&path).unwrap();
+
+    let error = Journal::open(&path).unwrap_err();
+    assert!(error.contains("line 5: "), "{error}");
+    assert_eq!(fs::read_to_string(&path).unwrap(), content);
+
+    fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn downlinked_lines_must_be_complete() {
+    let journal = Journal::new(String::from("STATION LOG"), String::from("Kepler"));
+    let mut content = journal.to_lines().unwrap();
+    content.push_str("{\"mission_day\":1,\"timest");
+
+    assert!(Journal::from_lines(content.as_str()).is_err());
+}
@@ -0,0 +1,117 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::thread;
+use std::time::Duration;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::{Name, ModuleCounts};
+use found_terminal::packets::ping_reply::PingReply;
+use found_terminal::packets::failure::FailurePacket;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::protocol::error::DecodeError;
+use found_terminal::protocol::beacon::{StationBeacon, SUBSCRIBERS_MAX};
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+use found_terminal::protocol::udp;
+
+
+fn first_module_path(station: &Station) -> String {
+    return station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2)
+        .expect("an installed module");
+}
+
+#[test]
+fn failure_packet_round_trips() {
+    let station = Station::from_seed(4);
+    let packet = FailurePacket {
+        station: PingReply::from_station(&station),
+        port_address: 12,
+        path: String::from("Power Category / Fusion Power Section / Fusion Reactor"),
+    };
+
+    assert_eq!(FailurePacket::decode(&packet.encode()), Ok(packet.clone()));
+    assert!(PingReply::decode(&packet.encode()).is_err());
+
+    let bytes = packet.encode();
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(FailurePacket::decode(&trailing),
+               Err(DecodeError::LengthMismatch { field: "module path", length: packet.path.len() }));
+    assert!(matches!(FailurePacket::decode(&bytes[..bytes.len() - 1]), Err(DecodeError::ShortBuffer { .. })));
+}
+
+#[test]
+fn mission_control_tracks_a_station_and_its_failures() {
+    let mut station = Station::from_seed(4);
+    station.set_port_address(3).unwrap();
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+    station.set_telemetry(Box::new(beacon));
+
+    let mut mission = MissionControl::new("127.0.0.1:0", vec![address]).unwrap();
+    mission.ping_all().unwrap();
+    let updates = mission.poll(Duration::from_millis(500)).unwrap();
+
+    assert_eq!(updates, vec![MissionUpdate::Station(address)]);
+    let row = &mission.stations()[0];
+    assert_eq!(row.reply.name, station.name());
+    assert_eq!(row.reply.port_address, 3);
+    assert_eq!(row.reply.active_modules, station.total_modules());
+    assert_eq!(row.latest_failure, None);
+
+    let path = first_module_path(&station);
+    let broken = station.break_path(&path).unwrap();
+    let updates = mission.poll(Duration::from_millis(500)).unwrap();
+
+    assert_eq!(updates, vec![MissionUpdate::Failure(address, broken.clone())]);
+    let row = &mission.stations()[0];
+    assert_eq!(row.latest_failure, Some(broken));
+    assert_eq!(row.reply.active_modules, station.active_modules());
+}
+
+#[test]
+fn quiet_stations_are_dropped() {
+    let station = Station::from_seed(4);
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+
+    let mut mission = MissionControl::new("127.0.0.1:0", vec![address]).unwrap();
+    mission.set_station_timeout(Duration::from_millis(300));
+    mission.ping_all().unwrap();
+    assert_eq!(mission.poll(Duration::from_millis(200)).unwrap(), vec![MissionUpdate::Station(address)]);
+    assert_eq!(mission.stations().len(), 1);
+
+    // the beacon is gone and the next poll outlasts the timeout
+    drop(beacon);
+    mission.ping_all().unwrap();
+    assert_eq!(mission.poll(Duration::from_millis(200)).unwrap(), vec![MissionUpdate::Lost(address)]);
+    assert!(mission.stations().is_empty());
+}
+
+#[test]
+fn subscriptions_are_capped_and_expire() {
+    let station = Station::from_seed(4);
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+    beacon.set_subscription_timeout(Duration::from_millis(500));
+
+    let sockets: Vec<_> = (0..=SUBSCRIBERS_MAX).map(|_| udp::bind("127.0.0.1:0").unwrap()).collect();
+    for socket in &sockets {
+        udp::send_ping(socket, &PingPacket::new(), address).unwrap();
+        assert!(PingReply::decode(&udp::receive(socket, Some(Duration::from_secs(1))).unwrap().0).is_ok());
+    }
+
+    // the last one still got its reply, but nothing pushed
+    let subscribers = beacon.subscribers();
+    assert_eq!(subscribers.len(), SUBSCRIBERS_MAX);
+    let last = sockets[SUBSCRIBERS_MAX].local_addr().unwrap();
+    assert!(subscribers.iter().all(|subscriber| subscriber.address != last));
+
+    thread::sleep(Duration::from_millis(600));
+    assert!(beacon.subscribers().is_empty());
+}
@@ -0,0 +1,112 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::time::Duration;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::protocol::beacon::StationBeacon;
+use found_terminal::protocol::mission::{MissionControl, MissionUpdate};
+use found_terminal::protocol::port_address::PortAddressRange;
+
+
+fn mission_control(target: std::net::SocketAddr, range: PortAddressRange) -> MissionControl {
+    let mut mission = MissionControl::new("127.0.0.1:0", vec![target]).unwrap();
+    let mut ping = PingPacket::new();
+    ping.set_target_range(range);
+    mission.set_ping(ping);
+    return mission;
+}
+
+fn power_module_path(station: &Station) -> String {
+    return station.paths()
+        .into_iter()
+        .find(|path| path.starts_with("power/") && path.matches('/').count() == 2)
+        .expect("an installed power module");
+}
+
+#[test]
+fn parses_single_addresses_and_ranges() {
+    assert_eq!(PortAddressRange::parse("12"), Ok(PortAddressRange::single(12)));
+    assert_eq!(PortAddressRange::parse("10-20"), PortAddressRange::new(10, 20));
+    assert!(PortAddressRange::parse("20-10").is_err());
+    assert!(PortAddressRange::parse("32768").is_err());
+    assert!(PortAddressRange::parse("north").is_err());
+}
+
+#[test]
+fn ping_carries_its_target_range() {
+    let mut ping = PingPacket::new();
+    assert_eq!(ping.target_range(), PortAddressRange::all());
+
+    ping.set_target_range(PortAddressRange::new(0x0102, 0x7001).unwrap());
+    let decoded = PingPacket::decode(&ping.encode()).unwrap();
+
+    assert_eq!(decoded.target_range(), PortAddressRange::new(0x0102, 0x7001).unwrap());
+}
+
+#[test]
+fn station_rejects_out_of_range_port_addresses() {
+    let mut station = Station::from_seed(2);
+
+    assert!(station.set_port_address(32768).is_err());
+    assert!(station.set_category_port_address("power", Some(40000)).is_err());
+    assert!(station.set_category_port_address("power/fusion", Some(4)).is_err());
+    assert!(station.set_category_port_address("galley", Some(4)).is_err());
+}
+
+#[test]
+fn only_stations_in_the_target_range_answer() {
+    let mut station = Station::from_seed(2);
+    station.set_port_address(100).unwrap();
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+
+    let mut outside = mission_control(address, PortAddressRange::new(0, 99).unwrap());
+    outside.ping_all().unwrap();
+    assert!(outside.poll(Duration::from_millis(300)).unwrap().is_empty());
+
+    let mut inside = mission_control(address, PortAddressRange::new(100, 200).unwrap());
+    inside.ping_all().unwrap();
+    assert_eq!(inside.poll(Duration::from_millis(500)).unwrap(), vec![MissionUpdate::Station(address)]);
+}
+
+#[test]
+fn category_failures_go_to_subscribers_of_the_category_address() {
+    let mut station = Station::from_seed(2);
+    station.set_port_address(100).unwrap();
+    station.set_category_port_address("power", Some(500)).unwrap();
+    assert_eq!(station.port_addresses(), vec![100, 500]);
+
+    let beacon = StationBeacon::start("127.0.0.1:0", &station).unwrap();
+    let address = beacon.local_addr().unwrap();
+    station.set_telemetry(Box::new(beacon));
+
+    let mut station_watch = mission_control(address, PortAddressRange::single(100));
+    let mut power_watch = mission_control(address, PortAddressRange::single(500));
+    for mission in [&mut station_watch, &mut power_watch] {
+        mission.ping_all().unwrap();
+        assert_eq!(mission.poll(Duration::from_millis(500)).unwrap().len(), 1);
+    }
+
+    let broken = station.break_path(&power_module_path(&station)).unwrap();
+
+    assert_eq!(power_watch.poll(Duration::from_millis(500)).unwrap(),
+               vec![MissionUpdate::Failure(address, broken)]);
+    assert!(station_watch.poll(Duration::from_millis(300)).unwrap().is_empty());
+}
+
+#[test]
+fn port_addresses_survive_save_and_load() {
+    let mut station = Station::from_seed(2);
+    station.set_port_address(321).unwrap();
+    station.set_category_port_address("comms", Some(322)).unwrap();
+
+    let loaded = Station::from_save(&station.to_save()).unwrap();
+
+    assert_eq!(loaded.port_address(), 321);
+    assert_eq!(loaded.port_addresses(), vec![321, 322]);
+}
@@ -0,0 +1,108 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::time::Duration;
+
+// project
+use found_terminal::packets::ping::{PingPacket, PING_PACKET_SIZE};
+use found_terminal::protocol::error::DecodeError;
+use found_terminal::protocol::udp::{self, Endpoints};
+
+
+fn ping() -> PingPacket {
+    let mut ping = PingPacket::new();
+    ping.flags = 0b0000_0010;
+    ping.diag_priority = 0x40;
+    ping.target_port_address_top_hi = 0x12;
+    ping.target_port_address_top_lo = 0x34;
+    ping.target_port_address_bottom_hi = 0x01;
+    ping.target_port_address_bottom_lo = 0x02;
+    return ping;
+}
+
+#[test]
+fn ping_round_trips() {
+    let ping = ping();
+
+    assert_eq!(PingPacket::decode(&ping.encode()), Ok(ping));
+}
+
+#[test]
+fn ping_wire_layout() {
+    let bytes = ping().encode();
+
+    assert_eq!(bytes.len(), PING_PACKET_SIZE);
+    assert_eq!(&bytes[0..8], b"Station\0");
+    assert_eq!(&bytes[8..10], &[0x00, 0x10]);
+    assert_eq!(&bytes[10..12], &[0, 14]);
+    assert_eq!(&bytes[12..], &[0b0000_0010, 0x40, 0x12, 0x34, 0x01, 0x02]);
+}
+
+#[test]
+fn decode_rejects_bad_id() {
+    let mut bytes = ping().encode();
+    bytes[0] = b's';
+
+    assert_eq!(PingPacket::decode(&bytes), Err(DecodeError::BadMagic));
+}
+
+#[test]
+fn decode_rejects_other_protocol_version() {
+    let mut bytes = ping().encode();
+    bytes[11] = 15;
+
+    assert_eq!(PingPacket::decode(&bytes), Err(DecodeError::VersionMismatch { hi: 0, lo: 15 }));
+}
+
+#[test]
+fn decode_rejects_short_and_foreign_packets() {
+    let bytes = ping().encode();
+    assert_eq!(PingPacket::decode(&bytes[..PING_PACKET_SIZE - 1]),
+               Err(DecodeError::ShortBuffer { length: PING_PACKET_SIZE - 1, expected: PING_PACKET_SIZE }));
+    assert_eq!(PingPacket::decode(&bytes[..4]),
+               Err(DecodeError::ShortBuffer { length: 4, expected: 12 }));
+
+    let mut other = bytes.clone();
+    other[9] = 0x20;
+    assert_eq!(PingPacket::decode(&other), Err(DecodeError::UnknownOpCode(0x2000)));
+}
+
+#[test]
+fn ping_over_udp_on_localhost() {
+    let receiver = udp::bind("127.0.0.1:0").unwrap();
+    let sender = udp::bind("127.0.0.1:0").unwrap();
+    let ping = ping();
+
+    udp::send_ping(&sender, &ping, receiver.local_addr().unwrap()).unwrap();
+    let (bytes, source) = udp::receive(&receiver, Some(Duration::from_secs(2))).unwrap();
+
+    assert_eq!(source, sender.local_addr().unwrap());
+    assert_eq!(PingPacket::decode(&bytes), Ok(ping));
+}
+
+#[test]
+fn endpoints_take_bind_and_targets() {
+    let mut endpoints = Endpoints::new();
+    let mut args = ["127.0.0.1:0", "10.0.0.7", "10.0.0.8:7000", "--rounds"].map(String::from).into_iter();
+
+    assert_eq!(endpoints.parse_argument("--bind", &mut args), Ok(true));
+    assert_eq!(endpoints.parse_argument("--target", &mut args), Ok(true));
+    assert_eq!(endpoints.parse_argument("--target", &mut args), Ok(true));
+    assert_eq!(endpoints.parse_argument("--rounds", &mut args), Ok(false));
+    assert!(endpoints.parse_argument("--target", &mut args).is_err());
+    endpoints.default_targets().unwrap();
+
+    assert_eq!(endpoints.bind, "127.0.0.1:0");
+    assert_eq!(endpoints.targets, vec!["10.0.0.7:6454".parse().unwrap(), "10.0.0.8:7000".parse().unwrap()]);
+    assert!(udp::parse_target("station").is_err());
+}
+
+#[test]
+fn endpoints_default_to_the_broadcast_networks() {
+    let mut endpoints = Endpoints::new();
+    endpoints.default_targets().unwrap();
+
+    assert_eq!(endpoints.targets, vec!["2.255.255.255:6454".parse().unwrap(), "10.255.255.255:6454".parse().unwrap()]);
+}
@@ -0,0 +1,86 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::ModuleCounts;
+use found_terminal::station::events::SystemEvent;
+use found_terminal::prompt::scripted::ScriptedPrompter;
+use found_terminal::prompt::testing::TestPrompter;
+
+
+fn broken_station(seed: u64) -> (Station, String) {
+    let mut station = Station::from_seed(seed);
+
+    while !station.repairable() {
+        station.science();
+    }
+
+    let broken = station.take_events()
+        .into_iter()
+        .find_map(|event| match event {
+            SystemEvent::ModuleFailure(path) => Some(path),
+            _ => None,
+        })
+        .expect("a module failure");
+
+    return (station, broken);
+}
+
+#[test]
+fn repair_walks_category_section_and_module_menus() {
+    let (mut station, broken) = broken_station(7);
+    let prompter = TestPrompter::new();
+    station.set_prompter(Box::new(prompter.clone()));
+
+    let repaired = station.repair();
+
+    assert_eq!(repaired, Some(broken));
+    assert_eq!(station.active_modules(), station.total_modules());
+
+    let titles: Vec<String> = prompter.records()
+        .into_iter()
+        .map(|record| record.title)
+        .collect();
+    assert_eq!(titles, vec![
+        "Select section category to repair:",
+        "Select section to repair:",
+        "Select module to repair:",
+    ]);
+}
+
+#[test]
+fn repair_offers_only_repairable_options() {
+    let (mut station, _) = broken_station(11);
+    let prompter = TestPrompter::new();
+    station.set_prompter(Box::new(prompter.clone()));
+
+    station.repair();
+
+    for record in prompter.records() {
+        assert_eq!(record.options.len(), 1, "{:?}", record);
+    }
+}
+
+#[test]
+fn repair_follows_scripted_answers() {
+    let (mut station, broken) = broken_station(21);
+    let answers: Vec<String> = broken
+        .split(" / ")
+        .map(|name| name.to_string())
+        .collect();
+    station.set_prompter(Box::new(ScriptedPrompter::new(answers)));
+
+    assert_eq!(station.repair(), Some(broken.clone()));
+    assert_eq!(station.take_events(), vec![SystemEvent::ModuleRepaired(broken)]);
+}
+
+#[test]
+fn repair_stops_when_prompter_fails() {
+    let (mut station, _) = broken_station(5);
+    station.set_prompter(Box::new(ScriptedPrompter::new(vec![])));
+
+    assert_eq!(station.repair(), None);
+    assert!(station.repairable());
+}
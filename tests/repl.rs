@@ -0,0 +1,194 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::ModuleCounts;
+use found_terminal::station::path::PathError;
+use found_terminal::station::query::StatusQuery;
+use found_terminal::journal::journal::Journal;
+use found_terminal::repl::completion::complete;
+use found_terminal::repl::error::ReplError;
+use found_terminal::repl::form::Form;
+use found_terminal::repl::session::Session;
+use found_terminal::terminalisp::reader::{read, read_all};
+use found_terminal::terminalisp::repl as tl_repl;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+use found_terminal::terminalisp::value::Value;
+
+
+fn scoped(path: &str) -> Form {
+    return Form::Status(StatusQuery { scope: Some(path.to_string()), ..StatusQuery::new() });
+}
+
+fn form(input: &str) -> Result<Form, ReplError> {
+    return Form::from_value(&read(input).expect("readable input"));
+}
+
+fn eval_all(input: &str, session: &mut Session, station: &mut Station, journal: &mut Journal) -> Vec<Result<bool, ReplError>> {
+    return read_all(input)
+        .expect("readable input")
+        .iter()
+        .map(|value| session.eval(value, station, journal))
+        .collect();
+}
+
+fn captured<F: FnOnce()>(action: F) -> Vec<String> {
+    let buffer = BufferSink::new();
+    with_sink(Box::new(buffer.clone()), action);
+    return buffer.take();
+}
+
+#[test]
+fn forms_parse_from_terminalisp() {
+    assert_eq!(form("(status)"), Ok(Form::Status(StatusQuery::new())));
+    assert_eq!(form("(status :category power)"), Ok(scoped("power")));
+    assert_eq!(form("(status \"power/fossil\")"), Ok(scoped("power/fossil")));
+    assert_eq!(form("(status :section power/fossil :inactive :installed :summary)"), Ok(Form::Status(StatusQuery {
+        scope: Some("power/fossil".to_string()),
+        inactive_only: true,
+        hide_uninstalled: true,
+        summary: true,
+    })));
+    assert_eq!(form("(repair \"power/fossil/fossil-fuel-storage\")"),
+               Ok(Form::Repair("power/fossil/fossil-fuel-storage".to_string())));
+    assert_eq!(form("(science)"), Ok(Form::Science));
+    assert_eq!(form("(new-day)"), Ok(Form::NewDay));
+    assert_eq!(form("(journal \"all quiet\")"), Ok(Form::Journal("all quiet".to_string())));
+    assert_eq!(form("(power-down)"), Ok(Form::PowerDown));
+}
+
+#[test]
+fn bad_forms_are_typed_errors() {
+    assert_eq!(form("science"), Err(ReplError::NotAForm("science".to_string())));
+    assert_eq!(form("(dance)"), Err(ReplError::UnknownForm("dance".to_string())));
+    assert!(matches!(form("(science 1)"), Err(ReplError::BadArguments { form: "science", .. })));
+    assert!(matches!(form("(journal)"), Err(ReplError::BadArguments { form: "journal", .. })));
+    assert!(matches!(form("(status :category \"power/fossil\")"), Err(ReplError::BadArguments { form: "status", .. })));
+    assert!(matches!(form("(status :section power)"), Err(ReplError::BadArguments { form: "status", .. })));
+    assert!(matches!(form("(status :category power \"crew\")"), Err(ReplError::BadArguments { form: "status", .. })));
+    assert!(matches!(form("(status :everything)"), Err(ReplError::BadArguments { form: "status", .. })));
+    assert!(matches!(form("(status :category)"), Err(ReplError::BadArguments { form: "status", .. })));
+}
+
+#[test]
+fn errors_print_as_readable_error_forms() {
+    let error = ReplError::UnknownForm("da\"nce".to_string());
+    let forms = captured(|| tl_repl::repl_error(&error));
+
+    assert_eq!(forms.len(), 1);
+    let value = read(forms[0].as_str()).expect("error form reads back");
+    assert_eq!(value.head(), Some("error"));
+    assert_eq!(value.get("kind"), Some(&Value::quoted("unknown-form")));
+    assert_eq!(value.get("message").and_then(|v| v.as_str()), Some("unknown form \"da\"nce\""));
+}
+
+#[test]
+fn first_journal_form_is_the_day_log() {
+    let mut station = Station::from_seed(5);
+    let mut journal = Journal::new("STATION LOG".to_string(), station.name_display());
+    let day = station.mission_day;
+
+    let mut session = Session::start(&station, &mut journal);
+    assert!(session.awaiting_log());
+
+    let results = eval_all("(journal \"first light\") (journal \"still here\")", &mut session, &mut station, &mut journal);
+    assert_eq!(results, vec![Ok(false), Ok(false)]);
+    assert!(!session.awaiting_log());
+    assert_eq!(station.mission_day, day + 1);
+
+    let results = eval_all("(status) (new-day)", &mut session, &mut station, &mut journal);
+    assert_eq!(results, vec![Ok(false), Ok(true)]);
+    assert!(session.awaiting_log());
+    assert_eq!(station.mission_day, day + 1);
+
+    let text = journal.to_lines().expect("journal serializes");
+    assert!(text.contains("first light"));
+    assert!(text.contains("still here"));
+}
+
+#[test]
+fn status_is_emitted_and_scoped() {
+    let mut station = Station::from_seed(5);
+    let mut journal = Journal::new("STATION LOG".to_string(), station.name_display());
+    let mut session = Session::start(&station, &mut journal);
+    eval_all("(journal \"log\")", &mut session, &mut station, &mut journal);
+
+    let mut results = vec![];
+    let forms = captured(|| {
+        results = eval_all("(status :category power)", &mut session, &mut station, &mut journal);
+    });
+
+    assert_eq!(results, vec![Ok(false)]);
+    let query = StatusQuery { scope: Some("power".to_string()), ..StatusQuery::new() };
+    let expected = station.status_query(&query).expect("power category");
+    assert_eq!(forms.last().map(|v| read(v.as_str())), Some(Ok(expected)));
+}
+
+#[test]
+fn repair_errors_keep_the_day_open() {
+    let mut station = Station::from_seed(5);
+    let mut journal = Journal::new("STATION LOG".to_string(), station.name_display());
+    let mut session = Session::start(&station, &mut journal);
+    eval_all("(journal \"log\")", &mut session, &mut station, &mut journal);
+
+    let path = station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2 && station.module(path).map(|m| m.active()).unwrap_or(false))
+        .expect("an active module");
+
+    let results = eval_all(format!("(repair \"{path}\")").as_str(), &mut session, &mut station, &mut journal);
+    assert!(matches!(results[0], Err(ReplError::Path(PathError::AlreadyActive(_)))));
+    assert!(!session.awaiting_log());
+
+    station.break_path(path.as_str()).expect("module breaks");
+    let active = station.active_modules();
+    let results = eval_all(format!("(repair {path})").as_str(), &mut session, &mut station, &mut journal);
+    assert_eq!(results, vec![Ok(true)]);
+    assert_eq!(station.active_modules(), active + 1);
+    assert!(session.awaiting_log());
+}
+
+#[test]
+fn shut_down_station_rejects_forms() {
+    let mut station = Station::from_seed(5);
+    let mut journal = Journal::new("STATION LOG".to_string(), station.name_display());
+    let mut session = Session::start(&station, &mut journal);
+
+    for path in station.paths().into_iter().filter(|path| path.matches('/').count() == 2) {
+        let _ = station.break_path(path.as_str());
+    }
+    let results = eval_all("(journal \"dark\") (science)", &mut session, &mut station, &mut journal);
+
+    assert_eq!(results, vec![Err(ReplError::ShutDown), Err(ReplError::ShutDown)]);
+    assert!(station.is_shut_down());
+}
+
+#[test]
+fn completion_offers_forms_paths_and_categories() {
+    let station = Station::from_seed(5);
+    let paths = station.paths();
+    let module = paths.iter().find(|path| path.matches('/').count() == 2).expect("a module path");
+    let category = module.split('/').next().expect("a category");
+
+    let (start, candidates) = complete("(sci", 4, &paths);
+    assert_eq!((start, candidates), (1, vec!["science".to_string()]));
+
+    let line = format!("(repair \"{category}/");
+    let (start, candidates) = complete(line.as_str(), line.len(), &paths);
+    assert_eq!(start, 9);
+    assert!(candidates.contains(module));
+    assert!(candidates.iter().all(|candidate| candidate.starts_with(&format!("{category}/"))));
+
+    let line = "(status :category p";
+    let (_, candidates) = complete(line, line.len(), &paths);
+    assert!(!candidates.is_empty());
+    assert!(candidates.iter().all(|candidate| candidate.starts_with('p') && !candidate.contains('/')));
+
+    let (_, candidates) = complete("(status :in", 11, &paths);
+    assert_eq!(candidates, vec![":inactive".to_string(), ":installed".to_string()]);
+
+    let (_, candidates) = complete("(journal \"done\") ", 17, &paths);
+    assert!(candidates.is_empty());
+}
@@ -0,0 +1,110 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::env;
+use std::fs;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::{ModuleCounts, Name};
+use found_terminal::station::save::StationSave;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+
+
+// a few days in, with modules broken by the days and one broken by hand
+fn played_station(seed: u64) -> Station {
+    let mut station = Station::from_seed(seed);
+    with_sink(Box::new(BufferSink::new()), || {
+        for _ in 0..3 {
+            station.new_day();
+        }
+        let path = station.paths()
+            .into_iter()
+            .find(|path| path.matches('/').count() == 2 && station.status_path(path).is_ok())
+            .expect("an installed module");
+        station.break_path(&path).expect("module breaks");
+    });
+    station.take_events();
+    return station;
+}
+
+fn rejection<F: FnOnce(&mut StationSave)>(change: F) -> String {
+    let mut save = played_station(5).to_save();
+    change(&mut save);
+    return match Station::from_save(&save) {
+        Ok(_) => String::new(),
+        Err(e) => e,
+    };
+}
+
+#[test]
+fn saved_station_reloads_unchanged() {
+    let mut station = played_station(5);
+    assert!(station.mission_day > 0);
+    assert!(station.active_modules() < station.total_modules());
+
+    let path = env::temp_dir().join(format!("found-terminal-save-{}.json", std::process::id()));
+    station.save(&path).unwrap();
+    let mut loaded = Station::load(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded.status(), station.status());
+    assert_eq!(loaded.seed(), station.seed());
+    assert_eq!(loaded.mission_day, station.mission_day);
+    assert_eq!(loaded.active_modules(), station.active_modules());
+
+    // the RNG position is saved too, so both go on to break the same modules
+    with_sink(Box::new(BufferSink::new()), || {
+        for _ in 0..3 {
+            station.new_day();
+            loaded.new_day();
+        }
+    });
+    assert_eq!(loaded.status(), station.status());
+    assert_eq!(loaded.take_events(), station.take_events());
+}
+
+#[test]
+fn wrong_counts_are_rejected() {
+    let station = played_station(5);
+    assert_eq!(rejection(|save| save.active_modules += 1),
+               format!("{}: active-modules is {}, modules give {}",
+                       station.name(), station.active_modules() + 1, station.active_modules()));
+    assert!(rejection(|save| save.total_sections -= 1).contains(": total-sections is "));
+    assert!(rejection(|save| save.categories[0].installed_sections += 1).contains(": installed-sections is "));
+    assert!(rejection(|save| save.categories[1].total_modules += 1).contains(": total-modules is "));
+    assert!(rejection(|save| save.categories[2].sections[0].active_modules += 1).contains(": active-modules is "));
+}
+
+#[test]
+fn wrong_shapes_are_rejected() {
+    assert_eq!(rejection(|save| { save.categories.pop(); }), "expected 6 categories, found 5");
+    assert!(rejection(|save| { save.categories[0].sections.pop(); }).contains(" sections, found "));
+    assert!(rejection(|save| { save.categories[4].sections[0].modules.pop(); }).contains(" modules, found "));
+}
+
+#[test]
+fn unknown_names_are_rejected() {
+    assert_eq!(rejection(|save| save.name = String::from("Nowhere")), "unknown station name \"Nowhere\"");
+    assert!(rejection(|save| save.categories[0].name = String::from("Plumbing"))
+        .ends_with("found \"Plumbing\""));
+    assert!(rejection(|save| save.categories[0].sections[0].name = String::from("Attic"))
+        .ends_with("found \"Attic\""));
+    assert!(rejection(|save| save.categories[0].sections[0].modules[0].name = String::from("Toaster"))
+        .ends_with("found \"Toaster\""));
+}
+
+#[test]
+fn active_modules_need_an_installed_section() {
+    let error = rejection(|save| {
+        let section = save.categories
+            .iter_mut()
+            .flat_map(|category| category.sections.iter_mut())
+            .find(|section| !section.installed)
+            .expect("an uninstalled section");
+        section.modules[0].active = true;
+    });
+    assert!(error.contains("active in uninstalled section"), "{error}");
+}
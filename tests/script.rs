@@ -0,0 +1,104 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::components::ModuleCounts;
+use found_terminal::journal::journal::Journal;
+use found_terminal::journal::entry::EntryKind;
+use found_terminal::script::script::{run_script, Command};
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+use found_terminal::terminalisp::reader::read_all;
+use found_terminal::terminalisp::value::Value;
+
+
+fn run_fixture(name: &str, seed: u64) -> (Station, Journal, Vec<Value>) {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name);
+    let mut station = Station::from_seed(seed);
+    let mut journal = Journal::new(String::from("STATION LOG"), station.name_display());
+
+    let buffer = BufferSink::new();
+    with_sink(Box::new(buffer.clone()), || {
+        let file = File::open(&path).unwrap();
+        run_script(BufReader::new(file), &mut station, &mut journal).unwrap();
+    });
+
+    let forms = read_all(buffer.contents().as_str()).unwrap();
+    return (station, journal, forms);
+}
+
+fn named<'a>(forms: &'a [Value], name: &str) -> Vec<&'a Value> {
+    forms.iter().filter(|form| form.head() == Some(name)).collect()
+}
+
+fn bodies(journal: &Journal, kind: EntryKind) -> Vec<String> {
+    journal.entries_of_kind(kind)
+        .into_iter()
+        .map(|entry| entry.body.clone())
+        .collect()
+}
+
+#[test]
+fn parses_script_lines() {
+    assert_eq!(Command::parse("  STATUS "), Some(Command::Status));
+    assert_eq!(Command::parse("REPAIR  comms/antenna/antenna "),
+               Some(Command::Repair(String::from("comms/antenna/antenna"))));
+    assert_eq!(Command::parse("NEW DAY"), Some(Command::NewDay));
+    assert_eq!(Command::parse("POWER DOWN"), Some(Command::PowerDown));
+    assert_eq!(Command::parse("status"), Some(Command::Journal(String::from("status"))));
+    assert_eq!(Command::parse("; comment"), None);
+    assert_eq!(Command::parse("# Day one"), Some(Command::Journal(String::from("# Day one"))));
+    assert_eq!(Command::parse("   "), None);
+}
+
+#[test]
+fn mission_fixture_runs_every_command() {
+    let (station, journal, forms) = run_fixture("mission.script", 2);
+
+    assert_eq!(named(&forms, "script-command").len(), 8);
+    assert_eq!(named(&forms, "script-error").len(), 0);
+    assert_eq!(named(&forms, "station").len(), 2);
+    assert_eq!(named(&forms, "module-repaired")[0].to_string(),
+               "(module-repaired \"Power Category / Nuclear Power Section / Nuclear Waste Storage\")");
+    assert_eq!(forms.last().unwrap().to_string(), "(script-end :line 12)");
+
+    assert_eq!(bodies(&journal, EntryKind::CrewLog), vec![
+        "Day one: the reactor hums along.",
+        "Crew notes: the waste storage gauge reads zero.",
+        "Day two, nothing to repair.",
+    ]);
+    assert_eq!(bodies(&journal, EntryKind::DayMarker), vec![
+        "Mission Day 0", "Mission Day 1", "Mission Day 2", "Mission Day 3",
+    ]);
+    assert!(bodies(&journal, EntryKind::SystemEvent)
+        .contains(&String::from("Module repaired: Power Category / Nuclear Power Section / Nuclear Waste Storage")),
+        "{:?}", bodies(&journal, EntryKind::SystemEvent));
+
+    // the last day opened without a log entry and ended with the power down
+    assert_eq!(station.mission_day, 3);
+    assert_eq!(journal.entries_for_day(3).iter().filter(|entry| entry.kind == EntryKind::CrewLog).count(), 0);
+    assert_eq!(station.active_modules(), 0);
+}
+
+#[test]
+fn errors_fixture_keeps_the_day_open() {
+    let (station, journal, forms) = run_fixture("errors.script", 2);
+
+    let errors: Vec<Option<i128>> = named(&forms, "script-error")
+        .into_iter()
+        .map(|form| form.get("line").and_then(Value::as_integer))
+        .collect();
+    assert_eq!(errors, vec![Some(3), Some(4), Some(5), Some(6)]);
+
+    // the one good repair on the last line still ends day one
+    assert_eq!(named(&forms, "module-repaired").len(), 1);
+    assert_eq!(station.mission_day, 1);
+    assert_eq!(station.active_modules(), station.total_modules());
+    assert_eq!(bodies(&journal, EntryKind::CrewLog), vec!["Day one: trying the wrong modules first."]);
+}
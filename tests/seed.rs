@@ -0,0 +1,54 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::events::SystemEvent;
+use found_terminal::prompt::testing::TestPrompter;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+use found_terminal::terminalisp::value::Value;
+
+
+// A fixed list of player actions, with every repair menu answered by its first option.
+fn play(seed: u64) -> (Vec<Value>, Vec<Vec<SystemEvent>>, Vec<String>) {
+    let mut station = Station::from_seed(seed);
+    station.set_prompter(Box::new(TestPrompter::new()));
+    let mut statuses: Vec<Value> = vec![];
+    let mut events: Vec<Vec<SystemEvent>> = vec![];
+
+    let forms = BufferSink::new();
+    with_sink(Box::new(forms.clone()), || {
+        for day in 0..12 {
+            station.new_day();
+            if day % 3 == 0 {
+                station.science();
+            }
+            if station.repairable() && day % 2 == 1 {
+                station.repair();
+            }
+            statuses.push(station.status());
+            events.push(station.take_events());
+        }
+    });
+
+    return (statuses, events, forms.take());
+}
+
+#[test]
+fn same_seed_and_actions_give_the_same_mission() {
+    for seed in [0, 7, 42, u64::MAX] {
+        let (statuses, events, forms) = play(seed);
+        assert_eq!(play(seed), (statuses.clone(), events.clone(), forms));
+
+        assert_eq!(Station::from_seed(seed).seed(), seed);
+        assert!(events.iter().flatten().any(|event| matches!(event, SystemEvent::ModuleFailure(_))));
+    }
+}
+
+#[test]
+fn seeds_give_different_missions() {
+    let missions: Vec<Vec<Value>> = (0..4).map(|seed| play(seed).0).collect();
+
+    assert!(missions.iter().skip(1).any(|mission| *mission != missions[0]));
+}
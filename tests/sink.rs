@@ -0,0 +1,101 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::panic;
+use std::thread;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::terminalisp::sink::{emit, set_sink, with_sink, BufferSink, MultiSink};
+use found_terminal::terminalisp::station as tl_station;
+
+
+fn first_module_path(station: &Station) -> String {
+    return station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2)
+        .expect("an installed module");
+}
+
+#[test]
+fn buffer_sink_captures_section_failure() {
+    let buffer = BufferSink::new();
+    with_sink(Box::new(buffer.clone()), || {
+        let mut station = Station::from_seed(3);
+        let path = first_module_path(&station);
+        station.break_path(&path).expect("module breaks");
+    });
+
+    let forms = buffer.take();
+
+    assert!(forms.iter().any(|form| form.starts_with("(section-failure ")));
+    assert!(buffer.forms().is_empty());
+}
+
+#[test]
+fn multi_sink_fans_out_to_every_sink() {
+    let first = BufferSink::new();
+    let second = BufferSink::new();
+    let mut multi = MultiSink::default();
+    multi.add(Box::new(first.clone()));
+    multi.add(Box::new(second.clone()));
+    with_sink(Box::new(multi), || {
+        tl_station::station_seed(42);
+        tl_station::end_transmission();
+    });
+
+    let expected = vec![String::from("(station-seed 42)"), String::from("(end-transmission)")];
+    assert_eq!(first.forms(), expected);
+    assert_eq!(second.forms(), expected);
+    assert_eq!(first.contents(), "(station-seed 42)\n(end-transmission)\n");
+}
+
+// the only test here that installs the process-wide sink, the others stay on their own thread
+#[test]
+fn sink_is_shared_with_other_threads() {
+    let buffer = BufferSink::new();
+    let previous = set_sink(Box::new(buffer.clone()));
+
+    thread::spawn(|| tl_station::station_seed(7)).join().unwrap();
+    tl_station::end_transmission();
+
+    set_sink(previous);
+    assert_eq!(buffer.take(), vec![String::from("(station-seed 7)"), String::from("(end-transmission)")]);
+}
+
+#[test]
+fn thread_sink_leaves_other_threads_alone() {
+    let outer = BufferSink::new();
+    let inner = BufferSink::new();
+
+    with_sink(Box::new(outer.clone()), || {
+        emit(String::from("(outer)"));
+        with_sink(Box::new(inner.clone()), || emit(String::from("(inner)")));
+        emit(String::from("(outer-again)"));
+    });
+
+    assert_eq!(outer.take(), vec![String::from("(outer)"), String::from("(outer-again)")]);
+    assert_eq!(inner.take(), vec![String::from("(inner)")]);
+}
+
+#[test]
+fn thread_sink_is_restored_after_a_panic() {
+    let outer = BufferSink::new();
+    let inner = BufferSink::new();
+
+    with_sink(Box::new(outer.clone()), || {
+        let result = panic::catch_unwind(|| {
+            with_sink(Box::new(inner.clone()), || {
+                emit(String::from("(inner)"));
+                panic!("emitter failed");
+            })
+        });
+        assert!(result.is_err());
+        emit(String::from("(outer)"));
+    });
+
+    assert_eq!(outer.take(), vec![String::from("(outer)")]);
+    assert_eq!(inner.take(), vec![String::from("(inner)")]);
+}
@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::str::FromStr;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::journal::journal::Journal;
+use found_terminal::station::snapshot::{StationSnapshot, ModuleStatus};
+use found_terminal::event::event::Event;
+use found_terminal::terminalisp::format::{OutputFormat, render, to_json};
+use found_terminal::terminalisp::pretty::pretty;
+use found_terminal::terminalisp::sink::{emit, with_sink, BufferSink, FormatSink};
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::event as tl_event;
+use found_terminal::terminalisp::journal as tl_journal;
+use found_terminal::terminalisp::value::Value;
+
+
+fn broken_station(seed: u64) -> (Station, String) {
+    let mut station = Station::from_seed(seed);
+    let path = station.paths()
+        .into_iter()
+        .find(|path| path.matches('/').count() == 2)
+        .expect("an installed module");
+    station.break_path(path.as_str()).expect("module breaks");
+    return (station, path);
+}
+
+#[test]
+fn snapshot_lists_installed_sections_and_module_status() {
+    let (station, path) = broken_station(8);
+    let snapshot = station.snapshot();
+
+    assert_eq!(snapshot.name, station.status().get("name").and_then(Value::as_str).unwrap());
+    assert_eq!(snapshot.categories.len(), 6);
+    for category in &snapshot.categories {
+        assert_eq!(category.sections.len() as u16, category.installed_sections);
+    }
+
+    let inactive: Vec<&str> = snapshot.categories.iter()
+        .flat_map(|category| category.sections.iter())
+        .flat_map(|section| section.modules.iter())
+        .filter(|module| module.status == ModuleStatus::Inactive)
+        .map(|module| module.name.as_str())
+        .collect();
+    assert_eq!(inactive.len(), 1);
+    let module = station.module(path.as_str()).unwrap();
+    assert_eq!(inactive[0], module.name());
+}
+
+#[test]
+fn snapshot_round_trips_through_json_and_yaml() {
+    let (station, _) = broken_station(9);
+    let snapshot = station.snapshot();
+
+    let json = snapshot.to_json();
+    assert!(json.contains("\"status\": \"inactive\""));
+    assert_eq!(serde_json::from_str::<StationSnapshot>(json.as_str()).unwrap(), snapshot);
+
+    let yaml = snapshot.to_yaml();
+    assert!(yaml.contains("mission_day: 0"));
+    assert_eq!(serde_yaml::from_str::<StationSnapshot>(yaml.as_str()).unwrap(), snapshot);
+}
+
+#[test]
+fn status_form_has_the_snapshot_shape() {
+    let (station, _) = broken_station(10);
+
+    let mut json = to_json(&station.status());
+    let form = json.as_object_mut().unwrap().shift_remove("form");
+
+    assert_eq!(form, Some(serde_json::Value::from("station")));
+    assert_eq!(json, serde_json::to_value(station.snapshot()).unwrap());
+}
+
+#[test]
+fn forms_render_as_json_lines_and_yaml_documents() {
+    let form = "(station-event 'meteor-shower \"Rocks\" :days 3)";
+
+    assert_eq!(render(form, OutputFormat::Terminalisp), form);
+    assert_eq!(render(form, OutputFormat::Json),
+               r#"{"form":"station-event","days":3,"args":["meteor-shower","Rocks"]}"#);
+    assert_eq!(render(form, OutputFormat::Yaml),
+               "---\nform: station-event\ndays: 3\nargs:\n- meteor-shower\n- Rocks\n");
+
+    assert_eq!(render("(station-seed 18446744073709551615)", OutputFormat::Json),
+               r#"{"form":"station-seed","args":[18446744073709551615]}"#);
+    assert_eq!(render("(big -18446744073709551616)", OutputFormat::Json),
+               r#"{"form":"big","args":["-18446744073709551616"]}"#);
+    assert_eq!(OutputFormat::from_str("yaml"), Ok(OutputFormat::Yaml));
+}
+
+#[test]
+fn form_and_args_keywords_keep_their_colon() {
+    assert_eq!(render("(note :form \"haiku\" :args 3 x)", OutputFormat::Json),
+               r#"{"form":"note",":form":"haiku",":args":3,"args":["x"]}"#);
+
+    // nested forms carry no "form" tag to clash with
+    assert_eq!(render("(outer :inner (note :form 1))", OutputFormat::Json),
+               r#"{"form":"outer","inner":{"form":1}}"#);
+}
+
+#[test]
+fn journal_printout_is_one_form() {
+    let mut journal = Journal::new(String::from("STATION LOG"), String::from("Kepler"));
+    journal.day(1).unwrap();
+    journal.add_entry(String::from("# Day one\n---")).unwrap();
+
+    let buffer = BufferSink::new();
+    with_sink(Box::new(FormatSink::new(OutputFormat::Json, Box::new(buffer.clone()))), || {
+        tl_journal::journal_printout(journal.header(), journal.title(), journal.entries());
+    });
+    let forms = buffer.take();
+
+    assert_eq!(forms.len(), 1);
+    let printout: serde_json::Value = serde_json::from_str(forms[0].as_str()).unwrap();
+    assert_eq!(printout["form"], "journal");
+    assert_eq!(printout["entries"][1]["body"], "# Day one\n---");
+}
+
+#[test]
+fn format_sink_converts_status_and_events() {
+    let buffer = BufferSink::new();
+    with_sink(Box::new(FormatSink::new(OutputFormat::Json, Box::new(buffer.clone()))), || {
+        let station = Station::from_seed(12);
+        tl_station::status(&station.status());
+        tl_event::station_event(Event::MessageFromEarth);
+        emit(pretty(&station.status(), 20));
+    });
+
+    let forms = buffer.take();
+
+    assert_eq!(forms.len(), 3);
+    let status: serde_json::Value = serde_json::from_str(forms[0].as_str()).unwrap();
+    assert_eq!(status["form"], "station");
+    assert_eq!(status["categories"].as_array().map(|v| v.len()), Some(6));
+
+    let event: serde_json::Value = serde_json::from_str(forms[1].as_str()).unwrap();
+    assert_eq!(event["form"], "station-event");
+    assert!(!forms[1].contains('\n'));
+    assert_eq!(forms[2], forms[0]);
+}
@@ -0,0 +1,151 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::station::path::PathError;
+use found_terminal::station::query::StatusQuery;
+use found_terminal::prompt::testing::TestPrompter;
+use found_terminal::terminalisp::pretty::pretty;
+use found_terminal::terminalisp::reader::read;
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::value::Value;
+
+
+fn broken_station(seed: u64) -> (Station, String) {
+    let mut station = Station::from_seed(seed);
+    let path = station.paths()
+        .into_iter()
+        .rfind(|path| path.matches('/').count() == 2)
+        .expect("an installed module");
+    station.break_path(path.as_str()).expect("module breaks");
+    return (station, path);
+}
+
+fn list<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    return value.get(key).and_then(Value::as_list).expect(key);
+}
+
+fn statuses(value: &Value) -> Vec<&Value> {
+    return list(value, "categories")
+        .iter()
+        .flat_map(|category| list(category, "sections"))
+        .flat_map(|section| section.get("modules").and_then(Value::as_list).unwrap_or_default())
+        .map(|module| module.get("status").expect("module status"))
+        .collect();
+}
+
+fn query() -> StatusQuery {
+    return StatusQuery::new();
+}
+
+#[test]
+fn hiding_uninstalled_sections_matches_the_status_tree() {
+    let station = Station::from_seed(21);
+
+    let hidden = StatusQuery { hide_uninstalled: true, ..query() };
+    assert_eq!(station.status_query(&hidden), Ok(station.status()));
+
+    let full = station.status_query(&query()).unwrap();
+    let uninstalled: Vec<&Value> = list(&full, "categories")
+        .iter()
+        .flat_map(|category| list(category, "sections"))
+        .filter(|section| section.get("status") == Some(&Value::quoted("uninstalled")))
+        .collect();
+    assert!(!uninstalled.is_empty());
+    assert!(uninstalled.iter().all(|section| section.get("modules").is_none()));
+}
+
+#[test]
+fn inactive_only_keeps_the_broken_module_and_its_parents() {
+    let (station, path) = broken_station(22);
+    let filtered = station.status_query(&StatusQuery { inactive_only: true, ..query() }).unwrap();
+
+    let categories = list(&filtered, "categories");
+    assert_eq!(categories.len(), 1);
+    assert_eq!(list(&categories[0], "sections").len(), 1);
+    assert_eq!(statuses(&filtered), vec![&Value::quoted("inactive")]);
+
+    let module = station.module(path.as_str()).unwrap();
+    let sections = list(&categories[0], "sections");
+    assert_eq!(list(&sections[0], "modules")[0].get("name").and_then(Value::as_str), Some(module.name().as_str()));
+}
+
+#[test]
+fn scope_picks_a_category_section_or_module() {
+    let (station, path) = broken_station(23);
+    let parts: Vec<&str> = path.split('/').collect();
+    let section_path = format!("{}/{}", parts[0], parts[1]);
+
+    let category = station.status_query(&StatusQuery { scope: Some(parts[0].to_string()), ..query() }).unwrap();
+    assert_eq!(category.head(), Some("category"));
+
+    let section = station.status_query(&StatusQuery { scope: Some(section_path), inactive_only: true, ..query() }).unwrap();
+    assert_eq!(section.head(), Some("section"));
+    assert_eq!(list(&section, "modules").len(), 1);
+
+    let module = station.status_query(&StatusQuery { scope: Some(path.clone()), ..query() }).unwrap();
+    assert_eq!(module.get("status"), Some(&Value::quoted("inactive")));
+
+    let unknown = station.status_query(&StatusQuery { scope: Some("warp".to_string()), ..query() });
+    assert_eq!(unknown, Err(PathError::UnknownCategory("warp".to_string())));
+}
+
+#[test]
+fn scoped_node_stays_when_nothing_is_inactive() {
+    let station = Station::from_seed(24);
+    let category = station.paths().into_iter().next().unwrap();
+
+    let value = station.status_query(&StatusQuery { scope: Some(category), inactive_only: true, ..query() }).unwrap();
+    assert_eq!(value.head(), Some("category"));
+    assert_eq!(list(&value, "sections"), &[] as &[Value]);
+}
+
+#[test]
+fn summary_prints_one_line_per_category() {
+    let (station, _) = broken_station(25);
+    let summary = station.status_query(&StatusQuery { summary: true, ..query() }).unwrap();
+
+    let categories = list(&summary, "categories");
+    assert_eq!(categories.len(), 6);
+    for category in categories {
+        assert_eq!(category.head(), Some("category"));
+        assert!(category.get("sections").is_none());
+    }
+
+    let text = pretty(&summary, tl_station::STATUS_WIDTH);
+    assert_eq!(read(text.as_str()), Ok(summary.clone()));
+    let category_lines = text.lines().filter(|line| line.contains("(category ")).count();
+    assert_eq!(category_lines, 6);
+    assert!(text.lines().filter(|line| line.contains("(category ")).all(|line| line.ends_with(')')));
+
+    let inactive = station.status_query(&StatusQuery { summary: true, inactive_only: true, ..query() }).unwrap();
+    let categories = list(&inactive, "categories");
+    assert_eq!(categories.len(), 1);
+    let count = |key: &str| categories[0].get(key).and_then(Value::as_integer).unwrap();
+    assert!(count("active-modules") < count("total-modules"));
+}
+
+#[test]
+fn menu_prompts_for_a_view_and_a_scope() {
+    let station = Station::from_seed(26);
+    let scopes: Vec<String> = station.paths()
+        .into_iter()
+        .filter(|path| path.matches('/').count() < 2)
+        .collect();
+
+    let mut prompter = TestPrompter::new();
+    prompter.choose(1).choose(1);
+    let chosen = StatusQuery::prompt(&mut prompter, scopes.clone()).unwrap();
+    assert_eq!(chosen, StatusQuery { scope: Some(scopes[0].clone()), inactive_only: true, ..query() });
+
+    prompter.choose(3).choose(0);
+    let chosen = StatusQuery::prompt(&mut prompter, scopes.clone()).unwrap();
+    assert_eq!(chosen, StatusQuery { summary: true, ..query() });
+
+    let records = prompter.records();
+    assert_eq!(records[0].title, "Status view:");
+    assert_eq!(records[1].title, "Status of:");
+    assert_eq!(records[1].options.len(), scopes.len() + 1);
+}
@@ -0,0 +1,196 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at http://mozilla.org/MPL/2.0/.
+
+// library
+use std::io::Cursor;
+
+// dependencies
+use proptest::prelude::*;
+
+// project
+use found_terminal::station::station::Station;
+use found_terminal::journal::journal::Journal;
+use found_terminal::script::script::run_script;
+use found_terminal::packets::ping::PingPacket;
+use found_terminal::protocol::dissect::dissect;
+use found_terminal::event::event::Event;
+use found_terminal::terminalisp::value::Value;
+use found_terminal::terminalisp::reader::{read, read_all, ReadError, DEPTH_MAX};
+use found_terminal::terminalisp::pretty::pretty;
+use found_terminal::terminalisp::sink::{with_sink, BufferSink};
+use found_terminal::terminalisp::station as tl_station;
+use found_terminal::terminalisp::original as tl_original;
+use found_terminal::terminalisp::event as tl_event;
+use found_terminal::terminalisp::dissect as tl_dissect;
+
+
+fn capture<F: FnOnce()>(f: F) -> Vec<String> {
+    let buffer = BufferSink::new();
+    with_sink(Box::new(buffer.clone()), f);
+    return buffer.take();
+}
+
+fn token() -> impl Strategy<Value = String> {
+    "[a-z][a-z0-9/-]{0,8}"
+}
+
+fn value() -> impl Strategy<Value = Value> {
+    let leaf = prop_oneof![
+        token().prop_map(Value::Symbol),
+        token().prop_map(Value::Keyword),
+        token().prop_map(Value::Quoted),
+        ".*".prop_map(Value::Str),
+        any::<i64>().prop_map(Value::integer),
+        any::<u64>().prop_map(Value::integer),
+    ];
+    leaf.prop_recursive(4, 32, 6, |inner| proptest::collection::vec(inner, 0..6).prop_map(Value::List))
+}
+
+proptest! {
+    #[test]
+    fn printed_values_read_back(value in value()) {
+        prop_assert_eq!(read(value.to_string().as_str()), Ok(value));
+    }
+
+    #[test]
+    fn pretty_values_read_back(value in value(), width in 0usize..120) {
+        prop_assert_eq!(read(pretty(&value, width).as_str()), Ok(value));
+    }
+}
+
+#[test]
+fn strings_are_escaped() {
+    let form = Value::form("section-failure", vec![("name", Value::string("The \"Big\" One\\\n"))]);
+
+    assert_eq!(form.to_string(), "(section-failure :name \"The \\\"Big\\\" One\\\\\\n\")");
+    assert_eq!(read(form.to_string().as_str()), Ok(form));
+}
+
+#[test]
+fn reads_forms_by_shape() {
+    let value = read("(module-restored :path \"comms/antenna/antenna\" :status 'ok :day -3)").unwrap();
+
+    assert_eq!(value.head(), Some("module-restored"));
+    assert_eq!(value.get("path").and_then(Value::as_str), Some("comms/antenna/antenna"));
+    assert_eq!(value.get("status"), Some(&Value::quoted("ok")));
+    assert_eq!(value.get("day").and_then(Value::as_integer), Some(-3));
+    assert_eq!(value.get("missing"), None);
+}
+
+#[test]
+fn reports_read_errors() {
+    assert_eq!(read("(station :name"), Err(ReadError::UnexpectedEnd));
+    assert_eq!(read(")"), Err(ReadError::UnexpectedClose(0)));
+    assert_eq!(read("\"open"), Err(ReadError::UnterminatedString(0)));
+    assert_eq!(read("\"\\q\""), Err(ReadError::InvalidEscape(1)));
+    assert_eq!(read("(a) (b)"), Err(ReadError::TrailingInput(4)));
+    assert_eq!(read("(a ')"), Err(ReadError::InvalidToken(3, String::from("'"))));
+}
+
+#[test]
+fn deep_nesting_is_refused() {
+    let nested = |depth: usize| "(".repeat(depth) + &")".repeat(depth);
+    assert!(read(&nested(DEPTH_MAX)).is_ok());
+    assert_eq!(read(&nested(DEPTH_MAX + 1)), Err(ReadError::TooDeep(DEPTH_MAX)));
+
+    // far past what the stack would take, and without the closing parens
+    let open = "(".repeat(1_000_000);
+    assert_eq!(read_all(&open), Err(ReadError::TooDeep(DEPTH_MAX)));
+}
+
+#[test]
+fn reads_the_station_status_tree() {
+    let station = Station::from_seed(11);
+    let status = read(pretty(&station.status(), tl_station::STATUS_WIDTH).as_str()).unwrap();
+
+    assert_eq!(status, station.status());
+
+    assert_eq!(status.head(), Some("station"));
+    assert_eq!(status.get("name").and_then(Value::as_str), Some(station.name_display()
+        .split('"').nth(1).unwrap()));
+    let categories = status.get("categories").and_then(Value::as_list).unwrap();
+    assert_eq!(categories.len(), 6);
+    assert!(categories.iter().all(|category| category.head() == Some("category")));
+}
+
+#[test]
+fn pretty_keeps_short_lists_on_one_line() {
+    let module = Value::form("module", vec![("name", Value::string("Antenna")), ("status", Value::quoted("ok"))]);
+
+    assert_eq!(pretty(&module, 80), "(module :name \"Antenna\" :status 'ok)");
+    assert_eq!(pretty(&Value::List(vec![]), 0), "()");
+}
+
+#[test]
+fn pretty_breaks_long_forms_with_aligned_keywords() {
+    let module = Value::form("module", vec![("name", Value::string("Antenna")), ("status", Value::quoted("ok"))]);
+    let section = Value::form("section", vec![
+        ("name", Value::string("Antenna Section")),
+        ("modules", Value::List(vec![module.clone(), module])),
+    ]);
+
+    assert_eq!(pretty(&section, 60), concat!(
+        "(section\n",
+        "  :name \"Antenna Section\"\n",
+        "  :modules\n",
+        "    ((module :name \"Antenna\" :status 'ok)\n",
+        "     (module :name \"Antenna\" :status 'ok)))",
+    ));
+}
+
+#[test]
+fn pretty_station_status_fits_the_width() {
+    let station = Station::from_seed(11);
+    let text = pretty(&station.status(), tl_station::STATUS_WIDTH);
+
+    assert!(text.lines().count() > 1);
+    assert!(text.lines().all(|line| line.chars().count() <= tl_station::STATUS_WIDTH), "{text}");
+}
+
+#[test]
+fn reads_back_a_whole_session() {
+    let mut station = Station::from_seed(5);
+    let mut journal = Journal::new(String::from("STATION LOG"), station.name_display());
+    let script = "Day one, \"all\" quiet.\nSTATUS\nSCIENCE\nSCIENCE\nNEW DAY\nSTATUS\nPOWER DOWN\n";
+
+    let forms = capture(|| {
+        run_script(Cursor::new(script), &mut station, &mut journal).unwrap();
+    });
+    let values = read_all(forms.join("\n").as_str()).unwrap();
+
+    assert_eq!(values.len(), forms.len());
+    assert_eq!(read_all(journal.to_terminalisp().as_str()).unwrap().len(), 1);
+}
+
+#[test]
+fn reads_back_every_emitter() {
+    let forms = capture(|| {
+        tl_station::section_failure(String::from("Quote \"Section\""));
+        tl_station::sections_ok();
+        tl_station::until_final_transmission(3);
+        tl_station::end_transmission();
+        tl_station::station_seed(u64::MAX);
+        tl_station::station_file_error(String::from("file \"x\" missing"));
+        tl_station::argument_error(String::from("bad \\ argument"));
+        tl_original::station_header(String::from("Kepler \"K\""), 3);
+        tl_original::station_status(vec![String::from("name")], vec![String::from("\"Kepler\"")],
+                                    vec![String::from("Life Support"), String::from("Power")],
+                                    vec![true, false]);
+        tl_original::section_failure_none();
+        tl_event::station_event(Event::MessageFromEarth);
+        tl_event::incoming_message("Hello \"up there\"");
+        tl_event::total_power_failure();
+        let mut ping = PingPacket::new().encode();
+        tl_dissect::packet(1, None, &dissect(&ping, None));
+        ping.truncate(14);
+        tl_dissect::packet(2, None, &dissect(&ping, None));
+    });
+
+    for form in &forms {
+        assert!(read(form.as_str()).is_ok(), "{form}");
+    }
+    let status = read(forms[8].as_str()).unwrap();
+    let sections = status.get("sections").and_then(Value::as_list).unwrap();
+    assert_eq!(sections[1].get("status"), Some(&Value::quoted("inactive")));
+}